@@ -59,6 +59,61 @@ pub mod services {
 /// Generated event types.
 pub mod events {
     tonic::include_proto!("containerd.events");
+
+    use crate::services::v1::{ImageCreate, ImageDelete, ImageUpdate};
+
+    macro_rules! typed_events {
+        ($($name:ident($ty:ty) => $type_url:literal),* $(,)?) => {
+            /// An event decoded by the type url of the `Any` that carries it.
+            #[derive(Clone, Debug, PartialEq)]
+            pub enum TypedEvent {
+                $($name($ty),)*
+                /// An event of the type that is not registered.
+                Unknown(prost_types::Any),
+            }
+
+            /// Decode the event by its type url, which is the full name of the message type,
+            /// optionally prefixed by a host like `types.googleapis.com/`.
+            ///
+            /// An event of an unregistered type is returned as `TypedEvent::Unknown`.
+            pub fn decode(any: &prost_types::Any) -> Result<TypedEvent, prost::DecodeError> {
+                let name = any.type_url.rsplit('/').next().unwrap_or(&any.type_url);
+                let event = match name {
+                    $($type_url => {
+                        TypedEvent::$name(<$ty as prost::Message>::decode(any.value.as_slice())?)
+                    })*
+                    _ => TypedEvent::Unknown(any.clone()),
+                };
+                Ok(event)
+            }
+        };
+    }
+
+    typed_events! {
+        TaskCreate(TaskCreate) => "containerd.events.TaskCreate",
+        TaskStart(TaskStart) => "containerd.events.TaskStart",
+        TaskExecAdded(TaskExecAdded) => "containerd.events.TaskExecAdded",
+        TaskExecStarted(TaskExecStarted) => "containerd.events.TaskExecStarted",
+        TaskPaused(TaskPaused) => "containerd.events.TaskPaused",
+        TaskResumed(TaskResumed) => "containerd.events.TaskResumed",
+        TaskExit(TaskExit) => "containerd.events.TaskExit",
+        TaskDelete(TaskDelete) => "containerd.events.TaskDelete",
+        TaskOom(TaskOom) => "containerd.events.TaskOOM",
+        TaskCheckpointed(TaskCheckpointed) => "containerd.events.TaskCheckpointed",
+        ContainerCreate(ContainerCreate) => "containerd.events.ContainerCreate",
+        ContainerUpdate(ContainerUpdate) => "containerd.events.ContainerUpdate",
+        ContainerDelete(ContainerDelete) => "containerd.events.ContainerDelete",
+        ContentDelete(ContentDelete) => "containerd.events.ContentDelete",
+        ImageCreate(ImageCreate) => "containerd.services.images.v1.ImageCreate",
+        ImageUpdate(ImageUpdate) => "containerd.services.images.v1.ImageUpdate",
+        ImageDelete(ImageDelete) => "containerd.services.images.v1.ImageDelete",
+        NamespaceCreate(NamespaceCreate) => "containerd.events.NamespaceCreate",
+        NamespaceUpdate(NamespaceUpdate) => "containerd.events.NamespaceUpdate",
+        NamespaceDelete(NamespaceDelete) => "containerd.events.NamespaceDelete",
+        SnapshotPrepare(SnapshotPrepare) => "containerd.events.SnapshotPrepare",
+        SnapshotCommit(SnapshotCommit) => "containerd.events.SnapshotCommit",
+        SnapshotRemove(SnapshotRemove) => "containerd.events.SnapshotRemove",
+    }
 }
 
 /// Connect creates a unix channel to containerd GRPC socket.
@@ -22,45 +22,52 @@ use log::{debug, error, warn};
 use tokio::sync::mpsc::{channel, Receiver, Sender};
 
 use ::runc::options::DeleteOpts;
-use containerd_shim::asynchronous::container::Container;
+use containerd_shim::asynchronous::container::{Container, ContainerFactory, ContainerHooks};
 use containerd_shim::asynchronous::monitor::{
     monitor_subscribe, monitor_unsubscribe, Subscription,
 };
 use containerd_shim::asynchronous::processes::Process;
-use containerd_shim::asynchronous::publisher::RemotePublisher;
+use containerd_shim::asynchronous::publisher::EventPublisher;
 use containerd_shim::asynchronous::task::TaskService;
 use containerd_shim::asynchronous::{spawn, ExitSignal, Shim};
 use containerd_shim::event::Event;
 use containerd_shim::monitor::{Subject, Topic};
 use containerd_shim::protos::events::task::TaskExit;
 use containerd_shim::protos::protobuf::{Message, SingularPtrField};
-use containerd_shim::util::{convert_to_timestamp, timestamp};
-use containerd_shim::util::{read_options, read_runtime, read_spec, write_str_to_file};
+use containerd_shim::util::{convert_to_timestamp, timestamp, Deadline};
+use containerd_shim::util::{
+    read_options, read_runtime, read_spec, unmount_rootfs, write_str_to_file,
+};
 use containerd_shim::{io_error, Config, Context, DeleteResponse, Error, StartOpts};
 
 use crate::asynchronous::runc::{RuncContainer, RuncFactory};
 use crate::common::{create_runc, has_shared_pid_namespace};
-use crate::common::{ShimExecutor, GROUP_LABELS};
+use crate::common::{events_spill_file, ShimExecutor, GROUP_LABELS};
 
 mod runc;
+mod state;
 
 pub(crate) struct Service {
     exit: Arc<ExitSignal>,
     id: String,
     namespace: String,
+    /// hooks around the lifecycle of the containers, the runc shim itself has none.
+    hooks: Option<Arc<dyn ContainerHooks>>,
 }
 
 #[async_trait]
 impl Shim for Service {
     type T = TaskService<RuncFactory, RuncContainer>;
 
-    async fn new(_runtime_id: &str, id: &str, namespace: &str, _config: &mut Config) -> Self {
+    async fn new(_runtime_id: &str, id: &str, namespace: &str, config: &mut Config) -> Self {
+        config.publisher.spill_file = events_spill_file();
         let exit = Arc::new(ExitSignal::default());
         // TODO: add publisher
         Service {
             exit,
             id: id.to_string(),
             namespace: namespace.to_string(),
+            hooks: None,
         }
     }
 
@@ -101,6 +108,9 @@ impl Shim for Service {
         runc.delete(&self.id, Some(&DeleteOpts { force: true }))
             .await
             .unwrap_or_else(|e| warn!("failed to remove runc container: {}", e));
+        unmount_rootfs(bundle.join("rootfs"))
+            .await
+            .unwrap_or_else(|e| warn!("failed to cleanup rootfs mount: {}", e));
         let mut resp = DeleteResponse::new();
         // sigkill
         resp.exit_status = 137;
@@ -112,15 +122,19 @@ impl Shim for Service {
         self.exit.wait().await;
     }
 
-    async fn create_task_service(&self, publisher: RemotePublisher) -> Self::T {
+    async fn create_task_service(&self, publisher: Box<dyn EventPublisher>) -> Self::T {
         let (tx, rx) = channel(128);
         let exit_clone = self.exit.clone();
-        let task = TaskService::new(&*self.namespace, exit_clone, tx.clone());
+        let mut task = TaskService::new(&*self.namespace, exit_clone, tx.clone());
+        task.factory = RuncFactory::new(self.hooks.clone());
         let s = monitor_subscribe(Topic::Pid)
             .await
             .expect("monitor subscribe failed");
         process_exits(s, &task, tx).await;
         forward(publisher, self.namespace.to_string(), rx).await;
+        task.restore()
+            .await
+            .unwrap_or_else(|e| warn!("failed to restore containers: {}", e));
         task
     }
 }
@@ -131,29 +145,46 @@ async fn process_exits(
     tx: Sender<(String, Box<dyn Message>)>,
 ) {
     let containers = task.containers.clone();
+    let processes = task.processes.clone();
+    let factory = task.factory.clone();
+    let ns = task.namespace.to_string();
     let mut s = s;
     tokio::spawn(async move {
         while let Some(e) = s.rx.recv().await {
             if let Subject::Pid(pid) = e.subject {
                 debug!("receive exit event: {}", &e);
                 let exit_code = e.exit_code;
-                for (_k, cont) in containers.lock().await.iter_mut() {
-                    let bundle = cont.bundle.to_string();
+                let key = match processes.exited(pid, exit_code) {
+                    Some(key) => key,
+                    None => continue,
+                };
+                let cont = match containers.lock().await.get(&key.id).cloned() {
+                    Some(cont) => cont,
+                    None => continue,
+                };
+                let mut cont = cont.lock().await;
+                match key.exec_id {
                     // pid belongs to container init process
-                    if cont.init.pid == pid {
+                    None => {
                         // kill all children process if the container has a private PID namespace
-                        if should_kill_all_on_exit(&bundle).await {
-                            cont.kill(None, 9, true).await.unwrap_or_else(|e| {
-                                error!("failed to kill init's children: {}", e)
-                            });
+                        if should_kill_all_on_exit(&cont.bundle).await {
+                            cont.kill(None, 9, true, Deadline::default())
+                                .await
+                                .unwrap_or_else(|e| {
+                                    error!("failed to kill init's children: {}", e)
+                                });
                         }
                         // set exit for init process
                         cont.init.set_exited(exit_code).await;
+                        cont.post_exit(exit_code).await;
+                        factory.save(&ns, &*cont).await.unwrap_or_else(|e| {
+                            warn!("failed to save state of container {}: {}", cont.id, e)
+                        });
 
                         // publish event
                         let (_, code, exited_at) = match cont.get_exit_info(None).await {
                             Ok(info) => info,
-                            Err(_) => break,
+                            Err(_) => continue,
                         };
 
                         let ts = convert_to_timestamp(exited_at);
@@ -169,17 +200,16 @@ async fn process_exits(
                         tx.send((topic.to_string(), Box::new(event)))
                             .await
                             .unwrap_or_else(|e| warn!("send {} to publisher: {}", topic, e));
-
-                        break;
                     }
-
                     // pid belongs to container common process
-                    for (_exec_id, p) in cont.processes.iter_mut() {
-                        // set exit for exec process
-                        if p.pid == pid {
+                    Some(exec_id) => {
+                        if let Some(p) = cont.processes.get_mut(&exec_id) {
+                            // set exit for exec process
                             p.set_exited(exit_code).await;
                             // TODO: publish event
-                            break;
+                            factory.save(&ns, &*cont).await.unwrap_or_else(|e| {
+                                warn!("failed to save state of container {}: {}", cont.id, e)
+                            });
                         }
                     }
                 }
@@ -190,7 +220,7 @@ async fn process_exits(
 }
 
 async fn forward(
-    publisher: RemotePublisher,
+    publisher: Box<dyn EventPublisher>,
     ns: String,
     mut rx: Receiver<(String, Box<dyn Message>)>,
 ) {
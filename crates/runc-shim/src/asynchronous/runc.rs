@@ -14,6 +14,7 @@
    limitations under the License.
 */
 
+use std::collections::HashMap;
 use std::convert::TryFrom;
 use std::os::unix::io::{AsRawFd, FromRawFd, RawFd};
 use std::os::unix::prelude::ExitStatusExt;
@@ -22,7 +23,7 @@ use std::process::ExitStatus;
 use std::sync::Arc;
 
 use async_trait::async_trait;
-use log::{debug, error};
+use log::{debug, error, warn};
 use nix::sys::signal::kill;
 use nix::unistd::Pid;
 use oci_spec::runtime::{LinuxResources, Process};
@@ -32,28 +33,34 @@ use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite};
 use containerd_shim::api::{CreateTaskRequest, ExecProcessRequest, Options, Status};
 use containerd_shim::asynchronous::console::ConsoleSocket;
 use containerd_shim::asynchronous::container::{
-    ContainerFactory, ContainerTemplate, ProcessFactory,
+    ContainerFactory, ContainerHooks, ContainerTemplate, HookContext, ProcessFactory,
 };
 use containerd_shim::asynchronous::monitor::{
     monitor_subscribe, monitor_unsubscribe, Subscription,
 };
-use containerd_shim::asynchronous::processes::{ProcessLifecycle, ProcessTemplate};
+use containerd_shim::asynchronous::processes::{Process as _, ProcessLifecycle, ProcessTemplate};
 use containerd_shim::io::Stdio;
-use containerd_shim::monitor::{ExitEvent, Subject, Topic};
-use containerd_shim::protos::api::ProcessInfo;
+use containerd_shim::monitor::{claim_exit, ExitEvent, Subject, Topic};
+use containerd_shim::protos::api::{Mount, ProcessInfo};
 use containerd_shim::protos::cgroups::metrics::Metrics;
-use containerd_shim::protos::protobuf::{CodedInputStream, Message};
+use containerd_shim::protos::protobuf::{CodedInputStream, Message, ProtobufEnum};
 use containerd_shim::util::{
-    asyncify, mkdir, mount_rootfs, read_file_to_str, write_options, write_runtime,
+    asyncify, lookup_mount, mkdir, mount_rootfs, read_file_to_str, read_options, unmount_rootfs,
+    write_options, write_runtime, Deadline,
 };
 use containerd_shim::{io_error, other, other_error, Console, Error, ExitSignal, Result};
 use runc::{Command, Runc, Spawner};
 
+use crate::asynchronous::state::{load_state, remove_state, save_state, saved_bundles, watch_exit};
 use crate::common::receive_socket;
 use crate::common::CreateConfig;
 use crate::common::{
-    check_kill_error, create_io, create_runc, get_spec_from_request, ProcessIO, ShimExecutor,
-    INIT_PID_FILE,
+    check_kill_error, create_io, create_runc, get_spec_from_request, runc_error, ProcessIO,
+    ShimExecutor, INIT_PID_FILE,
+};
+use crate::state::{
+    is_alive, process_start_time, timestamp_nanos, ContainerState, ExecState, ProcessState,
+    UNKNOWN_EXIT_STATUS,
 };
 
 pub type ExecProcess = ProcessTemplate<RuncExecLifecycle>;
@@ -62,7 +69,10 @@ pub type InitProcess = ProcessTemplate<RuncInitLifecycle>;
 pub type RuncContainer = ContainerTemplate<InitProcess, ExecProcess, RuncExecFactory>;
 
 #[derive(Clone, Default)]
-pub(crate) struct RuncFactory {}
+pub(crate) struct RuncFactory {
+    /// hooks of the containers created or restored by the factory
+    hooks: Option<Arc<dyn ContainerHooks>>,
+}
 
 #[async_trait]
 impl ContainerFactory<RuncContainer> for RuncFactory {
@@ -70,6 +80,7 @@ impl ContainerFactory<RuncContainer> for RuncFactory {
         &self,
         ns: &str,
         req: &CreateTaskRequest,
+        deadline: Deadline,
     ) -> containerd_shim::Result<RuncContainer> {
         let bundle = req.get_bundle();
         let mut opts = Options::new();
@@ -80,11 +91,16 @@ impl ContainerFactory<RuncContainer> for RuncFactory {
         if opts.compute_size() > 0 {
             debug!("create options: {:?}", &opts);
         }
+        if let Some(hooks) = &self.hooks {
+            hooks
+                .pre_create(&HookContext::new(req.get_id(), bundle, 0).await)
+                .await?;
+        }
         let runtime = opts.binary_name.as_str();
         write_options(bundle, &opts).await?;
         write_runtime(bundle, runtime).await?;
 
-        let rootfs_vec = req.get_rootfs().to_vec();
+        let rootfs_vec = req.get_rootfs();
         let rootfs = if !rootfs_vec.is_empty() {
             let tmp_rootfs = Path::new(bundle).join("rootfs");
             mkdir(&tmp_rootfs, 0o711).await?;
@@ -93,15 +109,121 @@ impl ContainerFactory<RuncContainer> for RuncFactory {
             PathBuf::new()
         };
 
+        let create = async {
+            let c = self
+                .create_with_rootfs(ns, req, &opts, rootfs_vec, &rootfs)
+                .await?;
+            if let Err(e) = c.post_create().await {
+                force_delete(&c.init.lifecycle.runtime, req.get_id()).await;
+                return Err(e);
+            }
+            Ok(c)
+        };
+        let res = deadline.run("create", create).await;
+        if let Err(Error::DeadlineExceeded(_)) = res {
+            // runc is killed in the middle, remove the container it may have left.
+            match create_runc(
+                runtime,
+                ns,
+                bundle,
+                &opts,
+                Some(Arc::new(ShimExecutor::default())),
+            ) {
+                Ok(runc) => force_delete(&runc, req.get_id()).await,
+                Err(e) => warn!("failed to create runc to delete {}: {}", req.get_id(), e),
+            }
+        }
+        if res.is_err() && !rootfs_vec.is_empty() {
+            rollback_rootfs(&rootfs).await;
+        }
+        res
+    }
+
+    async fn cleanup(&self, _ns: &str, c: &RuncContainer) -> containerd_shim::Result<()> {
+        let rootfs = &c.init.lifecycle.rootfs;
+        if !rootfs.is_empty() {
+            unmount_rootfs(rootfs).await?;
+        }
+        remove_state(&c.bundle).await
+    }
+
+    async fn save(&self, _ns: &str, c: &RuncContainer) -> containerd_shim::Result<()> {
+        let state = ContainerState {
+            id: c.id.to_string(),
+            bundle: c.bundle.to_string(),
+            rootfs: c.init.lifecycle.rootfs.to_string(),
+            init: process_state(&c.init),
+            execs: c
+                .processes
+                .values()
+                .map(|p| ExecState {
+                    process: process_state(p),
+                    spec: p.lifecycle.spec.clone(),
+                })
+                .collect(),
+        };
+        save_state(&c.bundle, state).await
+    }
+
+    async fn restore(&self, ns: &str) -> containerd_shim::Result<Vec<RuncContainer>> {
+        let mut containers = vec![];
+        for bundle in saved_bundles().await? {
+            match self.restore_container(ns, &bundle).await {
+                Ok(c) => containers.push(c),
+                Err(e) => warn!(
+                    "failed to restore container from {}: {}",
+                    bundle.display(),
+                    e
+                ),
+            }
+        }
+        Ok(containers)
+    }
+}
+
+impl RuncFactory {
+    /// A factory of the containers calling the hooks around their lifecycle.
+    pub(crate) fn new(hooks: Option<Arc<dyn ContainerHooks>>) -> Self {
+        Self { hooks }
+    }
+
+    async fn with_hooks(&self, c: RuncContainer) -> RuncContainer {
+        match &self.hooks {
+            Some(hooks) => c.with_hooks(hooks.clone()).await,
+            None => c,
+        }
+    }
+
+    async fn create_with_rootfs(
+        &self,
+        ns: &str,
+        req: &CreateTaskRequest,
+        opts: &Options,
+        rootfs_vec: &[Mount],
+        rootfs: &Path,
+    ) -> Result<RuncContainer> {
         for m in rootfs_vec {
-            mount_rootfs(&m, rootfs.as_path()).await?
+            mount_rootfs(m, rootfs).await?
+        }
+        if !rootfs_vec.is_empty() {
+            // mountinfo has the real path of the mount point, while the bundle may be a symlink
+            let real = tokio::fs::canonicalize(rootfs).await.map_err(io_error!(
+                e,
+                "canonicalize {}",
+                rootfs.display()
+            ))?;
+            if lookup_mount(&real).await?.is_none() {
+                return Err(other!("rootfs {} is not mounted", rootfs.display()));
+            }
         }
 
+        let bundle = req.get_bundle();
+        let runtime = opts.binary_name.as_str();
         let runc = create_runc(
             runtime,
             ns,
             bundle,
-            &opts,
+            opts,
             Some(Arc::new(ShimExecutor::default())),
         )?;
 
@@ -116,32 +238,25 @@ impl ContainerFactory<RuncContainer> for RuncFactory {
         let mut init = InitProcess::new(
             id,
             stdio,
-            RuncInitLifecycle::new(runc.clone(), opts.clone(), bundle),
+            RuncInitLifecycle::new(runc.clone(), opts.clone(), bundle, rootfs),
         );
 
         let config = CreateConfig::default();
         self.do_create(&mut init, config).await?;
-        let container = RuncContainer {
-            id: id.to_string(),
-            bundle: bundle.to_string(),
+        let container = RuncContainer::new(
+            id,
+            bundle,
             init,
-            process_factory: RuncExecFactory {
+            RuncExecFactory {
                 runtime: runc,
                 bundle: bundle.to_string(),
                 io_uid: opts.io_uid,
                 io_gid: opts.io_gid,
             },
-            processes: Default::default(),
-        };
-        Ok(container)
-    }
-
-    async fn cleanup(&self, _ns: &str, _c: &RuncContainer) -> containerd_shim::Result<()> {
-        Ok(())
+        );
+        Ok(self.with_hooks(container).await)
     }
-}
 
-impl RuncFactory {
     async fn do_create(&self, init: &mut InitProcess, _config: CreateConfig) -> Result<()> {
         let id = init.id.to_string();
         let stdio = &init.stdio;
@@ -172,15 +287,147 @@ impl RuncFactory {
             if let Some(s) = socket {
                 s.clean().await;
             }
-            return Err(other!("failed to create runc container: {}", e));
+            return Err(runc_error(e, "failed to create runc container"));
         }
-        copy_io_or_console(init, socket, pio, init.lifecycle.exit_signal.clone()).await?;
-        let pid = read_file_to_str(pid_path).await?.parse::<i32>()?;
-        init.pid = pid;
-        Ok(())
+        // runc has created the container, it is removed if the rest of the create fails
+        let exit_signal = init.lifecycle.exit_signal.clone();
+        let res = match copy_io_or_console(init, socket, pio, exit_signal).await {
+            Ok(()) => read_file_to_str(pid_path)
+                .await
+                .and_then(|pid| pid.parse::<i32>().map_err(Into::into)),
+            Err(e) => Err(e),
+        };
+        match res {
+            Ok(pid) => {
+                init.pid = pid;
+                Ok(())
+            }
+            Err(e) => {
+                force_delete(&init.lifecycle.runtime, &id).await;
+                Err(e)
+            }
+        }
+    }
+
+    async fn restore_container(&self, ns: &str, bundle: &Path) -> Result<RuncContainer> {
+        let state = load_state(bundle).await?;
+        let opts = read_options(bundle).await?;
+        let bundle = bundle.to_string_lossy().to_string();
+        let runc = create_runc(
+            opts.binary_name.as_str(),
+            ns,
+            &bundle,
+            &opts,
+            Some(Arc::new(ShimExecutor::default())),
+        )?;
+
+        let mut init = InitProcess::new(
+            &state.id,
+            state.init.stdio.clone(),
+            RuncInitLifecycle::new(
+                runc.clone(),
+                opts.clone(),
+                &bundle,
+                Path::new(&state.rootfs),
+            ),
+        );
+        restore_process(&mut init, &state.init).await;
+        if init.exited_at.is_none() {
+            // the init process is alive, take the status from runc, it may be paused.
+            match runc.state(&state.id).await {
+                Ok(c) => match c.status.as_str() {
+                    "created" => init.state = Status::CREATED,
+                    "running" => init.state = Status::RUNNING,
+                    "paused" => init.state = Status::PAUSED,
+                    "pausing" => init.state = Status::PAUSING,
+                    _ => {}
+                },
+                Err(e) => warn!("failed to get runc state of {}: {}", state.id, e),
+            }
+        }
+
+        let mut processes = HashMap::new();
+        for e in state.execs {
+            let mut p = ExecProcess::new(
+                &e.process.id,
+                e.process.stdio.clone(),
+                RuncExecLifecycle {
+                    runtime: runc.clone(),
+                    bundle: bundle.to_string(),
+                    container_id: state.id.to_string(),
+                    io_uid: opts.io_uid,
+                    io_gid: opts.io_gid,
+                    spec: e.spec,
+                    exit_signal: Default::default(),
+                },
+            );
+            restore_process(&mut p, &e.process).await;
+            processes.insert(p.id.to_string(), p);
+        }
+
+        let mut container = RuncContainer::new(
+            &state.id,
+            &bundle,
+            init,
+            RuncExecFactory {
+                runtime: runc,
+                bundle: bundle.to_string(),
+                io_uid: opts.io_uid,
+                io_gid: opts.io_gid,
+            },
+        );
+        container.processes = processes;
+        Ok(self.with_hooks(container).await)
     }
 }
 
+fn process_state<S>(p: &ProcessTemplate<S>) -> ProcessState {
+    let start_time = if p.pid > 0 && p.exited_at.is_none() {
+        process_start_time(p.pid).unwrap_or_default()
+    } else {
+        0
+    };
+    ProcessState {
+        id: p.id.to_string(),
+        status: p.state.value(),
+        pid: p.pid,
+        start_time,
+        stdio: p.stdio.clone(),
+        exit_code: p.exit_code,
+        exited_at: timestamp_nanos(p.exited_at),
+    }
+}
+
+/// Restore the process from the saved state, the stdio of a process not on a terminal
+/// are the fifos opened by runc itself, so it keeps writing to them without the shim,
+/// but a console can not be recovered once the shim holding it exited.
+async fn restore_process<S>(p: &mut ProcessTemplate<S>, s: &ProcessState)
+where
+    S: ProcessLifecycle<ProcessTemplate<S>> + Sync + Send,
+{
+    p.state = Status::from_i32(s.status).unwrap_or(Status::UNKNOWN);
+    p.pid = s.pid;
+    p.exit_code = s.exit_code;
+    p.exited_at = s.exited_at();
+    if p.exited_at.is_some() || p.pid <= 0 {
+        return;
+    }
+    if !is_alive(s.pid, s.start_time) {
+        // the process has exited and been reaped while no shim was serving it,
+        // so its wait status is lost.
+        debug!(
+            "process {} with pid {} exited while shim was down",
+            p.id, p.pid
+        );
+        p.set_exited(UNKNOWN_EXIT_STATUS).await;
+        return;
+    }
+    if p.stdio.terminal {
+        warn!("console of process {} can not be recovered", p.id);
+    }
+    watch_exit(s.pid, s.start_time);
+}
+
 pub struct RuncExecFactory {
     runtime: Runc,
     bundle: String,
@@ -190,7 +437,7 @@ pub struct RuncExecFactory {
 
 #[async_trait]
 impl ProcessFactory<ExecProcess> for RuncExecFactory {
-    async fn create(&self, req: &ExecProcessRequest) -> Result<ExecProcess> {
+    async fn create(&self, req: &ExecProcessRequest, _deadline: Deadline) -> Result<ExecProcess> {
         let p = get_spec_from_request(req)?;
         Ok(ExecProcess {
             state: Status::CREATED,
@@ -223,16 +470,22 @@ pub struct RuncInitLifecycle {
     runtime: Runc,
     opts: Options,
     bundle: String,
+    rootfs: String,
     exit_signal: Arc<ExitSignal>,
 }
 
 #[async_trait]
 impl ProcessLifecycle<InitProcess> for RuncInitLifecycle {
-    async fn start(&self, p: &mut InitProcess) -> containerd_shim::Result<()> {
-        self.runtime
-            .start(p.id.as_str())
-            .await
-            .map_err(other_error!(e, "failed start"))?;
+    async fn start(&self, p: &mut InitProcess, deadline: Deadline) -> containerd_shim::Result<()> {
+        let start = async {
+            self.runtime
+                .start(p.id.as_str())
+                .await
+                .map_err(|e| runc_error(e, "failed start"))
+        };
+        // runc may have started the container when it is killed at the deadline,
+        // the workload is left alone for the client to query or delete.
+        deadline.run("start", start).await?;
         p.state = Status::RUNNING;
         Ok(())
     }
@@ -242,38 +495,52 @@ impl ProcessLifecycle<InitProcess> for RuncInitLifecycle {
         p: &mut InitProcess,
         signal: u32,
         all: bool,
+        deadline: Deadline,
     ) -> containerd_shim::Result<()> {
-        self.runtime
-            .kill(
-                p.id.as_str(),
-                signal,
-                Some(&runc::options::KillOpts { all }),
-            )
-            .await
-            .map_err(|e| check_kill_error(e.to_string()))
+        let kill = async {
+            self.runtime
+                .kill(
+                    p.id.as_str(),
+                    signal,
+                    Some(&runc::options::KillOpts { all }),
+                )
+                .await
+                .map_err(|e| check_kill_error(e.to_string()))
+        };
+        deadline.run("kill", kill).await
     }
 
-    async fn delete(&self, p: &mut InitProcess) -> containerd_shim::Result<()> {
-        self.runtime
-            .delete(
-                p.id.as_str(),
-                Some(&runc::options::DeleteOpts { force: true }),
-            )
-            .await
-            .or_else(|e| {
-                if !e.to_string().to_lowercase().contains("does not exist") {
-                    Err(e)
-                } else {
-                    Ok(())
-                }
-            })
-            .map_err(other_error!(e, "failed delete"))?;
+    async fn delete(&self, p: &mut InitProcess, deadline: Deadline) -> containerd_shim::Result<()> {
+        let delete = async {
+            self.runtime
+                .delete(
+                    p.id.as_str(),
+                    Some(&runc::options::DeleteOpts { force: true }),
+                )
+                .await
+                .or_else(|e| {
+                    if !e.to_string().to_lowercase().contains("does not exist") {
+                        Err(e)
+                    } else {
+                        Ok(())
+                    }
+                })
+                .map_err(|e| runc_error(e, "failed delete"))
+        };
+        deadline.run("delete", delete).await?;
         self.exit_signal.signal();
         Ok(())
     }
 
     #[cfg(target_os = "linux")]
-    async fn update(&self, p: &mut InitProcess, resources: &LinuxResources) -> Result<()> {
+    async fn update(
+        &self,
+        p: &mut InitProcess,
+        resources: &LinuxResources,
+        deadline: Deadline,
+    ) -> Result<()> {
+        // writing the cgroup files can not be cancelled halfway
+        deadline.check("update")?;
         if p.pid <= 0 {
             return Err(other!(
                 "failed to update resources because init process is {}",
@@ -284,12 +551,18 @@ impl ProcessLifecycle<InitProcess> for RuncInitLifecycle {
     }
 
     #[cfg(not(target_os = "linux"))]
-    async fn update(&self, _p: &mut InitProcess, _resources: &LinuxResources) -> Result<()> {
+    async fn update(
+        &self,
+        _p: &mut InitProcess,
+        _resources: &LinuxResources,
+        _deadline: Deadline,
+    ) -> Result<()> {
         Err(Error::Unimplemented("update resource".to_string()))
     }
 
     #[cfg(target_os = "linux")]
-    async fn stats(&self, p: &InitProcess) -> Result<Metrics> {
+    async fn stats(&self, p: &InitProcess, deadline: Deadline) -> Result<Metrics> {
+        deadline.check("stats")?;
         if p.pid <= 0 {
             return Err(other!(
                 "failed to collect metrics because init process is {}",
@@ -300,16 +573,18 @@ impl ProcessLifecycle<InitProcess> for RuncInitLifecycle {
     }
 
     #[cfg(not(target_os = "linux"))]
-    async fn stats(&self, _p: &InitProcess) -> Result<Metrics> {
+    async fn stats(&self, _p: &InitProcess, _deadline: Deadline) -> Result<Metrics> {
         Err(Error::Unimplemented("process stats".to_string()))
     }
 
-    async fn ps(&self, p: &InitProcess) -> Result<Vec<ProcessInfo>> {
-        let pids = self
-            .runtime
-            .ps(&*p.id)
-            .await
-            .map_err(other_error!(e, "failed to execute runc ps"))?;
+    async fn ps(&self, p: &InitProcess, deadline: Deadline) -> Result<Vec<ProcessInfo>> {
+        let ps = async {
+            self.runtime
+                .ps(&*p.id)
+                .await
+                .map_err(other_error!(e, "failed to execute runc ps"))
+        };
+        let pids = deadline.run("ps", ps).await?;
         Ok(pids
             .iter()
             .map(|&x| ProcessInfo {
@@ -321,7 +596,7 @@ impl ProcessLifecycle<InitProcess> for RuncInitLifecycle {
 }
 
 impl RuncInitLifecycle {
-    pub fn new(runtime: Runc, opts: Options, bundle: &str) -> Self {
+    pub fn new(runtime: Runc, opts: Options, bundle: &str, rootfs: &Path) -> Self {
         let work_dir = Path::new(bundle).join("work");
         let mut opts = opts;
         if opts.get_criu_path().is_empty() {
@@ -331,11 +606,31 @@ impl RuncInitLifecycle {
             runtime,
             opts,
             bundle: bundle.to_string(),
+            rootfs: rootfs.to_string_lossy().to_string(),
             exit_signal: Default::default(),
         }
     }
 }
 
+/// Remove the runc container that failed to be created, if runc has left it.
+async fn force_delete(runc: &Runc, id: &str) {
+    runc.delete(id, Some(&runc::options::DeleteOpts { force: true }))
+        .await
+        .unwrap_or_else(|e| debug!("failed to delete runc container {}: {}", id, e));
+}
+
+/// Undo the rootfs mounts of a container that failed to be created, and remove the
+/// rootfs directory that was made for them.
+async fn rollback_rootfs(rootfs: &Path) {
+    if let Err(e) = unmount_rootfs(rootfs).await {
+        error!("failed to unmount rootfs {}: {}", rootfs.display(), e);
+        return;
+    }
+    if let Err(e) = tokio::fs::remove_dir(rootfs).await {
+        warn!("failed to remove rootfs dir {}: {}", rootfs.display(), e);
+    }
+}
+
 pub struct RuncExecLifecycle {
     runtime: Runc,
     bundle: String,
@@ -348,39 +643,26 @@ pub struct RuncExecLifecycle {
 
 #[async_trait]
 impl ProcessLifecycle<ExecProcess> for RuncExecLifecycle {
-    async fn start(&self, p: &mut ExecProcess) -> containerd_shim::Result<()> {
+    async fn start(&self, p: &mut ExecProcess, deadline: Deadline) -> containerd_shim::Result<()> {
         let pid_path = Path::new(self.bundle.as_str()).join(format!("{}.pid", &p.id));
-        let mut exec_opts = runc::options::ExecOpts {
-            io: None,
-            pid_file: Some(pid_path.to_owned()),
-            console_socket: None,
-            detach: true,
-        };
-        let (socket, pio) = if p.stdio.terminal {
-            let s = ConsoleSocket::new().await?;
-            exec_opts.console_socket = Some(s.path.to_owned());
-            (Some(s), None)
-        } else {
-            let pio = create_io(&p.id, self.io_uid, self.io_gid, &p.stdio)?;
-            exec_opts.io = pio.io.as_ref().cloned();
-            (None, Some(pio))
-        };
-        //TODO  checkpoint support
-        let exec_result = self
-            .runtime
-            .exec(&self.container_id, &self.spec, Some(&exec_opts))
-            .await;
-        if let Err(e) = exec_result {
-            if let Some(s) = socket {
-                s.clean().await;
+        match deadline.run("exec", self.exec(p, &pid_path)).await {
+            Ok(pid) => {
+                p.pid = pid;
+                p.state = Status::RUNNING;
+                Ok(())
+            }
+            Err(e @ Error::DeadlineExceeded(_)) => {
+                // runc may have started the process before it is killed.
+                if let Ok(pid) = read_file_to_str(&pid_path).await {
+                    if let Ok(pid) = pid.trim().parse::<i32>() {
+                        kill(Pid::from_raw(pid), nix::sys::signal::Signal::SIGKILL)
+                            .unwrap_or_else(|e| debug!("failed to kill exec {}: {}", p.id, e));
+                    }
+                }
+                Err(e)
             }
-            return Err(other!("failed to start runc exec: {}", e));
+            Err(e) => Err(e),
         }
-        copy_io_or_console(p, socket, pio, p.lifecycle.exit_signal.clone()).await?;
-        let pid = read_file_to_str(pid_path).await?.parse::<i32>()?;
-        p.pid = pid;
-        p.state = Status::RUNNING;
-        Ok(())
     }
 
     async fn kill(
@@ -388,7 +670,9 @@ impl ProcessLifecycle<ExecProcess> for RuncExecLifecycle {
         p: &mut ExecProcess,
         signal: u32,
         _all: bool,
+        deadline: Deadline,
     ) -> containerd_shim::Result<()> {
+        deadline.check("kill")?;
         if p.pid <= 0 {
             Err(Error::FailedPreconditionError(
                 "process not created".to_string(),
@@ -405,24 +689,67 @@ impl ProcessLifecycle<ExecProcess> for RuncExecLifecycle {
         }
     }
 
-    async fn delete(&self, _p: &mut ExecProcess) -> containerd_shim::Result<()> {
+    async fn delete(
+        &self,
+        _p: &mut ExecProcess,
+        _deadline: Deadline,
+    ) -> containerd_shim::Result<()> {
         self.exit_signal.signal();
         Ok(())
     }
 
-    async fn update(&self, _p: &mut ExecProcess, _resources: &LinuxResources) -> Result<()> {
+    async fn update(
+        &self,
+        _p: &mut ExecProcess,
+        _resources: &LinuxResources,
+        _deadline: Deadline,
+    ) -> Result<()> {
         Err(Error::Unimplemented("exec update".to_string()))
     }
 
-    async fn stats(&self, _p: &ExecProcess) -> Result<Metrics> {
+    async fn stats(&self, _p: &ExecProcess, _deadline: Deadline) -> Result<Metrics> {
         Err(Error::Unimplemented("exec stats".to_string()))
     }
 
-    async fn ps(&self, _p: &ExecProcess) -> Result<Vec<ProcessInfo>> {
+    async fn ps(&self, _p: &ExecProcess, _deadline: Deadline) -> Result<Vec<ProcessInfo>> {
         Err(Error::Unimplemented("exec ps".to_string()))
     }
 }
 
+impl RuncExecLifecycle {
+    async fn exec(&self, p: &mut ExecProcess, pid_path: &Path) -> Result<i32> {
+        let mut exec_opts = runc::options::ExecOpts {
+            io: None,
+            pid_file: Some(pid_path.to_owned()),
+            console_socket: None,
+            detach: true,
+        };
+        let (socket, pio) = if p.stdio.terminal {
+            let s = ConsoleSocket::new().await?;
+            exec_opts.console_socket = Some(s.path.to_owned());
+            (Some(s), None)
+        } else {
+            let pio = create_io(&p.id, self.io_uid, self.io_gid, &p.stdio)?;
+            exec_opts.io = pio.io.as_ref().cloned();
+            (None, Some(pio))
+        };
+        //TODO  checkpoint support
+        let exec_result = self
+            .runtime
+            .exec(&self.container_id, &self.spec, Some(&exec_opts))
+            .await;
+        if let Err(e) = exec_result {
+            if let Some(s) = socket {
+                s.clean().await;
+            }
+            return Err(runc_error(e, "failed to start runc exec"));
+        }
+        copy_io_or_console(p, socket, pio, p.lifecycle.exit_signal.clone()).await?;
+        let pid = read_file_to_str(pid_path).await?.parse::<i32>()?;
+        Ok(pid)
+    }
+}
+
 async fn copy_console(
     console_socket: &ConsoleSocket,
     stdio: &Stdio,
@@ -625,26 +952,50 @@ impl Spawner for ShimExecutor {
         let subscription = monitor_subscribe(Topic::Pid)
             .await
             .map_err(|e| runc::error::Error::Other(Box::new(e)))?;
-        let sid = subscription.id;
-        let child = match cmd.spawn() {
-            Ok(c) => c,
-            Err(e) => {
-                monitor_unsubscribe(sid).await.unwrap_or_default();
-                return Err(runc::error::Error::ProcessSpawnFailed(e));
-            }
+        let mut execution = Execution {
+            sid: subscription.id,
+            pid: None,
         };
+        let child = cmd
+            .spawn()
+            .map_err(runc::error::Error::ProcessSpawnFailed)?;
         let pid = child.id().unwrap();
+        claim_exit(pid as i32);
+        execution.pid = Some(pid as i32);
         let (stdout, stderr, exit_code) = tokio::join!(
             read_std(child.stdout),
             read_std(child.stderr),
             wait_pid(pid as i32, subscription)
         );
+        execution.pid = None;
         let status = ExitStatus::from_raw(exit_code);
-        monitor_unsubscribe(sid).await.unwrap_or_default();
         Ok((status, pid, stdout, stderr))
     }
 }
 
+/// A command run by the [ShimExecutor], it is killed if dropped before it exits,
+/// which happens when the deadline of the request passes.
+///
+/// `kill_on_drop` of the command doesn't fit, as the exit is reaped by the shim
+/// instead of tokio, which can't tell whether the pid still belongs to the command.
+struct Execution {
+    sid: i64,
+    pid: Option<i32>,
+}
+
+impl Drop for Execution {
+    fn drop(&mut self) {
+        if let Some(pid) = self.pid {
+            kill(Pid::from_raw(pid), nix::sys::signal::Signal::SIGKILL)
+                .unwrap_or_else(|e| debug!("failed to kill command {}: {}", pid, e));
+        }
+        let sid = self.sid;
+        tokio::spawn(async move {
+            monitor_unsubscribe(sid).await.unwrap_or_default();
+        });
+    }
+}
+
 async fn read_std<T>(std: Option<T>) -> String
 where
     T: AsyncRead + Unpin,
@@ -0,0 +1,83 @@
+/*
+   Copyright The containerd Authors.
+
+   Licensed under the Apache License, Version 2.0 (the "License");
+   you may not use this file except in compliance with the License.
+   You may obtain a copy of the License at
+
+       http://www.apache.org/licenses/LICENSE-2.0
+
+   Unless required by applicable law or agreed to in writing, software
+   distributed under the License is distributed on an "AS IS" BASIS,
+   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+   See the License for the specific language governing permissions and
+   limitations under the License.
+*/
+
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use log::{debug, warn};
+use tokio::io::unix::AsyncFd;
+
+use containerd_shim::asynchronous::monitor::monitor_notify_by_pid;
+use containerd_shim::util::asyncify;
+use containerd_shim::Result;
+
+use crate::state::{self, exit_status, is_alive, is_zombie, pidfd_open, ContainerState, PidFd};
+
+const EXIT_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+pub async fn save_state(bundle: impl AsRef<Path>, state: ContainerState) -> Result<()> {
+    let bundle = bundle.as_ref().to_path_buf();
+    asyncify(move || state::save_state(&bundle, &state)).await
+}
+
+pub async fn load_state(bundle: impl AsRef<Path>) -> Result<ContainerState> {
+    let bundle = bundle.as_ref().to_path_buf();
+    asyncify(move || state::load_state(&bundle)).await
+}
+
+pub async fn remove_state(bundle: impl AsRef<Path>) -> Result<()> {
+    let bundle = bundle.as_ref().to_path_buf();
+    asyncify(move || state::remove_state(&bundle)).await
+}
+
+pub async fn saved_bundles() -> Result<Vec<PathBuf>> {
+    asyncify(state::saved_bundles).await
+}
+
+/// A restored process is not a child of the shim, so its exit will not be reaped,
+/// watch it and notify the monitor when it exits.
+pub fn watch_exit(pid: i32, start_time: u64) {
+    tokio::spawn(async move {
+        let pidfd = wait_exit(pid, start_time).await;
+        let code = exit_status(pidfd.as_ref(), pid, start_time);
+        debug!("restored process {} exited with {}", pid, code);
+        monitor_notify_by_pid(pid, code)
+            .await
+            .unwrap_or_else(|e| warn!("failed to notify exit of process {}: {}", pid, e));
+    });
+}
+
+/// Wait for the process to exit, and return its pidfd if it was held until then.
+async fn wait_exit(pid: i32, start_time: u64) -> Option<PidFd> {
+    match pidfd_open(pid).and_then(AsyncFd::new) {
+        Ok(fd) => {
+            // the pid may be reused between the check of the caller and pidfd_open
+            if !is_alive(pid, start_time) {
+                return None;
+            }
+            // a pidfd becomes readable when the process exits.
+            if fd.readable().await.is_ok() {
+                return Some(fd.into_inner());
+            }
+        }
+        Err(e) if e.raw_os_error() == Some(libc::ESRCH) => return None,
+        Err(e) => debug!("failed to open pidfd of {}, poll it instead: {}", pid, e),
+    }
+    while is_alive(pid, start_time) && !is_zombie(pid) {
+        tokio::time::sleep(EXIT_POLL_INTERVAL).await;
+    }
+    None
+}
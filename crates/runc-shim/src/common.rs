@@ -14,8 +14,9 @@
    limitations under the License.
 */
 
+use std::env::current_dir;
 use std::os::unix::io::RawFd;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
 use log::{debug, warn};
@@ -27,6 +28,8 @@ use oci_spec::runtime::{LinuxNamespaceType, Spec};
 
 use containerd_shim::api::{ExecProcessRequest, Options};
 use containerd_shim::io::Stdio;
+#[cfg(not(feature = "async"))]
+use containerd_shim::util::Deadline;
 use containerd_shim::util::IntoOption;
 use containerd_shim::{io_error, other, other_error, Error};
 use runc::io::{Io, NullIo, FIFO};
@@ -38,6 +41,7 @@ pub const GROUP_LABELS: [&str; 2] = [
     "io.kubernetes.cri.sandbox-id",
 ];
 pub const INIT_PID_FILE: &str = "init.pid";
+const EVENTS_SPILL_FILE: &str = "events.spill";
 
 pub struct ProcessIO {
     pub uri: Option<String>,
@@ -99,8 +103,24 @@ pub fn create_io(
     Ok(pio)
 }
 
+/// File the events are spilled to while containerd is unreachable, in the work dir of the
+/// shim, which is the bundle of the first container it serves.
+pub fn events_spill_file() -> Option<PathBuf> {
+    match current_dir() {
+        Ok(dir) => Some(dir.join(EVENTS_SPILL_FILE)),
+        Err(e) => {
+            warn!("events are not spilled, failed to get work dir: {}", e);
+            None
+        }
+    }
+}
+
 #[derive(Default, Debug)]
-pub struct ShimExecutor {}
+pub struct ShimExecutor {
+    /// The command is killed once the deadline of the request running it passes.
+    #[cfg(not(feature = "async"))]
+    pub(crate) deadline: Deadline,
+}
 
 pub fn get_spec_from_request(
     req: &ExecProcessRequest,
@@ -114,6 +134,17 @@ pub fn get_spec_from_request(
     }
 }
 
+/// The error of a runc command killed by the [ShimExecutor] at the deadline of the request.
+pub fn deadline_exceeded(e: &runc::error::Error) -> Option<Error> {
+    match e {
+        runc::error::Error::Other(e) => match e.downcast_ref::<Error>() {
+            Some(Error::DeadlineExceeded(what)) => Some(Error::DeadlineExceeded(what.to_string())),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
 pub fn check_kill_error(emsg: String) -> Error {
     let emsg = emsg.to_lowercase();
     if emsg.contains("process already finished")
@@ -128,6 +159,31 @@ pub fn check_kill_error(emsg: String) -> Error {
     }
 }
 
+/// Convert the error of a runc command, classified by the stderr of runc, so that containerd
+/// recognizes it by the ttrpc code like the errors of the Go shim.
+pub fn runc_error(e: runc::error::Error, context: &str) -> Error {
+    if let Some(e) = deadline_exceeded(&e) {
+        return e;
+    }
+    let stderr = match &e {
+        runc::error::Error::CommandFailed { stderr, .. } => stderr.to_lowercase(),
+        _ => return other!("{}: {}", context, e),
+    };
+    let msg = format!("{}: {}", context, e);
+    if stderr.contains("does not exist") {
+        Error::NotFoundError(msg)
+    } else if stderr.contains("already exists") {
+        Error::AlreadyExists(msg)
+    } else if stderr.contains("not running")
+        || stderr.contains("not paused")
+        || stderr.contains("cannot start")
+    {
+        Error::FailedPreconditionError(msg)
+    } else {
+        Error::Other(msg)
+    }
+}
+
 const DEFAULT_RUNC_ROOT: &str = "/run/containerd/runc";
 const DEFAULT_COMMAND: &str = "runc";
 
@@ -0,0 +1,419 @@
+/*
+   Copyright The containerd Authors.
+
+   Licensed under the Apache License, Version 2.0 (the "License");
+   you may not use this file except in compliance with the License.
+   You may obtain a copy of the License at
+
+       http://www.apache.org/licenses/LICENSE-2.0
+
+   Unless required by applicable law or agreed to in writing, software
+   distributed under the License is distributed on an "AS IS" BASIS,
+   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+   See the License for the specific language governing permissions and
+   limitations under the License.
+*/
+
+//! State of the containers saved in their bundles, so that a shim started at the address
+//! of a shim that died can take over its containers.
+//!
+//! `start` of a grouped container spawns a new shim at the address of the group when the
+//! socket left by the previous shim is not working anymore. The new shim finds the bundles
+//! that have a saved state and the same address among the siblings of its own bundle.
+//!
+//! Restore does not reattach the stdio of the processes. stdio that is not a terminal is
+//! a set of fifos opened by runc, the process keeps writing to them and containerd keeps
+//! reading, so it works without the shim. A console is held by the shim that created it,
+//! so it is lost with that shim and can not be resized any more; a warning is logged.
+//! Neither is io that the shim copies itself, for the stdio schemes other than fifos,
+//! resumed by the new shim.
+
+use std::env::current_dir;
+use std::os::unix::io::{AsRawFd, RawFd};
+use std::path::{Path, PathBuf};
+
+use log::debug;
+use nix::ioctl_readwrite;
+use nix::sys::socket::{getsockname, SockAddr};
+use oci_spec::runtime::Process;
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+
+use containerd_shim::io::Stdio;
+use containerd_shim::{io_error, other, other_error, Error, Result};
+
+/// File in the bundle that the shim saves the state of the container to.
+pub const STATE_FILE_NAME: &str = "shim-state.json";
+
+const ADDRESS_FILE_NAME: &str = "address";
+
+/// Exit status reported for a restored process when its wait status is lost,
+/// it is the same as the unknown exit status of containerd.
+pub const UNKNOWN_EXIT_STATUS: i32 = 255;
+
+/// The listening socket is passed to the shim process as the fourth file descriptor.
+const SOCKET_FD: RawFd = 3;
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ContainerState {
+    pub id: String,
+    pub bundle: String,
+    pub rootfs: String,
+    pub init: ProcessState,
+    #[serde(default)]
+    pub execs: Vec<ExecState>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ExecState {
+    pub process: ProcessState,
+    pub spec: Process,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ProcessState {
+    pub id: String,
+    pub status: i32,
+    pub pid: i32,
+    /// start time of the process in clock ticks after boot, used to detect pid reuse.
+    #[serde(default)]
+    pub start_time: u64,
+    pub stdio: Stdio,
+    pub exit_code: i32,
+    /// unix timestamp in nanoseconds
+    #[serde(default)]
+    pub exited_at: Option<i64>,
+}
+
+impl ProcessState {
+    pub fn exited_at(&self) -> Option<OffsetDateTime> {
+        self.exited_at
+            .and_then(|t| OffsetDateTime::from_unix_timestamp_nanos(t as i128).ok())
+    }
+}
+
+pub fn timestamp_nanos(t: Option<OffsetDateTime>) -> Option<i64> {
+    t.map(|t| t.unix_timestamp_nanos() as i64)
+}
+
+/// Write the state to a temporary file and rename it,
+/// so that a shim dying in the middle never leaves a partial state.
+pub fn save_state(bundle: impl AsRef<Path>, state: &ContainerState) -> Result<()> {
+    let content = serde_json::to_string(state)?;
+    let path = bundle.as_ref().join(STATE_FILE_NAME);
+    let tmp_path = bundle.as_ref().join(format!(".{}", STATE_FILE_NAME));
+    std::fs::write(&tmp_path, content).map_err(io_error!(e, "write {}", tmp_path.display()))?;
+    std::fs::rename(&tmp_path, &path).map_err(io_error!(
+        e,
+        "rename tmp file to {}",
+        path.display()
+    ))?;
+    Ok(())
+}
+
+pub fn load_state(bundle: impl AsRef<Path>) -> Result<ContainerState> {
+    let path = bundle.as_ref().join(STATE_FILE_NAME);
+    let content =
+        std::fs::read_to_string(&path).map_err(io_error!(e, "read {}", path.display()))?;
+    serde_json::from_str(&content).map_err(other_error!(e, "parse {}", path.display()))
+}
+
+pub fn remove_state(bundle: impl AsRef<Path>) -> Result<()> {
+    let path = bundle.as_ref().join(STATE_FILE_NAME);
+    match std::fs::remove_file(&path) {
+        Err(e) if e.kind() != std::io::ErrorKind::NotFound => Err(Error::IoError {
+            context: format!("remove {}", path.display()),
+            err: e,
+        }),
+        _ => Ok(()),
+    }
+}
+
+/// Bundles that have a saved state and are served at the address this shim listens on.
+///
+/// The shim runs in the bundle of the container that started it, and every container
+/// grouped into the shim has the address of the shim written in its bundle.
+pub fn saved_bundles() -> Result<Vec<PathBuf>> {
+    let address = match listen_address() {
+        Some(address) => address,
+        None => return Ok(vec![]),
+    };
+    let cwd = current_dir().map_err(io_error!(e, "get current dir"))?;
+    let parent = cwd
+        .parent()
+        .ok_or_else(|| other!("bundle {} has no parent", cwd.display()))?;
+    saved_bundles_in(parent, &address)
+}
+
+/// Address of the socket passed to the shim, in the form written to the address file.
+fn listen_address() -> Option<String> {
+    unix_address(SOCKET_FD)
+}
+
+fn unix_address(fd: RawFd) -> Option<String> {
+    match getsockname(fd) {
+        Ok(SockAddr::Unix(addr)) => addr.path().map(|p| format!("unix://{}", p.display())),
+        Ok(_) => None,
+        Err(e) => {
+            debug!("failed to get the address of socket {}: {}", fd, e);
+            None
+        }
+    }
+}
+
+fn saved_bundles_in(parent: &Path, address: &str) -> Result<Vec<PathBuf>> {
+    let entries =
+        std::fs::read_dir(parent).map_err(io_error!(e, "read dir {}", parent.display()))?;
+    let mut bundles = vec![];
+    for entry in entries {
+        let path = entry
+            .map_err(io_error!(e, "read dir {}", parent.display()))?
+            .path();
+        if !path.join(STATE_FILE_NAME).exists() {
+            continue;
+        }
+        match std::fs::read_to_string(path.join(ADDRESS_FILE_NAME)) {
+            Ok(a) if a.trim() == address => bundles.push(path),
+            _ => debug!("bundle {} is not served by this shim", path.display()),
+        }
+    }
+    Ok(bundles)
+}
+
+/// Start time of the process, it is the 22nd field of /proc/[pid]/stat.
+pub fn process_start_time(pid: i32) -> Option<u64> {
+    let stat = read_stat(pid)?;
+    stat_field(&stat, 22)?.parse().ok()
+}
+
+fn read_stat(pid: i32) -> Option<String> {
+    std::fs::read_to_string(format!("/proc/{}/stat", pid)).ok()
+}
+
+/// The nth field of /proc/[pid]/stat, counted from 1 as in proc(5).
+fn stat_field(stat: &str, n: usize) -> Option<&str> {
+    // the command name in the 2nd field may contain spaces and parentheses,
+    // so the fields are counted from the last ')', which ends the 2nd field.
+    let fields = &stat[stat.rfind(')')? + 1..];
+    fields.split_whitespace().nth(n.checked_sub(3)?)
+}
+
+/// Whether the process is still the one that was saved, and has not been reaped.
+pub fn is_alive(pid: i32, start_time: u64) -> bool {
+    pid > 0 && start_time > 0 && process_start_time(pid) == Some(start_time)
+}
+
+/// Whether the process has exited but is not reaped by its parent yet.
+pub fn is_zombie(pid: i32) -> bool {
+    read_stat(pid).as_deref().and_then(|s| stat_field(s, 3)) == Some("Z")
+}
+
+/// Exit status of a restored process that has exited.
+///
+/// A restored process is not a child of this shim, so its wait status can not be
+/// collected by the reaper. While the exited process is a zombie not reaped by its
+/// new parent yet, the wait status is the 52nd field of /proc/[pid]/stat. Once it is
+/// reaped, the status is kept by the pidfd held while waiting for the exit, on kernels
+/// that support `PIDFD_GET_INFO`. Otherwise the status is lost, and `UNKNOWN_EXIT_STATUS`
+/// is returned, as containerd does.
+///
+/// The exits reaped by the shim itself are saved with the state, so the status is only
+/// unknown for a process that exits while no shim is running, or that is reaped before
+/// the new shim holds its pidfd.
+pub fn exit_status(pidfd: Option<&PidFd>, pid: i32, start_time: u64) -> i32 {
+    read_stat(pid)
+        .and_then(|stat| parse_exit_status(&stat, start_time))
+        .or_else(|| pidfd.and_then(pidfd_exit_status))
+        .unwrap_or(UNKNOWN_EXIT_STATUS)
+}
+
+fn parse_exit_status(stat: &str, start_time: u64) -> Option<i32> {
+    // the pid may have been reused by another process
+    if stat_field(stat, 22)?.parse::<u64>().ok()? != start_time {
+        return None;
+    }
+    // only a zombie has its wait status kept
+    if stat_field(stat, 3)? != "Z" {
+        return None;
+    }
+    stat_field(stat, 52)?.parse().ok().map(exit_code)
+}
+
+/// Exit code of a wait status, 128 plus the signal for a process killed by a signal.
+fn exit_code(status: i32) -> i32 {
+    let signal = status & 0x7f;
+    if signal == 0 {
+        (status >> 8) & 0xff
+    } else {
+        128 + signal
+    }
+}
+
+// from linux/pidfd.h
+const PIDFS_IOCTL_MAGIC: u8 = 0xFF;
+const PIDFD_GET_INFO: u8 = 11;
+const PIDFD_INFO_EXIT: u64 = 1 << 3;
+
+/// The first version of struct pidfd_info, which the kernel fills up to its own size.
+#[repr(C)]
+#[derive(Default)]
+struct PidFdInfo {
+    mask: u64,
+    _cgroupid: u64,
+    // pid, tgid, ppid and the real, effective, saved and fs uids and gids
+    _ids: [u32; 11],
+    exit_code: i32,
+}
+
+ioctl_readwrite!(pidfd_get_info, PIDFS_IOCTL_MAGIC, PIDFD_GET_INFO, PidFdInfo);
+
+/// Wait status of the process of the pidfd, which the kernel keeps for the pidfd once
+/// the process is reaped. It fails with ENOTTY on kernels before 6.15.
+fn pidfd_exit_status(pidfd: &PidFd) -> Option<i32> {
+    let mut info = PidFdInfo {
+        mask: PIDFD_INFO_EXIT,
+        ..Default::default()
+    };
+    // Safe because info outlives the ioctl, which doesn't keep a reference to it.
+    if let Err(e) = unsafe { pidfd_get_info(pidfd.as_raw_fd(), &mut info) } {
+        debug!("failed to get exit info of pidfd: {}", e);
+        return None;
+    }
+    if info.mask & PIDFD_INFO_EXIT == 0 {
+        return None;
+    }
+    Some(exit_code(info.exit_code))
+}
+
+pub struct PidFd(RawFd);
+
+impl AsRawFd for PidFd {
+    fn as_raw_fd(&self) -> RawFd {
+        self.0
+    }
+}
+
+impl Drop for PidFd {
+    fn drop(&mut self) {
+        unsafe {
+            libc::close(self.0);
+        }
+    }
+}
+
+/// Open a pidfd of the process, which becomes readable when the process exits.
+pub fn pidfd_open(pid: i32) -> std::io::Result<PidFd> {
+    let fd = unsafe { libc::syscall(libc::SYS_pidfd_open, pid, 0) };
+    if fd < 0 {
+        return Err(std::io::Error::last_os_error());
+    }
+    Ok(PidFd(fd as RawFd))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::os::unix::net::UnixListener;
+
+    use super::*;
+
+    #[test]
+    fn test_parse_start_time() {
+        let stat = "1234 (sleep) S 1 1234 1234 0 -1 4194560 105 0 0 0 0 0 0 0 20 0 1 0 \
+                    98765 2404352 128 18446744073709551615 1 1 0 0 0 0 0 0 0 0 0 0 17 3 0 0";
+        assert_eq!(stat_field(stat, 22), Some("98765"));
+
+        let stat = "1234 (a (b) c) S 1 1234 1234 0 -1 4194560 105 0 0 0 0 0 0 0 20 0 1 0 \
+                    4321 2404352 128 18446744073709551615 1 1 0 0 0 0 0 0 0 0 0 0 17 3 0 0";
+        assert_eq!(stat_field(stat, 22), Some("4321"));
+
+        assert_eq!(stat_field("1234 (sleep) S 1", 22), None);
+        assert_eq!(stat_field("", 22), None);
+    }
+
+    #[test]
+    fn test_parse_exit_status() {
+        let stat = |state: &str, status: i32| {
+            format!(
+                "1234 (sleep) {} 1 1234 1234 0 -1 4194560 105 0 0 0 0 0 0 0 20 0 1 0 \
+                 98765 0 0 18446744073709551615 0 0 0 0 0 0 0 0 0 0 0 0 17 3 0 0 \
+                 0 0 0 0 0 0 0 0 0 0 {}",
+                state, status
+            )
+        };
+        assert_eq!(parse_exit_status(&stat("Z", 3 << 8), 98765), Some(3));
+        assert_eq!(parse_exit_status(&stat("Z", 9), 98765), Some(137));
+        // the pid is reused by another process
+        assert_eq!(parse_exit_status(&stat("Z", 3 << 8), 4321), None);
+        // the process is still running
+        assert_eq!(parse_exit_status(&stat("S", 0), 98765), None);
+    }
+
+    #[test]
+    fn test_exit_status_of_zombie() {
+        let mut child = std::process::Command::new("sh")
+            .args(&["-c", "exit 3"])
+            .spawn()
+            .unwrap();
+        let pid = child.id() as i32;
+        let start_time = process_start_time(pid).unwrap();
+        // wait for it to become a zombie without reaping it
+        while !is_zombie(pid) {
+            std::thread::sleep(std::time::Duration::from_millis(10));
+        }
+        assert_eq!(exit_status(None, pid, start_time), 3);
+        child.wait().unwrap();
+        assert_eq!(exit_status(None, pid, start_time), UNKNOWN_EXIT_STATUS);
+    }
+
+    #[test]
+    fn test_exit_status_of_reaped() {
+        let mut child = std::process::Command::new("sh")
+            .args(&["-c", "exit 3"])
+            .spawn()
+            .unwrap();
+        let pid = child.id() as i32;
+        let start_time = process_start_time(pid).unwrap();
+        let pidfd = pidfd_open(pid).unwrap();
+        child.wait().unwrap();
+        // the kernel keeps the exit info only since 6.15
+        let expected = pidfd_exit_status(&pidfd).unwrap_or(UNKNOWN_EXIT_STATUS);
+        assert_eq!(exit_status(Some(&pidfd), pid, start_time), expected);
+        if expected != UNKNOWN_EXIT_STATUS {
+            assert_eq!(expected, 3);
+        }
+    }
+
+    #[test]
+    fn test_saved_bundles_in() {
+        let tmpdir = tempfile::tempdir().unwrap();
+        let address = "unix:///run/containerd/s/abc";
+        let bundle = |name: &str, address: &str, saved: bool| {
+            let path = tmpdir.path().join(name);
+            std::fs::create_dir(&path).unwrap();
+            std::fs::write(path.join(ADDRESS_FILE_NAME), address).unwrap();
+            if saved {
+                std::fs::write(path.join(STATE_FILE_NAME), "{}").unwrap();
+            }
+            path
+        };
+        let c1 = bundle("c1", address, true);
+        let c2 = bundle("c2", address, true);
+        bundle("c3", address, false);
+        bundle("c4", "unix:///run/containerd/s/def", true);
+
+        let mut bundles = saved_bundles_in(tmpdir.path(), address).unwrap();
+        bundles.sort();
+        assert_eq!(bundles, vec![c1, c2]);
+    }
+
+    #[test]
+    fn test_unix_address() {
+        let tmpdir = tempfile::tempdir().unwrap();
+        let path = tmpdir.path().join("shim.sock");
+        let listener = UnixListener::bind(&path).unwrap();
+        assert_eq!(
+            unix_address(listener.as_raw_fd()),
+            Some(format!("unix://{}", path.display()))
+        );
+    }
+}
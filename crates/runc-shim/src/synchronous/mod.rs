@@ -18,11 +18,9 @@ use std::sync::Arc;
 
 use containerd_shim::ExitSignal;
 
-mod container;
 mod io;
 mod runc;
 mod service;
-mod task;
 
 pub(crate) struct Service {
     exit: Arc<ExitSignal>,
@@ -13,52 +13,73 @@
    See the License for the specific language governing permissions and
    limitations under the License.
 */
-#![allow(unused)]
-
+use std::collections::HashMap;
 use std::convert::TryFrom;
+use std::fs::{File, OpenOptions};
 use std::io::Read;
+use std::os::unix::io::{AsRawFd, FromRawFd};
 use std::os::unix::prelude::ExitStatusExt;
 use std::path::{Path, PathBuf};
 use std::process::ExitStatus;
-use std::sync::mpsc::{Receiver, SyncSender};
 use std::sync::Arc;
+use std::thread::JoinHandle;
+use std::time::Duration;
 
-use log::{debug, error};
-use nix::sys::signal::kill;
+use log::{debug, error, warn};
+use nix::sys::signal::{kill, Signal};
 use nix::sys::stat::Mode;
 use nix::unistd::{mkdir, Pid};
-use oci_spec::runtime::{LinuxNamespaceType, LinuxResources};
+use oci_spec::runtime::{LinuxResources, Process};
 use time::OffsetDateTime;
 
 use containerd_shim as shim;
-use runc::{Command, Spawner};
+use runc::{Command, Runc, Spawner};
 use shim::api::*;
 use shim::console::ConsoleSocket;
+use shim::container::{ContainerFactory, ContainerTemplate, ProcessFactory};
 use shim::error::{Error, Result};
 use shim::io::Stdio;
-use shim::monitor::{monitor_subscribe, ExitEvent, Subject, Subscription, Topic};
-use shim::mount::mount_rootfs;
-use shim::protos::api::ProcessInfo;
+use shim::monitor::{
+    claim_exit, monitor_notify_by_pid, monitor_subscribe, ExitEvent, Subject, Subscription, Topic,
+};
+use shim::mount::{lookup_mount, unmount_rootfs};
+use shim::processes::{Process as _, ProcessLifecycle, ProcessTemplate};
+use shim::protos::api::{Mount, ProcessInfo};
 use shim::protos::cgroups::metrics::Metrics;
-use shim::protos::protobuf::{CodedInputStream, Message, RepeatedField};
-use shim::protos::shim::oci::ProcessDetails;
-use shim::util::{convert_to_any, read_spec_from_file, write_options, write_runtime, IntoOption};
+use shim::protos::protobuf::{CodedInputStream, Message, ProtobufEnum};
+use shim::util::{read_options, read_pid_from_file, write_options, write_runtime, Deadline};
 use shim::Console;
-use shim::{other, other_error};
+use shim::{io_error, other};
 
-use crate::common;
+use crate::common::receive_socket;
 use crate::common::{
-    create_io, has_shared_pid_namespace, CreateConfig, ShimExecutor, INIT_PID_FILE,
+    check_kill_error, create_io, create_runc, deadline_exceeded, get_spec_from_request, runc_error,
+    CreateConfig, ProcessIO, ShimExecutor, INIT_PID_FILE,
 };
-use crate::synchronous::container::{
-    CommonContainer, CommonProcess, Container, ContainerFactory, Process,
+use crate::state::{
+    exit_status, is_alive, is_zombie, load_state, pidfd_open, process_start_time, remove_state,
+    save_state, saved_bundles, timestamp_nanos, ContainerState, ExecState, PidFd, ProcessState,
+    UNKNOWN_EXIT_STATUS,
 };
+use crate::synchronous::io::spawn_copy;
+
+const EXIT_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+pub type ExecProcess = ProcessTemplate<RuncExecLifecycle>;
+pub type InitProcess = ProcessTemplate<RuncInitLifecycle>;
+
+pub type RuncContainer = ContainerTemplate<InitProcess, ExecProcess, RuncExecFactory>;
 
 #[derive(Clone, Default)]
 pub(crate) struct RuncFactory {}
 
 impl ContainerFactory<RuncContainer> for RuncFactory {
-    fn create(&self, ns: &str, req: &CreateTaskRequest) -> Result<RuncContainer> {
+    fn create(
+        &self,
+        ns: &str,
+        req: &CreateTaskRequest,
+        deadline: Deadline,
+    ) -> Result<RuncContainer> {
         let bundle = req.bundle.as_str();
         let mut opts = Options::new();
         if let Some(any) = req.options.as_ref() {
@@ -72,7 +93,7 @@ impl ContainerFactory<RuncContainer> for RuncFactory {
         write_options(bundle, &opts)?;
         write_runtime(bundle, runtime)?;
 
-        let rootfs_vec = req.get_rootfs().to_vec();
+        let rootfs_vec = req.get_rootfs();
         let rootfs = if !rootfs_vec.is_empty() {
             let tmp_rootfs = Path::new(bundle).join("rootfs");
             if !tmp_rootfs.as_path().exists() {
@@ -86,17 +107,98 @@ impl ContainerFactory<RuncContainer> for RuncFactory {
             .as_path()
             .to_str()
             .ok_or_else(|| other!("failed to convert rootfs to str"))?;
+
+        let res = self.create_with_rootfs(ns, req, &opts, rootfs_vec, rootfs, deadline);
+        if let Err(Error::DeadlineExceeded(_)) = res {
+            // runc is killed in the middle, remove the container it may have left.
+            match create_runc(
+                runtime,
+                ns,
+                bundle,
+                &opts,
+                Some(Arc::new(ShimExecutor::default())),
+            ) {
+                Ok(runc) => force_delete(&runc, req.get_id()),
+                Err(e) => warn!("failed to create runc to delete {}: {}", req.get_id(), e),
+            }
+        }
+        if res.is_err() && !rootfs_vec.is_empty() {
+            rollback_rootfs(rootfs);
+        }
+        res
+    }
+
+    fn cleanup(&self, _ns: &str, c: &RuncContainer) -> Result<()> {
+        let rootfs = &c.init.lifecycle.rootfs;
+        if !rootfs.is_empty() {
+            unmount_rootfs(rootfs, 0)?;
+        }
+        remove_state(&c.bundle)
+    }
+
+    fn save(&self, _ns: &str, c: &RuncContainer) -> Result<()> {
+        let state = ContainerState {
+            id: c.id.to_string(),
+            bundle: c.bundle.to_string(),
+            rootfs: c.init.lifecycle.rootfs.to_string(),
+            init: process_state(&c.init),
+            execs: c
+                .processes
+                .values()
+                .map(|p| ExecState {
+                    process: process_state(p),
+                    spec: p.lifecycle.spec.clone(),
+                })
+                .collect(),
+        };
+        save_state(&c.bundle, &state)
+    }
+
+    fn restore(&self, ns: &str) -> Result<Vec<RuncContainer>> {
+        let mut containers = vec![];
+        for bundle in saved_bundles()? {
+            match self.restore_container(ns, &bundle) {
+                Ok(c) => containers.push(c),
+                Err(e) => warn!(
+                    "failed to restore container from {}: {}",
+                    bundle.display(),
+                    e
+                ),
+            }
+        }
+        Ok(containers)
+    }
+}
+
+impl RuncFactory {
+    fn create_with_rootfs(
+        &self,
+        ns: &str,
+        req: &CreateTaskRequest,
+        opts: &Options,
+        rootfs_vec: &[Mount],
+        rootfs: &str,
+        deadline: Deadline,
+    ) -> Result<RuncContainer> {
         for m in rootfs_vec {
-            let mount_type = m.field_type.as_str().none_if(|&x| x.is_empty());
-            let source = m.source.as_str().none_if(|&x| x.is_empty());
-            mount_rootfs(mount_type, source, &m.options.to_vec(), rootfs)?;
+            shim::mount::Mount::from(m).mount(rootfs)?;
+        }
+        if !rootfs_vec.is_empty() {
+            // mountinfo has the real path of the mount point, while the bundle may be a symlink
+            let real =
+                std::fs::canonicalize(rootfs).map_err(io_error!(e, "canonicalize {}", rootfs))?;
+            if lookup_mount(&real)?.is_none() {
+                return Err(other!("rootfs {} is not mounted", rootfs));
+            }
         }
 
-        let runc = common::create_runc(
+        let bundle = req.bundle.as_str();
+        let runtime = opts.binary_name.as_str();
+        let runc = create_runc(
             runtime,
             ns,
             bundle,
-            &opts,
+            opts,
             Some(Arc::new(ShimExecutor::default())),
         )?;
 
@@ -108,530 +210,551 @@ impl ContainerFactory<RuncContainer> for RuncFactory {
             terminal: req.get_terminal(),
         };
 
-        let mut init = InitProcess::new(id, bundle, runc, stdio);
-        init.rootfs = rootfs.to_string();
-        let work_dir = Path::new(bundle).join("work");
-        let work_dir = work_dir
-            .as_path()
-            .to_str()
-            .ok_or_else(|| other!("failed to get work_dir str"))?;
-        init.work_dir = work_dir.to_string();
-        init.io_uid = opts.get_io_uid();
-        init.io_gid = opts.get_io_gid();
-        init.no_pivot_root = opts.get_no_pivot_root();
-        init.no_new_key_ring = opts.get_no_new_keyring();
-        init.criu_work_path = if opts.get_criu_path().is_empty() {
-            work_dir.to_string()
-        } else {
-            opts.get_criu_path().to_string()
-        };
+        let mut init = InitProcess::new(
+            id,
+            stdio,
+            RuncInitLifecycle::new(runc.clone(), opts.clone(), bundle, rootfs),
+        );
 
         let config = CreateConfig::default();
-        init.create(&config)?;
+        self.do_create(&mut init, config, deadline)?;
         let container = RuncContainer {
-            common: CommonContainer {
-                id: id.to_string(),
+            id: id.to_string(),
+            bundle: bundle.to_string(),
+            init,
+            process_factory: RuncExecFactory {
+                runtime: runc,
                 bundle: bundle.to_string(),
-                init,
-                processes: Default::default(),
+                io_uid: opts.io_uid,
+                io_gid: opts.io_gid,
             },
+            processes: Default::default(),
         };
         Ok(container)
     }
-}
 
-pub(crate) struct RuncContainer {
-    pub(crate) common: CommonContainer<InitProcess, ExecProcess>,
-}
+    fn do_create(
+        &self,
+        init: &mut InitProcess,
+        _config: CreateConfig,
+        deadline: Deadline,
+    ) -> Result<()> {
+        //TODO  checkpoint support
+        let id = init.id.to_string();
+        let stdio = &init.stdio;
+        let opts = &init.lifecycle.opts;
+        let bundle = &init.lifecycle.bundle;
+        let pid_path = Path::new(bundle).join(INIT_PID_FILE);
+        let mut create_opts = runc::options::CreateOpts::new()
+            .pid_file(&pid_path)
+            .no_pivot(opts.no_pivot_root)
+            .no_new_keyring(opts.no_new_keyring)
+            .detach(false);
+        let (socket, pio) = if stdio.terminal {
+            let s = ConsoleSocket::new()?;
+            create_opts.console_socket = Some(s.path.to_owned());
+            (Some(s), None)
+        } else {
+            let pio = create_io(&id, opts.io_uid, opts.io_gid, stdio)?;
+            create_opts.io = pio.io.as_ref().cloned();
+            (None, Some(pio))
+        };
 
-impl Container for RuncContainer {
-    fn start(&mut self, exec_id: Option<&str>) -> Result<i32> {
-        let id = self.id();
-        match exec_id {
-            Some(exec_id) => {
-                let process = self
-                    .common
-                    .processes
-                    .get_mut(exec_id)
-                    .ok_or_else(|| other!("can not find the exec by id"))?;
-                let pid_path = Path::new(self.common.bundle.as_str())
-                    .join(format!("{}.pid", &process.common.id));
-
-                let mut exec_opts = runc::options::ExecOpts {
-                    io: None,
-                    pid_file: Some(pid_path.to_owned()),
-                    console_socket: None,
-                    detach: true,
-                };
-                let terminal = process.common.stdio.terminal;
-                let socket = if terminal {
-                    let s = ConsoleSocket::new()?;
-                    exec_opts.console_socket = Some(s.path.to_owned());
-                    Some(s)
-                } else {
-                    let io = create_io(
-                        &process.common.id,
-                        self.common.init.io_uid,
-                        self.common.init.io_gid,
-                        &process.common.stdio,
-                    )?;
-
-                    process.common.io = Some(io);
-                    exec_opts.io = process
-                        .common
-                        .io
-                        .as_ref()
-                        .map(|x| &x.io)
-                        .unwrap_or(&None)
-                        .clone();
-                    None
-                };
-                //TODO  checkpoint support
-                self.common
-                    .init
-                    .runtime
-                    .exec(&id, &process.spec, Some(&exec_opts))
-                    .map_err(other_error!(e, "failed exec"))?;
-                if process.common.stdio.terminal {
-                    let console_socket =
-                        socket.ok_or_else(|| other!("failed to get console socket"))?;
-                    let console = process.common.copy_console(&console_socket)?;
-                    process.common.console = Some(console);
-                } else {
-                    process.common.copy_io()?;
-                }
-                process.common.set_pid_from_file(pid_path.as_path())?;
-                process.common.state = Status::RUNNING;
-                Ok(process.pid())
+        bounded(&init.lifecycle.runtime, deadline)
+            .create(&id, bundle, Some(&create_opts))
+            .map_err(|e| runc_error(e, "failed create"))?;
+        // runc has created the container, it is removed if the rest of the create fails
+        let res = copy_io_or_console(init, socket, pio).and_then(|_| read_pid_from_file(&pid_path));
+        match res {
+            Ok(pid) => {
+                init.pid = pid;
+                Ok(())
             }
-            None => {
-                self.common
-                    .init
-                    .runtime
-                    .start(&id)
-                    .map_err(other_error!(e, "failed start"))?;
-                self.common.init.common.set_status(Status::RUNNING);
-                Ok(self.pid())
+            Err(e) => {
+                force_delete(&init.lifecycle.runtime, &id);
+                Err(e)
             }
         }
     }
 
-    fn state(&self, exec_id: Option<&str>) -> Result<StateResponse> {
-        self.common.state(exec_id)
-    }
+    fn restore_container(&self, ns: &str, bundle: &Path) -> Result<RuncContainer> {
+        let state = load_state(bundle)?;
+        let opts = read_options(bundle)?;
+        let bundle = bundle.to_string_lossy().to_string();
+        let runc = create_runc(
+            opts.binary_name.as_str(),
+            ns,
+            &bundle,
+            &opts,
+            Some(Arc::new(ShimExecutor::default())),
+        )?;
 
-    fn kill(&mut self, exec_id: Option<&str>, signal: u32, all: bool) -> Result<()> {
-        match exec_id {
-            Some(_) => {
-                let p = self.common.get_mut_process(exec_id)?;
-                kill_process(p.pid() as u32, p.exited_at(), signal)
-                    .map_err(|e| common::check_kill_error(format!("{}", e)))
+        let mut init = InitProcess::new(
+            &state.id,
+            state.init.stdio.clone(),
+            RuncInitLifecycle::new(runc.clone(), opts.clone(), &bundle, &state.rootfs),
+        );
+        restore_process(&mut init, &state.init);
+        if init.exited_at.is_none() {
+            // the init process is alive, take the status from runc, it may be paused.
+            match runc.state(&state.id) {
+                Ok(c) => match c.status.as_str() {
+                    "created" => init.state = Status::CREATED,
+                    "running" => init.state = Status::RUNNING,
+                    "paused" => init.state = Status::PAUSED,
+                    "pausing" => init.state = Status::PAUSING,
+                    _ => {}
+                },
+                Err(e) => warn!("failed to get runc state of {}: {}", state.id, e),
             }
-            None => self
-                .common
-                .init
-                .runtime
-                .kill(
-                    self.id().as_str(),
-                    signal,
-                    Some(&runc::options::KillOpts { all }),
-                )
-                .map_err(|e| common::check_kill_error(format!("{}", e))),
         }
-    }
-
-    fn wait_channel(&mut self, exec_id: Option<&str>) -> Result<Receiver<i8>> {
-        self.common.wait_channel(exec_id)
-    }
 
-    fn get_exit_info(&self, exec_id: Option<&str>) -> Result<(i32, i32, Option<OffsetDateTime>)> {
-        self.common.get_exit_info(exec_id)
-    }
-
-    fn delete(&mut self, exec_id_opt: Option<&str>) -> Result<(i32, i32, Option<OffsetDateTime>)> {
-        let (pid, code, exited_at) = self
-            .get_exit_info(exec_id_opt)
-            .map_err(other_error!(e, "failed to get exit info"))?;
-        match exec_id_opt {
-            Some(exec_id) => {
-                self.common.processes.remove(exec_id);
-            }
-            None => {
-                self.common
-                    .init
-                    .runtime
-                    .delete(
-                        self.id().as_str(),
-                        Some(&runc::options::DeleteOpts { force: true }),
-                    )
-                    .or_else(|e| {
-                        if !e.to_string().to_lowercase().contains("does not exist") {
-                            Err(e)
-                        } else {
-                            Ok(())
-                        }
-                    })
-                    .map_err(other_error!(e, "failed delete"))?;
-            }
-        };
-        Ok((pid, code, exited_at))
-    }
-
-    fn exec(&mut self, req: ExecProcessRequest) -> Result<()> {
-        self.common
-            .exec(req)
-            .map_err(other_error!(e, "failed exec"))
-    }
+        let mut processes = HashMap::new();
+        for e in state.execs {
+            let mut p = ExecProcess::new(
+                &e.process.id,
+                e.process.stdio.clone(),
+                RuncExecLifecycle {
+                    runtime: runc.clone(),
+                    bundle: bundle.to_string(),
+                    container_id: state.id.to_string(),
+                    io_uid: opts.io_uid,
+                    io_gid: opts.io_gid,
+                    spec: e.spec,
+                },
+            );
+            restore_process(&mut p, &e.process);
+            processes.insert(p.id.to_string(), p);
+        }
 
-    fn resize_pty(&mut self, exec_id: Option<&str>, height: u32, width: u32) -> Result<()> {
-        self.common
-            .resize_pty(exec_id, height, width)
-            .map_err(other_error!(e, "failed resize pty"))
+        Ok(RuncContainer {
+            id: state.id,
+            bundle: bundle.to_string(),
+            init,
+            process_factory: RuncExecFactory {
+                runtime: runc,
+                bundle,
+                io_uid: opts.io_uid,
+                io_gid: opts.io_gid,
+            },
+            processes,
+        })
     }
+}
 
-    fn pid(&self) -> i32 {
-        self.common.init.pid()
+fn process_state<S>(p: &ProcessTemplate<S>) -> ProcessState {
+    let start_time = if p.pid > 0 && p.exited_at.is_none() {
+        process_start_time(p.pid).unwrap_or_default()
+    } else {
+        0
+    };
+    ProcessState {
+        id: p.id.to_string(),
+        status: p.state.value(),
+        pid: p.pid,
+        start_time,
+        stdio: p.stdio.clone(),
+        exit_code: p.exit_code,
+        exited_at: timestamp_nanos(p.exited_at),
     }
+}
 
-    #[cfg(target_os = "linux")]
-    fn stats(&self) -> Result<Metrics> {
-        let pid = self.common.init.pid() as u32;
-        containerd_shim::cgroup::collect_metrics(pid)
+/// Restore the process from the saved state, the stdio of a process not on a terminal
+/// are the fifos opened by runc itself, so it keeps writing to them without the shim,
+/// but a console can not be recovered once the shim holding it exited.
+fn restore_process<S>(p: &mut ProcessTemplate<S>, s: &ProcessState)
+where
+    S: ProcessLifecycle<ProcessTemplate<S>>,
+{
+    p.state = Status::from_i32(s.status).unwrap_or(Status::UNKNOWN);
+    p.pid = s.pid;
+    p.exit_code = s.exit_code;
+    p.exited_at = s.exited_at();
+    if p.exited_at.is_some() || p.pid <= 0 {
+        return;
+    }
+    if !is_alive(s.pid, s.start_time) {
+        // the process has exited and been reaped while no shim was serving it,
+        // so its wait status is lost.
+        debug!(
+            "process {} with pid {} exited while shim was down",
+            p.id, p.pid
+        );
+        p.set_exited(UNKNOWN_EXIT_STATUS);
+        return;
     }
-
-    #[cfg(not(target_os = "linux"))]
-    fn stats(&self) -> Result<Metrics> {
-        Err(Error::Unimplemented("stats".to_string()))
+    if p.stdio.terminal {
+        warn!("console of process {} can not be recovered", p.id);
     }
+    watch_exit(s.pid, s.start_time);
+}
 
-    #[cfg(target_os = "linux")]
-    fn update(&mut self, resources: &LinuxResources) -> Result<()> {
-        let pid = self.common.init.pid() as u32;
-        containerd_shim::cgroup::update_resources(pid, resources)
-    }
+/// A restored process is not a child of the shim, so its exit will not be reaped,
+/// watch it and notify the monitor when it exits.
+fn watch_exit(pid: i32, start_time: u64) {
+    std::thread::spawn(move || {
+        let pidfd = wait_exit(pid, start_time);
+        let code = exit_status(pidfd.as_ref(), pid, start_time);
+        debug!("restored process {} exited with {}", pid, code);
+        monitor_notify_by_pid(pid, code)
+            .unwrap_or_else(|e| warn!("failed to notify exit of process {}: {}", pid, e));
+    });
+}
 
-    #[cfg(not(target_os = "linux"))]
-    fn update(&mut self, resources: &LinuxResources) -> Result<()> {
-        Err(Error::Unimplemented("update".to_string()))
-    }
-
-    fn pids(&self) -> Result<PidsResponse> {
-        let pids = self
-            .common
-            .init
-            .runtime
-            .ps(self.common.init.id())
-            .map_err(other_error!(e, "failed to ps"))?;
-        let mut processes: Vec<ProcessInfo> = Vec::new();
-        for pid in pids {
-            let mut p_info = ProcessInfo {
-                pid: pid as u32,
-                ..Default::default()
+/// Wait for the process to exit, and return its pidfd if it was held until then.
+fn wait_exit(pid: i32, start_time: u64) -> Option<PidFd> {
+    match pidfd_open(pid) {
+        Ok(fd) => {
+            // the pid may be reused between the check of the caller and pidfd_open
+            if !is_alive(pid, start_time) {
+                return None;
+            }
+            // a pidfd becomes readable when the process exits.
+            let mut pfd = libc::pollfd {
+                fd: fd.as_raw_fd(),
+                events: libc::POLLIN,
+                revents: 0,
             };
-            for process in self.common.processes.values() {
-                if process.common.pid as usize == pid {
-                    let details = ProcessDetails {
-                        exec_id: "".to_string(),
-                        ..Default::default()
-                    };
-                    p_info.set_info(convert_to_any(Box::new(details))?);
+            loop {
+                if unsafe { libc::poll(&mut pfd, 1, -1) } >= 0 {
+                    return Some(fd);
+                }
+                let e = std::io::Error::last_os_error();
+                if e.kind() != std::io::ErrorKind::Interrupted {
+                    debug!("failed to poll pidfd of {}, poll it instead: {}", pid, e);
                     break;
                 }
             }
-            processes.push(p_info);
         }
-        let resp = PidsResponse {
-            processes: RepeatedField::from(processes),
-            ..Default::default()
-        };
-        Ok(resp)
+        Err(e) if e.raw_os_error() == Some(libc::ESRCH) => return None,
+        Err(e) => debug!("failed to open pidfd of {}, poll it instead: {}", pid, e),
     }
-
-    fn id(&self) -> String {
-        self.common.id.to_string()
+    while is_alive(pid, start_time) && !is_zombie(pid) {
+        std::thread::sleep(EXIT_POLL_INTERVAL);
     }
+    None
 }
 
-impl RuncContainer {
-    pub(crate) fn should_kill_all_on_exit(&mut self, bundle_path: &str) -> bool {
-        match read_spec_from_file(bundle_path) {
-            Ok(spec) => has_shared_pid_namespace(&spec),
-            Err(e) => {
-                error!("should_kill_all_on_exit: {}", e);
-                false
-            }
-        }
-    }
-}
-
-fn kill_process(pid: u32, exit_at: Option<OffsetDateTime>, sig: u32) -> Result<()> {
-    if pid == 0 {
-        Err(Error::FailedPreconditionError(
-            "process not created".to_string(),
-        ))
-    } else if exit_at.is_some() {
-        Err(Error::NotFoundError("process already finished".to_string()))
-    } else {
-        kill(
-            Pid::from_raw(pid as i32),
-            nix::sys::signal::Signal::try_from(sig as i32).unwrap(),
-        )
-        .map_err(Into::into)
-    }
+/// Remove the runc container that failed to be created, if runc has left it.
+fn force_delete(runc: &Runc, id: &str) {
+    runc.delete(id, Some(&runc::options::DeleteOpts { force: true }))
+        .unwrap_or_else(|e| debug!("failed to delete runc container {}: {}", id, e));
 }
 
-pub(crate) struct InitProcess {
-    pub(crate) common: CommonProcess,
-    pub(crate) bundle: String,
-    pub(crate) runtime: runc::Runc,
-    pub(crate) rootfs: String,
-    pub(crate) work_dir: String,
-    pub(crate) io_uid: u32,
-    pub(crate) io_gid: u32,
-    pub(crate) no_pivot_root: bool,
-    pub(crate) no_new_key_ring: bool,
-    pub(crate) criu_work_path: String,
+/// Runc running its commands until the deadline of the request.
+fn bounded(runtime: &Runc, deadline: Deadline) -> Runc {
+    runtime.with_spawner(Arc::new(ShimExecutor { deadline }))
 }
 
-impl InitProcess {
-    pub fn new(id: &str, bundle: &str, runtime: runc::Runc, stdio: Stdio) -> Self {
-        InitProcess {
-            common: CommonProcess {
-                state: Status::CREATED,
-                id: id.to_string(),
-                stdio,
-                pid: 0,
-                io: None,
-                exit_code: 0,
-                exited_at: None,
-                wait_chan_tx: vec![],
-                console: None,
-            },
-            bundle: bundle.to_string(),
-            runtime,
-            rootfs: "".to_string(),
-            work_dir: "".to_string(),
-            io_uid: 0,
-            io_gid: 0,
-            no_pivot_root: false,
-            no_new_key_ring: false,
-            criu_work_path: "".to_string(),
-        }
+/// Undo the rootfs mounts of a container that failed to be created, and remove the
+/// rootfs directory that was made for them.
+fn rollback_rootfs(rootfs: &str) {
+    if let Err(e) = unmount_rootfs(rootfs, 0) {
+        error!("failed to unmount rootfs {}: {}", rootfs, e);
+        return;
     }
-
-    pub fn create(&mut self, _conf: &CreateConfig) -> Result<()> {
-        //TODO  checkpoint support
-        let id = self.common.id.to_string();
-        let terminal = self.common.stdio.terminal;
-        let bundle = self.bundle.to_string();
-        let pid_path = Path::new(&bundle).join(INIT_PID_FILE);
-        let mut create_opts = runc::options::CreateOpts::new()
-            .pid_file(&pid_path)
-            .no_pivot(self.no_pivot_root)
-            .no_new_keyring(self.no_new_key_ring)
-            .detach(false);
-        let socket = if terminal {
-            let s = ConsoleSocket::new()?;
-            create_opts.console_socket = Some(s.path.to_owned());
-            Some(s)
-        } else {
-            let io = create_io(&id, self.io_uid, self.io_gid, &self.common.stdio)?;
-            self.common.io = Some(io);
-            create_opts.io = self
-                .common
-                .io
-                .as_ref()
-                .map(|x| &x.io)
-                .unwrap_or(&None)
-                .clone();
-            None
-        };
-
-        self.runtime
-            .create(&id, &bundle, Some(&create_opts))
-            .map_err(other_error!(e, "failed create"))?;
-        if terminal {
-            let console_socket = socket.ok_or_else(|| other!("failed to get console socket"))?;
-            let console = self.common.copy_console(&console_socket)?;
-            self.common.console = Some(console);
-        } else {
-            self.common.copy_io()?;
-        }
-        self.common.set_pid_from_file(pid_path.as_path())?;
-        Ok(())
+    if let Err(e) = std::fs::remove_dir(rootfs) {
+        warn!("failed to remove rootfs dir {}: {}", rootfs, e);
     }
 }
 
-impl Process for InitProcess {
-    fn set_exited(&mut self, exit_code: i32) {
-        self.common.set_exited(exit_code);
-    }
-
-    fn id(&self) -> &str {
-        self.common.id()
-    }
-
-    fn status(&self) -> Status {
-        self.common.status()
-    }
-
-    fn set_status(&mut self, status: Status) {
-        self.common.set_status(status)
-    }
-
-    fn pid(&self) -> i32 {
-        self.common.pid()
-    }
-
-    fn terminal(&self) -> bool {
-        self.common.terminal()
-    }
-
-    fn stdin(&self) -> String {
-        self.common.stdin()
-    }
-
-    fn stdout(&self) -> String {
-        self.common.stdout()
-    }
-
-    fn stderr(&self) -> String {
-        self.common.stderr()
-    }
-
-    fn state(&self) -> StateResponse {
-        self.common.state()
-    }
-
-    fn add_wait(&mut self, tx: SyncSender<i8>) {
-        self.common.add_wait(tx)
-    }
-
-    fn exit_code(&self) -> i32 {
-        self.common.exit_code()
-    }
-
-    fn exited_at(&self) -> Option<OffsetDateTime> {
-        self.common.exited_at()
-    }
-
-    fn copy_console(&self, console_socket: &ConsoleSocket) -> Result<Console> {
-        self.common.copy_console(console_socket)
-    }
-
-    fn copy_io(&self) -> Result<()> {
-        self.common.copy_io()
-    }
-
-    fn set_pid_from_file(&mut self, pid_path: &Path) -> Result<()> {
-        self.common.set_pid_from_file(pid_path)
-    }
+pub struct RuncExecFactory {
+    runtime: Runc,
+    bundle: String,
+    io_uid: u32,
+    io_gid: u32,
+}
 
-    fn resize_pty(&mut self, height: u32, width: u32) -> Result<()> {
-        self.common.resize_pty(height, width)
+impl ProcessFactory<ExecProcess> for RuncExecFactory {
+    fn create(&self, req: &ExecProcessRequest, _deadline: Deadline) -> Result<ExecProcess> {
+        let p = get_spec_from_request(req)?;
+        Ok(ExecProcess::new(
+            req.get_exec_id(),
+            Stdio {
+                stdin: req.stdin.to_string(),
+                stdout: req.stdout.to_string(),
+                stderr: req.stderr.to_string(),
+                terminal: req.terminal,
+            },
+            RuncExecLifecycle {
+                runtime: self.runtime.clone(),
+                bundle: self.bundle.to_string(),
+                container_id: req.id.to_string(),
+                io_uid: self.io_uid,
+                io_gid: self.io_gid,
+                spec: p,
+            },
+        ))
     }
 }
 
-pub(crate) struct ExecProcess {
-    pub(crate) common: CommonProcess,
-    pub(crate) spec: oci_spec::runtime::Process,
+pub struct RuncInitLifecycle {
+    runtime: Runc,
+    opts: Options,
+    bundle: String,
+    rootfs: String,
 }
 
-impl Process for ExecProcess {
-    fn set_exited(&mut self, exit_code: i32) {
-        self.common.set_exited(exit_code);
-    }
-
-    fn id(&self) -> &str {
-        self.common.id()
+impl ProcessLifecycle<InitProcess> for RuncInitLifecycle {
+    fn start(&self, p: &mut InitProcess, deadline: Deadline) -> Result<()> {
+        // runc may have started the container when it is killed at the deadline,
+        // the workload is left alone for the client to query or delete.
+        bounded(&self.runtime, deadline)
+            .start(p.id.as_str())
+            .map_err(|e| runc_error(e, "failed start"))?;
+        p.state = Status::RUNNING;
+        Ok(())
     }
 
-    fn status(&self) -> Status {
-        self.common.status()
+    fn kill(&self, p: &mut InitProcess, signal: u32, all: bool, deadline: Deadline) -> Result<()> {
+        bounded(&self.runtime, deadline)
+            .kill(
+                p.id.as_str(),
+                signal,
+                Some(&runc::options::KillOpts { all }),
+            )
+            .map_err(|e| deadline_exceeded(&e).unwrap_or_else(|| check_kill_error(e.to_string())))
+    }
+
+    fn delete(&self, p: &mut InitProcess, deadline: Deadline) -> Result<()> {
+        bounded(&self.runtime, deadline)
+            .delete(
+                p.id.as_str(),
+                Some(&runc::options::DeleteOpts { force: true }),
+            )
+            .or_else(|e| {
+                if !e.to_string().to_lowercase().contains("does not exist") {
+                    Err(e)
+                } else {
+                    Ok(())
+                }
+            })
+            .map_err(|e| runc_error(e, "failed delete"))
     }
 
-    fn set_status(&mut self, status: Status) {
-        self.common.set_status(status)
+    #[cfg(target_os = "linux")]
+    fn update(
+        &self,
+        p: &mut InitProcess,
+        resources: &LinuxResources,
+        deadline: Deadline,
+    ) -> Result<()> {
+        // writing the cgroup files can not be cancelled halfway
+        deadline.check("update")?;
+        if p.pid <= 0 {
+            return Err(other!(
+                "failed to update resources because init process is {}",
+                p.pid
+            ));
+        }
+        containerd_shim::cgroup::update_resources(p.pid as u32, resources)
     }
 
-    fn pid(&self) -> i32 {
-        self.common.pid()
+    #[cfg(not(target_os = "linux"))]
+    fn update(
+        &self,
+        _p: &mut InitProcess,
+        _resources: &LinuxResources,
+        _deadline: Deadline,
+    ) -> Result<()> {
+        Err(Error::Unimplemented("update resource".to_string()))
     }
 
-    fn terminal(&self) -> bool {
-        self.common.terminal()
+    #[cfg(target_os = "linux")]
+    fn stats(&self, p: &InitProcess, deadline: Deadline) -> Result<Metrics> {
+        deadline.check("stats")?;
+        if p.pid <= 0 {
+            return Err(other!(
+                "failed to collect metrics because init process is {}",
+                p.pid
+            ));
+        }
+        containerd_shim::cgroup::collect_metrics(p.pid as u32)
     }
 
-    fn stdin(&self) -> String {
-        self.common.stdin()
+    #[cfg(not(target_os = "linux"))]
+    fn stats(&self, _p: &InitProcess, _deadline: Deadline) -> Result<Metrics> {
+        Err(Error::Unimplemented("process stats".to_string()))
+    }
+
+    fn ps(&self, p: &InitProcess, deadline: Deadline) -> Result<Vec<ProcessInfo>> {
+        let pids = bounded(&self.runtime, deadline).ps(&*p.id).map_err(|e| {
+            deadline_exceeded(&e).unwrap_or_else(|| other!("failed to execute runc ps: {}", e))
+        })?;
+        Ok(pids
+            .iter()
+            .map(|&x| ProcessInfo {
+                pid: x as u32,
+                ..Default::default()
+            })
+            .collect())
     }
+}
 
-    fn stdout(&self) -> String {
-        self.common.stdout()
+impl RuncInitLifecycle {
+    pub fn new(runtime: Runc, opts: Options, bundle: &str, rootfs: &str) -> Self {
+        let work_dir = Path::new(bundle).join("work");
+        let mut opts = opts;
+        if opts.get_criu_path().is_empty() {
+            opts.criu_path = work_dir.to_string_lossy().to_string();
+        }
+        Self {
+            runtime,
+            opts,
+            bundle: bundle.to_string(),
+            rootfs: rootfs.to_string(),
+        }
     }
+}
 
-    fn stderr(&self) -> String {
-        self.common.stderr()
-    }
+pub struct RuncExecLifecycle {
+    runtime: Runc,
+    bundle: String,
+    container_id: String,
+    io_uid: u32,
+    io_gid: u32,
+    spec: Process,
+}
 
-    fn state(&self) -> StateResponse {
-        self.common.state()
+impl ProcessLifecycle<ExecProcess> for RuncExecLifecycle {
+    fn start(&self, p: &mut ExecProcess, deadline: Deadline) -> Result<()> {
+        let pid_path = Path::new(self.bundle.as_str()).join(format!("{}.pid", &p.id));
+        let mut exec_opts = runc::options::ExecOpts {
+            io: None,
+            pid_file: Some(pid_path.to_owned()),
+            console_socket: None,
+            detach: true,
+        };
+        let (socket, pio) = if p.stdio.terminal {
+            let s = ConsoleSocket::new()?;
+            exec_opts.console_socket = Some(s.path.to_owned());
+            (Some(s), None)
+        } else {
+            let pio = create_io(&p.id, self.io_uid, self.io_gid, &p.stdio)?;
+            exec_opts.io = pio.io.as_ref().cloned();
+            (None, Some(pio))
+        };
+        //TODO  checkpoint support
+        let res = bounded(&self.runtime, deadline)
+            .exec(&self.container_id, &self.spec, Some(&exec_opts))
+            .map_err(|e| runc_error(e, "failed exec"));
+        if let Err(e @ Error::DeadlineExceeded(_)) = res {
+            // runc may have started the process before it is killed.
+            if let Ok(pid) = read_pid_from_file(&pid_path) {
+                kill(Pid::from_raw(pid), Signal::SIGKILL)
+                    .unwrap_or_else(|e| debug!("failed to kill exec {}: {}", p.id, e));
+            }
+            return Err(e);
+        }
+        res?;
+        copy_io_or_console(p, socket, pio)?;
+        p.pid = read_pid_from_file(&pid_path)?;
+        p.state = Status::RUNNING;
+        Ok(())
     }
 
-    fn add_wait(&mut self, tx: SyncSender<i8>) {
-        self.common.add_wait(tx)
+    fn kill(&self, p: &mut ExecProcess, signal: u32, _all: bool, deadline: Deadline) -> Result<()> {
+        deadline.check("kill")?;
+        kill_process(p.pid as u32, p.exited_at, signal)
+            .map_err(|e| check_kill_error(format!("{}", e)))
     }
 
-    fn exit_code(&self) -> i32 {
-        self.common.exit_code()
+    fn delete(&self, _p: &mut ExecProcess, _deadline: Deadline) -> Result<()> {
+        Ok(())
     }
 
-    fn exited_at(&self) -> Option<OffsetDateTime> {
-        self.common.exited_at()
+    fn update(
+        &self,
+        _p: &mut ExecProcess,
+        _resources: &LinuxResources,
+        _deadline: Deadline,
+    ) -> Result<()> {
+        Err(Error::Unimplemented("exec update".to_string()))
     }
 
-    fn copy_console(&self, console_socket: &ConsoleSocket) -> Result<Console> {
-        self.common.copy_console(console_socket)
+    fn stats(&self, _p: &ExecProcess, _deadline: Deadline) -> Result<Metrics> {
+        Err(Error::Unimplemented("exec stats".to_string()))
     }
 
-    fn copy_io(&self) -> Result<()> {
-        self.common.copy_io()
+    fn ps(&self, _p: &ExecProcess, _deadline: Deadline) -> Result<Vec<ProcessInfo>> {
+        Err(Error::Unimplemented("exec ps".to_string()))
     }
+}
 
-    fn set_pid_from_file(&mut self, pid_path: &Path) -> Result<()> {
-        self.common.set_pid_from_file(pid_path)
+fn kill_process(pid: u32, exit_at: Option<OffsetDateTime>, sig: u32) -> Result<()> {
+    if pid == 0 {
+        Err(Error::FailedPreconditionError(
+            "process not created".to_string(),
+        ))
+    } else if exit_at.is_some() {
+        Err(Error::NotFoundError("process already finished".to_string()))
+    } else {
+        kill(
+            Pid::from_raw(pid as i32),
+            nix::sys::signal::Signal::try_from(sig as i32).unwrap(),
+        )
+        .map_err(Into::into)
     }
+}
 
-    fn resize_pty(&mut self, height: u32, width: u32) -> Result<()> {
-        self.common.resize_pty(height, width)
+fn copy_console(console_socket: &ConsoleSocket, stdio: &Stdio) -> Result<Console> {
+    debug!("copy_console: waiting for runtime to send console fd");
+    let stream = console_socket
+        .accept()
+        .map_err(io_error!(e, "accept console socket"))?;
+    let fd = receive_socket(stream.as_raw_fd())?;
+
+    if !stdio.stdin.is_empty() {
+        debug!("copy_console: pipe stdin to console");
+        let f = unsafe { File::from_raw_fd(fd) };
+        let stdin = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .open(stdio.stdin.as_str())
+            .map_err(io_error!(e, "open stdin"))?;
+        spawn_copy(stdin, f, None, None);
+    }
+
+    if !stdio.stdout.is_empty() {
+        let f = unsafe { File::from_raw_fd(fd) };
+        debug!("copy_console: pipe stdout from console");
+        let stdout = OpenOptions::new()
+            .write(true)
+            .open(stdio.stdout.as_str())
+            .map_err(io_error!(e, "open stdout"))?;
+        // open a read to make sure even if the read end of containerd shutdown,
+        // copy still continue until the restart of containerd succeed
+        let stdout_r = OpenOptions::new()
+            .read(true)
+            .open(stdio.stdout.as_str())
+            .map_err(io_error!(e, "open stdout for read"))?;
+        spawn_copy(
+            f,
+            stdout,
+            None,
+            Some(Box::new(move || {
+                drop(stdout_r);
+            })),
+        );
     }
+    let console = Console {
+        file: unsafe { File::from_raw_fd(fd) },
+    };
+    Ok(console)
 }
 
-impl TryFrom<ExecProcessRequest> for ExecProcess {
-    type Error = Error;
-    fn try_from(req: ExecProcessRequest) -> std::result::Result<Self, Self::Error> {
-        let p = common::get_spec_from_request(&req)?;
-        let exec_process = ExecProcess {
-            common: CommonProcess {
-                state: Status::CREATED,
-                id: req.exec_id,
-                stdio: Stdio {
-                    stdin: req.stdin,
-                    stdout: req.stdout,
-                    stderr: req.stderr,
-                    terminal: req.terminal,
-                },
-                pid: 0,
-                io: None,
-                exit_code: 0,
-                exited_at: None,
-                wait_chan_tx: vec![],
-                console: None,
-            },
-            spec: p,
-        };
-        Ok(exec_process)
-    }
+fn copy_io_or_console<S>(
+    p: &mut ProcessTemplate<S>,
+    socket: Option<ConsoleSocket>,
+    pio: Option<ProcessIO>,
+) -> Result<()> {
+    if p.stdio.terminal {
+        let console_socket = socket.ok_or_else(|| other!("failed to get console socket"))?;
+        p.console = Some(copy_console(&console_socket, &p.stdio)?);
+    } else if let Some(pio) = pio {
+        pio.copy(&p.stdio)?;
+    }
+    Ok(())
 }
 
 impl Spawner for ShimExecutor {
@@ -639,50 +762,57 @@ impl Spawner for ShimExecutor {
         let mut cmd = cmd;
         let subscription =
             monitor_subscribe(Topic::Pid).map_err(|e| runc::error::Error::Other(Box::new(e)))?;
-        let child = match cmd.spawn() {
+        let mut child = match cmd.spawn() {
             Ok(c) => c,
             Err(e) => {
                 return Err(runc::error::Error::ProcessSpawnFailed(e));
             }
         };
         let pid = child.id();
-        // May block here when stream exceeds buffer size, it's better to spawn another thread for io copy
-        let (stdout, stderr, exit_code) = (
-            read_std(child.stdout),
-            read_std(child.stderr),
-            wait_pid(pid as i32, subscription),
-        );
+        claim_exit(pid as i32);
+        // the outputs are read by threads of their own, so the exit is waited until the deadline
+        let (stdout, stderr) = (read_std(child.stdout.take()), read_std(child.stderr.take()));
+        let exit_code = match wait_pid(pid as i32, subscription, self.deadline) {
+            Ok(code) => code,
+            Err(e) => {
+                // the exit of the killed runc is reaped by the monitor
+                kill(Pid::from_raw(pid as i32), Signal::SIGKILL)
+                    .unwrap_or_else(|e| debug!("failed to kill runc {}: {}", pid, e));
+                return Err(runc::error::Error::Other(Box::new(e)));
+            }
+        };
         let status = ExitStatus::from_raw(exit_code);
+        let stdout = stdout.join().unwrap_or_default();
+        let stderr = stderr.join().unwrap_or_default();
         Ok((status, pid, stdout, stderr))
     }
 }
 
-fn read_std<T>(std: Option<T>) -> String
+fn read_std<T>(std: Option<T>) -> JoinHandle<String>
 where
-    T: Read,
+    T: Read + Send + 'static,
 {
-    let mut std = std;
-    if let Some(mut std) = std.take() {
+    std::thread::spawn(move || {
         let mut out = String::new();
-        std.read_to_string(&mut out).unwrap_or_else(|e| {
-            error!("failed to read stdout {}", e);
-            0
-        });
-        return out;
-    }
-    "".to_string()
+        if let Some(mut std) = std {
+            std.read_to_string(&mut out).unwrap_or_else(|e| {
+                error!("failed to read stdout {}", e);
+                0
+            });
+        }
+        out
+    })
 }
 
-fn wait_pid(pid: i32, s: Subscription) -> i32 {
+fn wait_pid(pid: i32, s: Subscription, deadline: Deadline) -> Result<i32> {
     loop {
-        if let Ok(ExitEvent {
-            subject: Subject::Pid(epid),
-            exit_code: code,
-        }) = s.rx.recv()
-        {
-            if pid == epid {
-                return code;
-            }
+        match deadline.recv("runc", &s.rx)? {
+            Some(ExitEvent {
+                subject: Subject::Pid(epid),
+                exit_code,
+            }) if epid == pid => return Ok(exit_code),
+            Some(_) => {}
+            None => return Err(other!("exit monitor of runc {} is closed", pid)),
         }
     }
 }
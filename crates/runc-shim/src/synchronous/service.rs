@@ -14,40 +14,42 @@
    limitations under the License.
 */
 
-#![allow(unused)]
-
 use std::env::current_dir;
-use std::path::Path;
 use std::sync::mpsc::{channel, Receiver, Sender};
 use std::sync::Arc;
 
 use log::{debug, error};
 
 use containerd_shim as shim;
-use runc::options::{DeleteOpts, GlobalOpts, DEFAULT_COMMAND};
+use runc::options::DeleteOpts;
 use shim::api::*;
-use shim::error::{Error, Result};
+use shim::container::{Container, ContainerFactory};
+use shim::error::Error;
 use shim::event::Event;
 use shim::monitor::{monitor_subscribe, Subject, Subscription, Topic};
+use shim::mount::unmount_rootfs;
+use shim::processes::Process;
 use shim::protos::events::task::TaskExit;
 use shim::protos::protobuf::{Message, SingularPtrField};
-use shim::publisher::RemotePublisher;
+use shim::publisher::EventPublisher;
+use shim::task::TaskService;
 use shim::util::{
-    convert_to_timestamp, read_options, read_runtime, read_spec_from_file, timestamp, write_address,
+    convert_to_timestamp, read_options, read_runtime, read_spec_from_file, timestamp,
+    write_address, Deadline,
 };
-use shim::{io_error, other_error, warn};
+use shim::{io_error, warn};
 use shim::{spawn, Config, Context, ExitSignal, Shim, StartOpts};
 
-use crate::common::{create_runc, ShimExecutor, GROUP_LABELS};
-use crate::synchronous::container::{Container, Process};
+use crate::common::{create_runc, has_shared_pid_namespace};
+use crate::common::{events_spill_file, ShimExecutor, GROUP_LABELS};
 use crate::synchronous::runc::{RuncContainer, RuncFactory};
-use crate::synchronous::task::ShimTask;
 use crate::synchronous::Service;
 
 impl Shim for Service {
-    type T = ShimTask<RuncFactory, RuncContainer>;
+    type T = TaskService<RuncFactory, RuncContainer>;
 
-    fn new(_runtime_id: &str, id: &str, namespace: &str, _config: &mut Config) -> Self {
+    fn new(_runtime_id: &str, id: &str, namespace: &str, config: &mut Config) -> Self {
+        config.publisher.spill_file = events_spill_file();
         Service {
             exit: Arc::new(ExitSignal::default()),
             id: id.to_string(),
@@ -79,7 +81,6 @@ impl Shim for Service {
         Ok(address)
     }
 
-    #[cfg(not(feature = "async"))]
     fn delete_shim(&mut self) -> containerd_shim::Result<DeleteResponse> {
         let namespace = self.namespace.as_str();
         let bundle = current_dir().map_err(io_error!(e, "get current dir"))?;
@@ -95,6 +96,8 @@ impl Shim for Service {
         )?;
         runc.delete(&self.id, Some(&DeleteOpts { force: true }))
             .unwrap_or_else(|e| warn!("failed to remove runc container: {}", e));
+        unmount_rootfs(bundle.join("rootfs"), 0)
+            .unwrap_or_else(|e| warn!("failed to cleanup rootfs mount: {}", e));
         let mut resp = DeleteResponse::new();
         // sigkill
         resp.exit_status = 137;
@@ -102,22 +105,19 @@ impl Shim for Service {
         Ok(resp)
     }
 
-    #[cfg(feature = "async")]
-    fn delete_shim(&mut self) -> containerd_shim::Result<DeleteResponse> {
-        Err(Error::Unimplemented("delete shim".to_string()))
-    }
-
     fn wait(&mut self) {
         self.exit.wait();
     }
 
-    fn create_task_service(&self, publisher: RemotePublisher) -> Self::T {
+    fn create_task_service(&self, publisher: Box<dyn EventPublisher>) -> Self::T {
         let (tx, rx) = channel();
-        let task = ShimTask::new(self.namespace.as_str(), Arc::clone(&self.exit), tx.clone());
+        let task = TaskService::new(self.namespace.as_str(), Arc::clone(&self.exit), tx.clone());
 
         let s = monitor_subscribe(Topic::All).expect("monitor subscribe failed");
         self.process_exits(s, &task, tx);
         forward(publisher, self.namespace.to_string(), rx);
+        task.restore()
+            .unwrap_or_else(|e| warn!("failed to restore containers: {}", e));
         task
     }
 }
@@ -126,32 +126,47 @@ impl Service {
     pub fn process_exits(
         &self,
         s: Subscription,
-        task: &ShimTask<RuncFactory, RuncContainer>,
+        task: &TaskService<RuncFactory, RuncContainer>,
         tx: Sender<(String, Box<dyn Message>)>,
     ) {
         let containers = task.containers.clone();
+        let processes = task.processes.clone();
+        let factory = task.factory.clone();
+        let ns = task.namespace.to_string();
         std::thread::spawn(move || {
             for e in s.rx.iter() {
                 if let Subject::Pid(pid) = e.subject {
                     debug!("receive exit event: {}", &e);
                     let exit_code = e.exit_code;
-                    for (_k, cont) in containers.lock().unwrap().iter_mut() {
-                        let bundle = cont.common.bundle.to_string();
+                    let key = match processes.exited(pid, exit_code) {
+                        Some(key) => key,
+                        None => continue,
+                    };
+                    let cont = match containers.lock().unwrap().get(&key.id).cloned() {
+                        Some(cont) => cont,
+                        None => continue,
+                    };
+                    let mut cont = cont.lock().unwrap();
+                    match key.exec_id {
                         // pid belongs to container init process
-                        if cont.pid() == pid {
+                        None => {
                             // kill all children process if the container has a private PID namespace
-                            if cont.should_kill_all_on_exit(&bundle) {
-                                cont.kill(None, 9, true).unwrap_or_else(|e| {
-                                    error!("failed to kill init's children: {}", e)
-                                });
+                            if should_kill_all_on_exit(&cont.bundle) {
+                                cont.kill(None, 9, true, Deadline::default())
+                                    .unwrap_or_else(|e| {
+                                        error!("failed to kill init's children: {}", e)
+                                    });
                             }
                             // set exit for init process
-                            cont.common.init.set_exited(exit_code);
+                            cont.init.set_exited(exit_code);
+                            factory.save(&ns, &*cont).unwrap_or_else(|e| {
+                                warn!("failed to save state of container {}: {}", cont.id, e)
+                            });
 
                             // publish event
                             let (_, code, exited_at) = match cont.get_exit_info(None) {
                                 Ok(info) => info,
-                                Err(_) => break,
+                                Err(_) => continue,
                             };
 
                             let ts = convert_to_timestamp(exited_at);
@@ -166,17 +181,16 @@ impl Service {
                             let topic = event.topic();
                             tx.send((topic.to_string(), Box::new(event)))
                                 .unwrap_or_else(|e| warn!("send {} to publisher: {}", topic, e));
-
-                            break;
                         }
-
                         // pid belongs to container common process
-                        for (_exec_id, p) in cont.common.processes.iter_mut() {
-                            // set exit for exec process
-                            if p.pid() == pid {
+                        Some(exec_id) => {
+                            if let Some(p) = cont.processes.get_mut(&exec_id) {
+                                // set exit for exec process
                                 p.set_exited(exit_code);
                                 // TODO: publish event
-                                break;
+                                factory.save(&ns, &*cont).unwrap_or_else(|e| {
+                                    warn!("failed to save state of container {}: {}", cont.id, e)
+                                });
                             }
                         }
                     }
@@ -186,7 +200,21 @@ impl Service {
     }
 }
 
-fn forward(publisher: RemotePublisher, ns: String, rx: Receiver<(String, Box<dyn Message>)>) {
+fn should_kill_all_on_exit(bundle_path: &str) -> bool {
+    match read_spec_from_file(bundle_path) {
+        Ok(spec) => has_shared_pid_namespace(&spec),
+        Err(e) => {
+            error!("should_kill_all_on_exit: {}", e);
+            false
+        }
+    }
+}
+
+fn forward(
+    publisher: Box<dyn EventPublisher>,
+    ns: String,
+    rx: Receiver<(String, Box<dyn Message>)>,
+) {
     std::thread::spawn(move || {
         for (topic, e) in rx.iter() {
             publisher
@@ -0,0 +1,141 @@
+/*
+   Copyright The containerd Authors.
+
+   Licensed under the Apache License, Version 2.0 (the "License");
+   you may not use this file except in compliance with the License.
+   You may obtain a copy of the License at
+
+       http://www.apache.org/licenses/LICENSE-2.0
+
+   Unless required by applicable law or agreed to in writing, software
+   distributed under the License is distributed on an "AS IS" BASIS,
+   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+   See the License for the specific language governing permissions and
+   limitations under the License.
+*/
+
+//! Runs the conformance cases of containerd-shim against the runc shim, launched by a fake
+//! containerd. It needs root and a runc binary in PATH, and is skipped without them:
+//!
+//! ```text
+//! sudo -E cargo test -p containerd-runc-shim --features testing --test conformance
+//! ```
+
+#![cfg(feature = "testing")]
+
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use containerd_shim::api::ShutdownRequest;
+use containerd_shim::protos::ttrpc::context::Context;
+use containerd_shim::testing::conformance::Conformance;
+use containerd_shim::testing::FakeContainerd;
+use containerd_shim::{io_error, other, Error, Result};
+use serde_json::{json, Value};
+
+const SHIM: &str = env!("CARGO_BIN_EXE_containerd-shim-runc-v2-rs");
+
+const NAMESPACE: &str = "runc-shim-conformance";
+
+/// Directories of the host bind mounted into the rootfs of the tasks.
+const HOST_DIRS: [&str; 5] = ["/bin", "/sbin", "/lib", "/lib64", "/usr"];
+
+fn runc_available() -> bool {
+    std::env::var_os("PATH")
+        .map(|paths| std::env::split_paths(&paths).any(|p| p.join("runc").is_file()))
+        .unwrap_or(false)
+}
+
+/// Prepare a bundle running `sleep` from the binaries of the host until it is killed.
+fn prepare_bundle(dir: &Path, id: &str) -> Result<PathBuf> {
+    let bundle = dir.join(id);
+    let rootfs = bundle.join("rootfs");
+    std::fs::create_dir_all(&rootfs).map_err(io_error!(e, "create {}", rootfs.display()))?;
+
+    let output = Command::new("runc")
+        .arg("spec")
+        .arg("--bundle")
+        .arg(&bundle)
+        .output()
+        .map_err(io_error!(e, "run runc spec"))?;
+    if !output.status.success() {
+        return Err(other!(
+            "runc spec: {}",
+            String::from_utf8_lossy(&output.stderr)
+        ));
+    }
+
+    let config = bundle.join("config.json");
+    let content =
+        std::fs::read_to_string(&config).map_err(io_error!(e, "read {}", config.display()))?;
+    let mut spec: Value = serde_json::from_str(&content)?;
+    spec["process"]["terminal"] = json!(false);
+    spec["process"]["args"] = json!(["sleep", "1000000"]);
+    spec["root"]["path"] = json!("rootfs");
+    let mounts = spec["mounts"]
+        .as_array_mut()
+        .ok_or_else(|| other!("no mounts in the spec"))?;
+    for dir in HOST_DIRS.iter() {
+        let host = Path::new(dir);
+        let target = rootfs.join(&dir[1..]);
+        match std::fs::symlink_metadata(host) {
+            // merged /usr, link it the same way in the rootfs
+            Ok(m) if m.file_type().is_symlink() => {
+                let link = std::fs::read_link(host).map_err(io_error!(e, "read link {}", dir))?;
+                std::os::unix::fs::symlink(&link, &target).map_err(io_error!(
+                    e,
+                    "link {}",
+                    target.display()
+                ))?;
+            }
+            Ok(m) if m.is_dir() => mounts.push(json!({
+                "destination": dir,
+                "type": "bind",
+                "source": dir,
+                "options": ["rbind", "ro"],
+            })),
+            _ => {}
+        }
+    }
+    std::fs::write(&config, serde_json::to_string(&spec)?).map_err(io_error!(
+        e,
+        "write {}",
+        config.display()
+    ))?;
+    Ok(bundle)
+}
+
+#[test]
+fn test_conformance() {
+    if !nix::unistd::geteuid().is_root() || !runc_available() {
+        eprintln!("skip the conformance test, it needs root and runc");
+        return;
+    }
+
+    let tmpdir = tempfile::tempdir().unwrap();
+    let containerd = FakeContainerd::new(tmpdir.path()).unwrap();
+    let bundles = tmpdir.path().join("bundles");
+    // the shim runs in a bundle of its own, the tasks are created in theirs.
+    let shim_bundle = prepare_bundle(&bundles, "shim").unwrap();
+    let shim = containerd.shim(SHIM, NAMESPACE, "shim", &shim_bundle);
+    let client = shim.start().unwrap();
+
+    let conformance = Conformance::new(&client, &containerd, |id| prepare_bundle(&bundles, id));
+    let results = conformance.run();
+
+    let req = ShutdownRequest {
+        id: "shim".to_string(),
+        now: true,
+        ..Default::default()
+    };
+    // the shim may exit before it replies to a shutdown now
+    client
+        .shutdown(Context::default(), &req)
+        .unwrap_or_default();
+
+    let failed: Vec<_> = results
+        .iter()
+        .filter_map(|(name, res)| res.as_ref().err().map(|e| format!("{}: {}", name, e)))
+        .collect();
+    assert!(failed.is_empty(), "failed cases: {:#?}", failed);
+}
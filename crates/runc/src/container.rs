@@ -36,7 +36,6 @@
 use std::collections::HashMap;
 
 use serde::{Deserialize, Serialize};
-use time::serde::timestamp;
 use time::OffsetDateTime;
 
 /// Information for runc container
@@ -47,11 +46,42 @@ pub struct Container {
     pub status: String,
     pub bundle: String,
     pub rootfs: String,
-    #[serde(with = "timestamp")]
+    #[serde(with = "created")]
     pub created: OffsetDateTime,
+    #[serde(default)]
     pub annotations: HashMap<String, String>,
 }
 
+/// runc reports the creation time as an RFC3339 string,
+/// while a unix timestamp is also accepted for compatibility.
+mod created {
+    use serde::de::Error;
+    use serde::{Deserialize, Deserializer};
+    use time::format_description::well_known::Rfc3339;
+    use time::OffsetDateTime;
+
+    pub use time::serde::timestamp::serialize;
+
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Created {
+        Timestamp(i64),
+        Rfc3339(String),
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<OffsetDateTime, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        match Created::deserialize(deserializer)? {
+            Created::Timestamp(t) => {
+                OffsetDateTime::from_unix_timestamp(t).map_err(D::Error::custom)
+            }
+            Created::Rfc3339(s) => OffsetDateTime::parse(&s, &Rfc3339).map_err(D::Error::custom),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -84,4 +114,27 @@ mod tests {
         assert_eq!(c.annotations.get("foo"), Some(&"bar".to_string()));
         assert_eq!(c.annotations.get("bar"), None);
     }
+
+    #[test]
+    fn serde_rfc3339_test() {
+        let j = r#"
+            {
+                "ociVersion": "1.0.2-dev",
+                "id": "fake",
+                "pid": 1000,
+                "status": "running",
+                "bundle": "/path/to/bundle",
+                "rootfs": "/path/to/rootfs",
+                "created": "2015-05-15T10:00:00.123456789Z",
+                "owner": ""
+            }"#;
+
+        let c: Container = serde_json::from_str(j).unwrap();
+        assert_eq!(c.status, "running");
+        assert_eq!(
+            c.created,
+            OffsetDateTime::from_unix_timestamp_nanos(1431684000123456789).unwrap()
+        );
+        assert!(c.annotations.is_empty());
+    }
 }
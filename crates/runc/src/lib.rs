@@ -126,6 +126,15 @@ impl Runc {
 
         Ok(cmd)
     }
+
+    /// A copy of the client running its commands with another spawner,
+    /// e.g. to bound the commands of a single request.
+    pub fn with_spawner(&self, spawner: Arc<dyn Spawner + Send + Sync>) -> Self {
+        Self {
+            spawner,
+            ..self.clone()
+        }
+    }
 }
 
 #[cfg(not(feature = "async"))]
@@ -564,7 +573,7 @@ impl Runc {
     }
 
     /// Return the state of a container
-    pub async fn state(&self, id: &str) -> Result<Vec<usize>> {
+    pub async fn state(&self, id: &str) -> Result<Container> {
         let args = vec!["state".to_string(), id.to_string()];
         let res = self.launch(self.command(&args)?, true).await?;
         serde_json::from_str(&res.output).map_err(Error::JsonDeserializationFailed)
@@ -990,6 +999,8 @@ pub struct DefaultExecutor {}
 impl Spawner for DefaultExecutor {
     async fn execute(&self, cmd: Command) -> Result<(ExitStatus, u32, String, String)> {
         let mut cmd = cmd;
+        // kill the command if the caller gives up waiting for it
+        cmd.kill_on_drop(true);
         let child = cmd.spawn().map_err(Error::ProcessSpawnFailed)?;
         let pid = child.id().unwrap();
         let result = child
@@ -72,12 +72,53 @@ fn main() {
         false,
     );
 
+    // generate async service
+    codegen(
+        "src/task_v3",
+        &["vendor/github.com/containerd/containerd/api/runtime/task/v3/shim.proto"],
+        false,
+        true,
+    );
+    fs::rename(
+        "src/task_v3/shim_ttrpc.rs",
+        "src/task_v3/shim_ttrpc_async.rs",
+    )
+    .unwrap();
+
+    codegen(
+        "src/task_v3",
+        &["vendor/github.com/containerd/containerd/api/runtime/task/v3/shim.proto"],
+        false,
+        false,
+    );
+
+    // generate async service
+    codegen(
+        "src/sandbox",
+        &["vendor/github.com/containerd/containerd/api/runtime/sandbox/v1/sandbox.proto"],
+        false,
+        true,
+    );
+    fs::rename(
+        "src/sandbox/sandbox_ttrpc.rs",
+        "src/sandbox/sandbox_ttrpc_async.rs",
+    )
+    .unwrap();
+
+    codegen(
+        "src/sandbox",
+        &["vendor/github.com/containerd/containerd/api/runtime/sandbox/v1/sandbox.proto"],
+        false,
+        false,
+    );
+
     codegen(
         "src/types",
         &[
             "vendor/github.com/containerd/containerd/api/types/mount.proto",
             "vendor/github.com/containerd/containerd/api/types/task/task.proto",
             "vendor/google/protobuf/empty.proto",
+            "vendor/github.com/containerd/containerd/api/types/platform.proto",
         ],
         true,
         false,
@@ -101,6 +142,7 @@ fn codegen(
         .rust_protobuf()
         .rust_protobuf_customize(ProtobufCustomize {
             gen_mod_rs: Some(gen_mod_rs),
+            serde_derive: Some(true),
             ..Default::default()
         })
         .customize(Customize {
@@ -110,4 +152,51 @@ fn codegen(
         .out_dir(path)
         .run()
         .expect("Failed to generate protos");
+
+    fixup_serde(path);
+}
+
+// rust-protobuf derives serde for the well known types by their fields,
+// so annotate the `Timestamp` and `Any` fields to use the canonical JSON mapping in `src/json.rs`.
+#[cfg(feature = "generate_bindings")]
+fn fixup_serde(path: &Path) {
+    const FIELDS: &[(&str, &str)] = &[
+        (
+            "::protobuf::SingularPtrField<::protobuf::well_known_types::Timestamp>",
+            "crate::json::timestamp",
+        ),
+        (
+            "::protobuf::SingularPtrField<::protobuf::well_known_types::Any>",
+            "crate::json::any",
+        ),
+    ];
+
+    for entry in fs::read_dir(path).unwrap() {
+        let file = entry.unwrap().path();
+        if file.extension().map_or(true, |ext| ext != "rs") {
+            continue;
+        }
+        let content = fs::read_to_string(&file).unwrap();
+        let mut fixed = String::with_capacity(content.len());
+        let mut prev = "";
+        for line in content.lines() {
+            for (ty, module) in FIELDS {
+                let attr = format!(
+                    "    #[cfg_attr(feature = \"with-serde\", serde(default, with = \"{}\"))]",
+                    module
+                );
+                if line.starts_with("    pub ")
+                    && line.ends_with(&format!(": {},", ty))
+                    && prev != attr
+                {
+                    fixed.push_str(&attr);
+                    fixed.push('\n');
+                }
+            }
+            fixed.push_str(line);
+            fixed.push('\n');
+            prev = line;
+        }
+        fs::write(&file, fixed).unwrap();
+    }
 }
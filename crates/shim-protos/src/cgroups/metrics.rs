@@ -24,6 +24,7 @@
 // const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_2_27_1;
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct Metrics {
     // message fields
     pub hugetlb: ::protobuf::RepeatedField<HugetlbStat>,
@@ -36,7 +37,9 @@ pub struct Metrics {
     pub cgroup_stats: ::protobuf::SingularPtrField<CgroupStats>,
     pub memory_oom_control: ::protobuf::SingularPtrField<MemoryOomControl>,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub cached_size: ::protobuf::CachedSize,
 }
 
@@ -638,6 +641,7 @@ impl ::protobuf::reflect::ProtobufValue for Metrics {
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct HugetlbStat {
     // message fields
     pub usage: u64,
@@ -645,7 +649,9 @@ pub struct HugetlbStat {
     pub failcnt: u64,
     pub pagesize: ::std::string::String,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub cached_size: ::protobuf::CachedSize,
 }
 
@@ -902,12 +908,15 @@ impl ::protobuf::reflect::ProtobufValue for HugetlbStat {
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct PidsStat {
     // message fields
     pub current: u64,
     pub limit: u64,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub cached_size: ::protobuf::CachedSize,
 }
 
@@ -1089,12 +1098,15 @@ impl ::protobuf::reflect::ProtobufValue for PidsStat {
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct CPUStat {
     // message fields
     pub usage: ::protobuf::SingularPtrField<CPUUsage>,
     pub throttling: ::protobuf::SingularPtrField<Throttle>,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub cached_size: ::protobuf::CachedSize,
 }
 
@@ -1320,6 +1332,7 @@ impl ::protobuf::reflect::ProtobufValue for CPUStat {
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct CPUUsage {
     // message fields
     pub total: u64,
@@ -1327,7 +1340,9 @@ pub struct CPUUsage {
     pub user: u64,
     pub per_cpu: ::std::vec::Vec<u64>,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub cached_size: ::protobuf::CachedSize,
 }
 
@@ -1583,13 +1598,16 @@ impl ::protobuf::reflect::ProtobufValue for CPUUsage {
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct Throttle {
     // message fields
     pub periods: u64,
     pub throttled_periods: u64,
     pub throttled_time: u64,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub cached_size: ::protobuf::CachedSize,
 }
 
@@ -1805,6 +1823,7 @@ impl ::protobuf::reflect::ProtobufValue for Throttle {
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct MemoryStat {
     // message fields
     pub cache: u64,
@@ -1844,7 +1863,9 @@ pub struct MemoryStat {
     pub kernel: ::protobuf::SingularPtrField<MemoryEntry>,
     pub kernel_tcp: ::protobuf::SingularPtrField<MemoryEntry>,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub cached_size: ::protobuf::CachedSize,
 }
 
@@ -3270,6 +3291,7 @@ impl ::protobuf::reflect::ProtobufValue for MemoryStat {
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct MemoryEntry {
     // message fields
     pub limit: u64,
@@ -3277,7 +3299,9 @@ pub struct MemoryEntry {
     pub max: u64,
     pub failcnt: u64,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub cached_size: ::protobuf::CachedSize,
 }
 
@@ -3527,13 +3551,16 @@ impl ::protobuf::reflect::ProtobufValue for MemoryEntry {
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct MemoryOomControl {
     // message fields
     pub oom_kill_disable: u64,
     pub under_oom: u64,
     pub oom_kill: u64,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub cached_size: ::protobuf::CachedSize,
 }
 
@@ -3749,6 +3776,7 @@ impl ::protobuf::reflect::ProtobufValue for MemoryOomControl {
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct BlkIOStat {
     // message fields
     pub io_service_bytes_recursive: ::protobuf::RepeatedField<BlkIOEntry>,
@@ -3760,7 +3788,9 @@ pub struct BlkIOStat {
     pub io_time_recursive: ::protobuf::RepeatedField<BlkIOEntry>,
     pub sectors_recursive: ::protobuf::RepeatedField<BlkIOEntry>,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub cached_size: ::protobuf::CachedSize,
 }
 
@@ -4258,6 +4288,7 @@ impl ::protobuf::reflect::ProtobufValue for BlkIOStat {
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct BlkIOEntry {
     // message fields
     pub op: ::std::string::String,
@@ -4266,7 +4297,9 @@ pub struct BlkIOEntry {
     pub minor: u64,
     pub value: u64,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub cached_size: ::protobuf::CachedSize,
 }
 
@@ -4564,12 +4597,15 @@ impl ::protobuf::reflect::ProtobufValue for BlkIOEntry {
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct RdmaStat {
     // message fields
     pub current: ::protobuf::RepeatedField<RdmaEntry>,
     pub limit: ::protobuf::RepeatedField<RdmaEntry>,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub cached_size: ::protobuf::CachedSize,
 }
 
@@ -4779,13 +4815,16 @@ impl ::protobuf::reflect::ProtobufValue for RdmaStat {
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct RdmaEntry {
     // message fields
     pub device: ::std::string::String,
     pub hca_handles: u32,
     pub hca_objects: u32,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub cached_size: ::protobuf::CachedSize,
 }
 
@@ -5008,6 +5047,7 @@ impl ::protobuf::reflect::ProtobufValue for RdmaEntry {
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct NetworkStat {
     // message fields
     pub name: ::std::string::String,
@@ -5020,7 +5060,9 @@ pub struct NetworkStat {
     pub tx_errors: u64,
     pub tx_dropped: u64,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub cached_size: ::protobuf::CachedSize,
 }
 
@@ -5447,6 +5489,7 @@ impl ::protobuf::reflect::ProtobufValue for NetworkStat {
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct CgroupStats {
     // message fields
     pub nr_sleeping: u64,
@@ -5455,7 +5498,9 @@ pub struct CgroupStats {
     pub nr_uninterruptible: u64,
     pub nr_io_wait: u64,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub cached_size: ::protobuf::CachedSize,
 }
 
@@ -24,13 +24,16 @@
 // const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_2_27_1;
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct ContainerCreate {
     // message fields
     pub id: ::std::string::String,
     pub image: ::std::string::String,
     pub runtime: ::protobuf::SingularPtrField<ContainerCreate_Runtime>,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub cached_size: ::protobuf::CachedSize,
 }
 
@@ -282,12 +285,16 @@ impl ::protobuf::reflect::ProtobufValue for ContainerCreate {
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct ContainerCreate_Runtime {
     // message fields
     pub name: ::std::string::String,
+    #[cfg_attr(feature = "with-serde", serde(default, with = "crate::json::any"))]
     pub options: ::protobuf::SingularPtrField<::protobuf::well_known_types::Any>,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub cached_size: ::protobuf::CachedSize,
 }
 
@@ -498,6 +505,7 @@ impl ::protobuf::reflect::ProtobufValue for ContainerCreate_Runtime {
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct ContainerUpdate {
     // message fields
     pub id: ::std::string::String,
@@ -505,7 +513,9 @@ pub struct ContainerUpdate {
     pub labels: ::std::collections::HashMap<::std::string::String, ::std::string::String>,
     pub snapshot_key: ::std::string::String,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub cached_size: ::protobuf::CachedSize,
 }
 
@@ -778,11 +788,14 @@ impl ::protobuf::reflect::ProtobufValue for ContainerUpdate {
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct ContainerDelete {
     // message fields
     pub id: ::std::string::String,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub cached_size: ::protobuf::CachedSize,
 }
 
@@ -24,6 +24,7 @@
 // const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_2_27_1;
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct TaskCreate {
     // message fields
     pub container_id: ::std::string::String,
@@ -33,7 +34,9 @@ pub struct TaskCreate {
     pub checkpoint: ::std::string::String,
     pub pid: u32,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub cached_size: ::protobuf::CachedSize,
 }
 
@@ -408,12 +411,15 @@ impl ::protobuf::reflect::ProtobufValue for TaskCreate {
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct TaskStart {
     // message fields
     pub container_id: ::std::string::String,
     pub pid: u32,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub cached_size: ::protobuf::CachedSize,
 }
 
@@ -602,15 +608,19 @@ impl ::protobuf::reflect::ProtobufValue for TaskStart {
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct TaskDelete {
     // message fields
     pub container_id: ::std::string::String,
     pub pid: u32,
     pub exit_status: u32,
+    #[cfg_attr(feature = "with-serde", serde(default, with = "crate::json::timestamp"))]
     pub exited_at: ::protobuf::SingularPtrField<::protobuf::well_known_types::Timestamp>,
     pub id: ::std::string::String,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub cached_size: ::protobuf::CachedSize,
 }
 
@@ -930,6 +940,7 @@ impl ::protobuf::reflect::ProtobufValue for TaskDelete {
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct TaskIO {
     // message fields
     pub stdin: ::std::string::String,
@@ -937,7 +948,9 @@ pub struct TaskIO {
     pub stderr: ::std::string::String,
     pub terminal: bool,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub cached_size: ::protobuf::CachedSize,
 }
 
@@ -1208,15 +1221,19 @@ impl ::protobuf::reflect::ProtobufValue for TaskIO {
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct TaskExit {
     // message fields
     pub container_id: ::std::string::String,
     pub id: ::std::string::String,
     pub pid: u32,
     pub exit_status: u32,
+    #[cfg_attr(feature = "with-serde", serde(default, with = "crate::json::timestamp"))]
     pub exited_at: ::protobuf::SingularPtrField<::protobuf::well_known_types::Timestamp>,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub cached_size: ::protobuf::CachedSize,
 }
 
@@ -1536,11 +1553,14 @@ impl ::protobuf::reflect::ProtobufValue for TaskExit {
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct TaskOOM {
     // message fields
     pub container_id: ::std::string::String,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub cached_size: ::protobuf::CachedSize,
 }
 
@@ -1695,12 +1715,15 @@ impl ::protobuf::reflect::ProtobufValue for TaskOOM {
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct TaskExecAdded {
     // message fields
     pub container_id: ::std::string::String,
     pub exec_id: ::std::string::String,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub cached_size: ::protobuf::CachedSize,
 }
 
@@ -1896,13 +1919,16 @@ impl ::protobuf::reflect::ProtobufValue for TaskExecAdded {
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct TaskExecStarted {
     // message fields
     pub container_id: ::std::string::String,
     pub exec_id: ::std::string::String,
     pub pid: u32,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub cached_size: ::protobuf::CachedSize,
 }
 
@@ -2132,11 +2158,14 @@ impl ::protobuf::reflect::ProtobufValue for TaskExecStarted {
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct TaskPaused {
     // message fields
     pub container_id: ::std::string::String,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub cached_size: ::protobuf::CachedSize,
 }
 
@@ -2291,11 +2320,14 @@ impl ::protobuf::reflect::ProtobufValue for TaskPaused {
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct TaskResumed {
     // message fields
     pub container_id: ::std::string::String,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub cached_size: ::protobuf::CachedSize,
 }
 
@@ -2450,12 +2482,15 @@ impl ::protobuf::reflect::ProtobufValue for TaskResumed {
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct TaskCheckpointed {
     // message fields
     pub container_id: ::std::string::String,
     pub checkpoint: ::std::string::String,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub cached_size: ::protobuf::CachedSize,
 }
 
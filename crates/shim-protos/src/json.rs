@@ -0,0 +1,287 @@
+/*
+   Copyright The containerd Authors.
+
+   Licensed under the Apache License, Version 2.0 (the "License");
+   you may not use this file except in compliance with the License.
+   You may obtain a copy of the License at
+
+       http://www.apache.org/licenses/LICENSE-2.0
+
+   Unless required by applicable law or agreed to in writing, software
+   distributed under the License is distributed on an "AS IS" BASIS,
+   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+   See the License for the specific language governing permissions and
+   limitations under the License.
+*/
+
+//! Canonical protobuf JSON mapping of `Timestamp` and `Any` fields of the generated messages.
+//!
+//! A `Timestamp` is an RFC 3339 string. An `Any` is an object with the type url in `@type`,
+//! and the fields of the message if it is one of the types known by this crate, otherwise
+//! the base64 encoded message in `value`.
+
+use protobuf::well_known_types::{Any, Timestamp};
+use protobuf::{Message, SingularPtrField};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::{Map, Value};
+use time::format_description::well_known::Rfc3339;
+use time::OffsetDateTime;
+
+use crate::cgroups::metrics::Metrics;
+use crate::events::container::{ContainerCreate, ContainerDelete, ContainerUpdate};
+use crate::events::content::ContentDelete;
+use crate::events::image::{ImageCreate, ImageDelete, ImageUpdate};
+use crate::events::namespace::{NamespaceCreate, NamespaceDelete, NamespaceUpdate};
+use crate::events::snapshot::{SnapshotCommit, SnapshotPrepare, SnapshotRemove};
+use crate::events::task::*;
+use crate::shim::oci::{CheckpointOptions, Options, ProcessDetails};
+
+const TYPE_KEY: &str = "@type";
+const VALUE_KEY: &str = "value";
+
+type Result<T> = std::result::Result<T, String>;
+
+pub mod timestamp {
+    use serde::de::Error as _;
+    use serde::ser::Error as _;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    use super::*;
+
+    pub fn serialize<S: Serializer>(
+        ts: &SingularPtrField<Timestamp>,
+        s: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        match ts.as_ref() {
+            Some(ts) => s.serialize_str(&format_timestamp(ts).map_err(S::Error::custom)?),
+            None => s.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        d: D,
+    ) -> std::result::Result<SingularPtrField<Timestamp>, D::Error> {
+        match Option::<String>::deserialize(d)? {
+            Some(s) => parse_timestamp(&s)
+                .map(SingularPtrField::some)
+                .map_err(D::Error::custom),
+            None => Ok(SingularPtrField::none()),
+        }
+    }
+}
+
+pub mod any {
+    use serde::de::Error as _;
+    use serde::ser::Error as _;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use super::*;
+
+    pub fn serialize<S: Serializer>(
+        any: &SingularPtrField<Any>,
+        s: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        match any.as_ref() {
+            Some(any) => any_to_json(any).map_err(S::Error::custom)?.serialize(s),
+            None => s.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        d: D,
+    ) -> std::result::Result<SingularPtrField<Any>, D::Error> {
+        match Option::<Value>::deserialize(d)? {
+            Some(v) => any_from_json(v)
+                .map(SingularPtrField::some)
+                .map_err(D::Error::custom),
+            None => Ok(SingularPtrField::none()),
+        }
+    }
+}
+
+fn format_timestamp(ts: &Timestamp) -> Result<String> {
+    let nanos = ts.get_seconds() as i128 * 1_000_000_000 + ts.get_nanos() as i128;
+    OffsetDateTime::from_unix_timestamp_nanos(nanos)
+        .map_err(|e| e.to_string())?
+        .format(&Rfc3339)
+        .map_err(|e| e.to_string())
+}
+
+fn parse_timestamp(s: &str) -> Result<Timestamp> {
+    let t = OffsetDateTime::parse(s, &Rfc3339).map_err(|e| format!("parse {}: {}", s, e))?;
+    let mut ts = Timestamp::new();
+    ts.set_seconds(t.unix_timestamp());
+    ts.set_nanos(t.nanosecond() as i32);
+    Ok(ts)
+}
+
+/// Converters of a message between its protobuf encoding and JSON.
+struct Codec {
+    to_json: fn(&[u8]) -> Result<Value>,
+    from_json: fn(Value) -> Result<Vec<u8>>,
+}
+
+fn to_json<M: Message + Serialize>(b: &[u8]) -> Result<Value> {
+    let m = M::parse_from_bytes(b).map_err(|e| e.to_string())?;
+    serde_json::to_value(&m).map_err(|e| e.to_string())
+}
+
+fn from_json<M: Message + DeserializeOwned>(v: Value) -> Result<Vec<u8>> {
+    let m: M = serde_json::from_value(v).map_err(|e| e.to_string())?;
+    m.write_to_bytes().map_err(|e| e.to_string())
+}
+
+macro_rules! codecs {
+    ($name:expr, $($ty:ty),* $(,)?) => {
+        $(
+            if $name == <$ty>::descriptor_static().full_name() {
+                return Some(Codec {
+                    to_json: to_json::<$ty>,
+                    from_json: from_json::<$ty>,
+                });
+            }
+        )*
+    };
+}
+
+/// Codec of the message type with the full name, the types that may be carried by `Any`.
+fn codec(name: &str) -> Option<Codec> {
+    codecs!(
+        name,
+        TaskCreate,
+        TaskStart,
+        TaskExecAdded,
+        TaskExecStarted,
+        TaskPaused,
+        TaskResumed,
+        TaskExit,
+        TaskDelete,
+        TaskOOM,
+        TaskCheckpointed,
+        ContainerCreate,
+        ContainerUpdate,
+        ContainerDelete,
+        ContentDelete,
+        ImageCreate,
+        ImageUpdate,
+        ImageDelete,
+        NamespaceCreate,
+        NamespaceUpdate,
+        NamespaceDelete,
+        SnapshotPrepare,
+        SnapshotCommit,
+        SnapshotRemove,
+        Options,
+        CheckpointOptions,
+        ProcessDetails,
+        Metrics,
+    );
+    None
+}
+
+/// The type url may be prefixed by a host, like `types.googleapis.com/`.
+fn type_name(type_url: &str) -> &str {
+    type_url.rsplit('/').next().unwrap_or(type_url)
+}
+
+fn any_to_json(any: &Any) -> Result<Value> {
+    let mut map = match codec(type_name(any.get_type_url())) {
+        Some(codec) => match (codec.to_json)(any.get_value())? {
+            Value::Object(map) => map,
+            _ => return Err(format!("{} is not a message", any.get_type_url())),
+        },
+        None => {
+            let mut map = Map::new();
+            map.insert(
+                VALUE_KEY.to_string(),
+                Value::String(base64::encode(any.get_value())),
+            );
+            map
+        }
+    };
+    map.insert(
+        TYPE_KEY.to_string(),
+        Value::String(any.get_type_url().to_string()),
+    );
+    Ok(Value::Object(map))
+}
+
+fn any_from_json(v: Value) -> Result<Any> {
+    let mut map = match v {
+        Value::Object(map) => map,
+        v => return Err(format!("expect an object for Any, got {}", v)),
+    };
+    let type_url = match map.remove(TYPE_KEY) {
+        Some(Value::String(s)) => s,
+        _ => return Err(format!("expect a string {} for Any", TYPE_KEY)),
+    };
+    let value = match (map.get(VALUE_KEY), codec(type_name(&type_url))) {
+        (Some(Value::String(s)), _) if map.len() == 1 => {
+            base64::decode(s).map_err(|e| format!("decode value of {}: {}", type_url, e))?
+        }
+        (_, Some(codec)) => (codec.from_json)(Value::Object(map))?,
+        (_, None) => return Err(format!("unknown type {}", type_url)),
+    };
+    let mut any = Any::new();
+    any.set_type_url(type_url);
+    any.set_value(value);
+    Ok(any)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::shim::events::Envelope;
+
+    use super::*;
+
+    #[test]
+    fn test_timestamp() {
+        let mut ts = Timestamp::new();
+        ts.set_seconds(1_640_995_200);
+        ts.set_nanos(500_000_000);
+        assert_eq!(format_timestamp(&ts).unwrap(), "2022-01-01T00:00:00.5Z");
+        assert_eq!(parse_timestamp("2022-01-01T00:00:00.5Z").unwrap(), ts);
+        assert_eq!(parse_timestamp("2022-01-01T08:00:00.5+08:00").unwrap(), ts);
+        assert!(parse_timestamp("2022-01-01").is_err());
+    }
+
+    #[test]
+    fn test_envelope() {
+        let mut exit = TaskExit::new();
+        exit.set_container_id("test".to_string());
+        exit.set_exit_status(137);
+        let mut any = Any::new();
+        any.set_type_url("containerd.events.TaskExit".to_string());
+        any.set_value(exit.write_to_bytes().unwrap());
+        let mut envelope = Envelope::new();
+        envelope.set_topic("/tasks/exit".to_string());
+        envelope.set_namespace("default".to_string());
+        envelope.set_timestamp(parse_timestamp("2022-01-01T00:00:00Z").unwrap());
+        envelope.set_event(any);
+
+        let json = serde_json::to_value(&envelope).unwrap();
+        assert_eq!(json["timestamp"], "2022-01-01T00:00:00Z");
+        assert_eq!(json["event"]["@type"], "containerd.events.TaskExit");
+        assert_eq!(json["event"]["container_id"], "test");
+        assert_eq!(json["event"]["exit_status"], 137);
+        assert!(json.get("unknown_fields").is_none());
+
+        let decoded: Envelope = serde_json::from_value(json).unwrap();
+        assert_eq!(decoded, envelope);
+    }
+
+    #[test]
+    fn test_unknown_any() {
+        let mut any = Any::new();
+        any.set_type_url("types.containerd.io/opencontainers/runtime-spec/1/Process".to_string());
+        any.set_value(b"{\"cwd\":\"/\"}".to_vec());
+
+        let json = any_to_json(&any).unwrap();
+        assert_eq!(json["value"], "eyJjd2QiOiIvIn0=");
+        assert_eq!(any_from_json(json).unwrap(), any);
+
+        let json = serde_json::json!({"@type": "unknown.Type", "field": 1});
+        assert!(any_from_json(json).is_err());
+    }
+}
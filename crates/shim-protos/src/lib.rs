@@ -28,6 +28,8 @@
 //! The `containerd-shim-protos` crate provides [Protobuf](https://github.com/protocolbuffers/protobuf.git) message
 //! and [TTRPC](https://github.com/containerd/ttrpc.git) service definitions for the
 //! [Containerd shim v2](https://github.com/containerd/containerd/blob/main/runtime/v2/task/shim.proto) protocol.
+//! The task service is also generated from the `containerd.task.v3` package, which newer containerd
+//! releases speak to shims, its messages have the same encoding as the v2 ones.
 //!
 //! The message and service definitions are auto-generated from protobuf source files under `vendor/`
 //! by using [ttrpc-codegen](https://github.com/containerd/ttrpc-rust/tree/master/ttrpc-codegen). So please do not
@@ -38,6 +40,13 @@
 //! - Re-generate the source files by `cargo build --features=generate_bindings`.
 //! - Commit the synchronized protobuf source files and auto-generated source files, keeping them in synchronization.
 //!
+//! # Features
+//! - `async`: async TTRPC services and clients in `shim_async`.
+//! - `with-serde`: `Serialize` and `Deserialize` for all generated messages, with the canonical
+//!   protobuf JSON mapping for `Timestamp` and `Any` fields. It is not named `serde`, as the
+//!   optional `serde` dependency implicitly defines a feature of that name, which only enables
+//!   the dependency. `with-serde` is also the feature that rust-protobuf's generated code checks.
+//!
 //! # Examples
 //!
 //! Here is a quick example how to use the crate:
@@ -75,10 +84,17 @@ pub mod cgroups;
 pub mod shim;
 #[rustfmt::skip]
 pub mod types;
+#[rustfmt::skip]
+pub mod sandbox;
+#[rustfmt::skip]
+pub mod task_v3;
 
 /// Includes event names shims can publish to containerd.
 pub mod topics;
 
+#[cfg(feature = "with-serde")]
+mod json;
+
 pub mod shim_sync {
     /// TTRPC client reexport for easier access.
     pub use ttrpc::Client;
@@ -86,8 +102,16 @@ pub mod shim_sync {
     /// Shim task service.
     pub use crate::shim::shim_ttrpc::{create_task, Task, TaskClient};
 
+    /// Shim task service of the task API v3.
+    pub use crate::task_v3::shim_ttrpc::{
+        create_task as create_task_v3, Task as TaskV3, TaskClient as TaskV3Client,
+    };
+
     /// Shim events service.
     pub use crate::shim::events_ttrpc::{create_events, Events, EventsClient};
+
+    /// Shim sandbox service.
+    pub use crate::sandbox::sandbox_ttrpc::{create_sandbox, Sandbox, SandboxClient};
 }
 
 pub use shim_sync::*;
@@ -100,8 +124,16 @@ pub mod shim_async {
     /// Shim task service.
     pub use crate::shim::shim_ttrpc_async::{create_task, Task, TaskClient};
 
+    /// Shim task service of the task API v3.
+    pub use crate::task_v3::shim_ttrpc_async::{
+        create_task as create_task_v3, Task as TaskV3, TaskClient as TaskV3Client,
+    };
+
     /// Shim events service.
     pub use crate::shim::events_ttrpc_async::{create_events, Events, EventsClient};
+
+    /// Shim sandbox service.
+    pub use crate::sandbox::sandbox_ttrpc_async::{create_sandbox, Sandbox, SandboxClient};
 }
 
 /// Reexport auto-generated public data structures.
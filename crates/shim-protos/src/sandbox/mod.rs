@@ -0,0 +1,7 @@
+pub mod sandbox;
+pub mod sandbox_ttrpc;
+#[cfg(feature = "async")]
+pub mod sandbox_ttrpc_async;
+
+pub(crate) mod mount;
+pub(crate) mod platform;
@@ -0,0 +1,3 @@
+/// Reexport types::mount::* to avoid manually editing other auto-generated source files.
+
+pub use crate::types::mount::*;
@@ -0,0 +1,3 @@
+/// Reexport types::platform::* to avoid manually editing other auto-generated source files.
+
+pub use crate::types::platform::*;
@@ -0,0 +1,3163 @@
+// This file is generated by rust-protobuf 2.27.1. Do not edit
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_imports)]
+#![allow(unused_results)]
+//! Generated file from `github.com/containerd/containerd/api/runtime/sandbox/v1/sandbox.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+// const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_2_27_1;
+
+#[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
+pub struct CreateSandboxRequest {
+    // message fields
+    pub sandbox_id: ::std::string::String,
+    pub bundle_path: ::std::string::String,
+    pub rootfs: ::protobuf::RepeatedField<super::mount::Mount>,
+    #[cfg_attr(feature = "with-serde", serde(default, with = "crate::json::any"))]
+    pub options: ::protobuf::SingularPtrField<::protobuf::well_known_types::Any>,
+    pub netns_path: ::std::string::String,
+    // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
+    pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a CreateSandboxRequest {
+    fn default() -> &'a CreateSandboxRequest {
+        <CreateSandboxRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl CreateSandboxRequest {
+    pub fn new() -> CreateSandboxRequest {
+        ::std::default::Default::default()
+    }
+
+    // string sandbox_id = 1;
+
+
+    pub fn get_sandbox_id(&self) -> &str {
+        &self.sandbox_id
+    }
+    pub fn clear_sandbox_id(&mut self) {
+        self.sandbox_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_sandbox_id(&mut self, v: ::std::string::String) {
+        self.sandbox_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_sandbox_id(&mut self) -> &mut ::std::string::String {
+        &mut self.sandbox_id
+    }
+
+    // Take field
+    pub fn take_sandbox_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.sandbox_id, ::std::string::String::new())
+    }
+
+    // string bundle_path = 2;
+
+
+    pub fn get_bundle_path(&self) -> &str {
+        &self.bundle_path
+    }
+    pub fn clear_bundle_path(&mut self) {
+        self.bundle_path.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_bundle_path(&mut self, v: ::std::string::String) {
+        self.bundle_path = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_bundle_path(&mut self) -> &mut ::std::string::String {
+        &mut self.bundle_path
+    }
+
+    // Take field
+    pub fn take_bundle_path(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.bundle_path, ::std::string::String::new())
+    }
+
+    // repeated .containerd.types.Mount rootfs = 3;
+
+
+    pub fn get_rootfs(&self) -> &[super::mount::Mount] {
+        &self.rootfs
+    }
+    pub fn clear_rootfs(&mut self) {
+        self.rootfs.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_rootfs(&mut self, v: ::protobuf::RepeatedField<super::mount::Mount>) {
+        self.rootfs = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_rootfs(&mut self) -> &mut ::protobuf::RepeatedField<super::mount::Mount> {
+        &mut self.rootfs
+    }
+
+    // Take field
+    pub fn take_rootfs(&mut self) -> ::protobuf::RepeatedField<super::mount::Mount> {
+        ::std::mem::replace(&mut self.rootfs, ::protobuf::RepeatedField::new())
+    }
+
+    // .google.protobuf.Any options = 4;
+
+
+    pub fn get_options(&self) -> &::protobuf::well_known_types::Any {
+        self.options.as_ref().unwrap_or_else(|| <::protobuf::well_known_types::Any as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_options(&mut self) {
+        self.options.clear();
+    }
+
+    pub fn has_options(&self) -> bool {
+        self.options.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_options(&mut self, v: ::protobuf::well_known_types::Any) {
+        self.options = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_options(&mut self) -> &mut ::protobuf::well_known_types::Any {
+        if self.options.is_none() {
+            self.options.set_default();
+        }
+        self.options.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_options(&mut self) -> ::protobuf::well_known_types::Any {
+        self.options.take().unwrap_or_else(|| ::protobuf::well_known_types::Any::new())
+    }
+
+    // string netns_path = 5;
+
+
+    pub fn get_netns_path(&self) -> &str {
+        &self.netns_path
+    }
+    pub fn clear_netns_path(&mut self) {
+        self.netns_path.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_netns_path(&mut self, v: ::std::string::String) {
+        self.netns_path = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_netns_path(&mut self) -> &mut ::std::string::String {
+        &mut self.netns_path
+    }
+
+    // Take field
+    pub fn take_netns_path(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.netns_path, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for CreateSandboxRequest {
+    fn is_initialized(&self) -> bool {
+        for v in &self.rootfs {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.options {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.sandbox_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.bundle_path)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.rootfs)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.options)?;
+                },
+                5 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.netns_path)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.sandbox_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.sandbox_id);
+        }
+        if !self.bundle_path.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.bundle_path);
+        }
+        for value in &self.rootfs {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        if let Some(ref v) = self.options.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if !self.netns_path.is_empty() {
+            my_size += ::protobuf::rt::string_size(5, &self.netns_path);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.sandbox_id.is_empty() {
+            os.write_string(1, &self.sandbox_id)?;
+        }
+        if !self.bundle_path.is_empty() {
+            os.write_string(2, &self.bundle_path)?;
+        }
+        for v in &self.rootfs {
+            os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        if let Some(ref v) = self.options.as_ref() {
+            os.write_tag(4, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if !self.netns_path.is_empty() {
+            os.write_string(5, &self.netns_path)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> CreateSandboxRequest {
+        CreateSandboxRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "sandbox_id",
+                |m: &CreateSandboxRequest| { &m.sandbox_id },
+                |m: &mut CreateSandboxRequest| { &mut m.sandbox_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "bundle_path",
+                |m: &CreateSandboxRequest| { &m.bundle_path },
+                |m: &mut CreateSandboxRequest| { &mut m.bundle_path },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::mount::Mount>>(
+                "rootfs",
+                |m: &CreateSandboxRequest| { &m.rootfs },
+                |m: &mut CreateSandboxRequest| { &mut m.rootfs },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<::protobuf::well_known_types::Any>>(
+                "options",
+                |m: &CreateSandboxRequest| { &m.options },
+                |m: &mut CreateSandboxRequest| { &mut m.options },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "netns_path",
+                |m: &CreateSandboxRequest| { &m.netns_path },
+                |m: &mut CreateSandboxRequest| { &mut m.netns_path },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<CreateSandboxRequest>(
+                "CreateSandboxRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static CreateSandboxRequest {
+        static instance: ::protobuf::rt::LazyV2<CreateSandboxRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(CreateSandboxRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for CreateSandboxRequest {
+    fn clear(&mut self) {
+        self.sandbox_id.clear();
+        self.bundle_path.clear();
+        self.rootfs.clear();
+        self.options.clear();
+        self.netns_path.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for CreateSandboxRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CreateSandboxRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
+pub struct CreateSandboxResponse {
+    // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
+    pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a CreateSandboxResponse {
+    fn default() -> &'a CreateSandboxResponse {
+        <CreateSandboxResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl CreateSandboxResponse {
+    pub fn new() -> CreateSandboxResponse {
+        ::std::default::Default::default()
+    }
+}
+
+impl ::protobuf::Message for CreateSandboxResponse {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> CreateSandboxResponse {
+        CreateSandboxResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let fields = ::std::vec::Vec::new();
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<CreateSandboxResponse>(
+                "CreateSandboxResponse",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static CreateSandboxResponse {
+        static instance: ::protobuf::rt::LazyV2<CreateSandboxResponse> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(CreateSandboxResponse::new)
+    }
+}
+
+impl ::protobuf::Clear for CreateSandboxResponse {
+    fn clear(&mut self) {
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for CreateSandboxResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CreateSandboxResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
+pub struct StartSandboxRequest {
+    // message fields
+    pub sandbox_id: ::std::string::String,
+    // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
+    pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a StartSandboxRequest {
+    fn default() -> &'a StartSandboxRequest {
+        <StartSandboxRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl StartSandboxRequest {
+    pub fn new() -> StartSandboxRequest {
+        ::std::default::Default::default()
+    }
+
+    // string sandbox_id = 1;
+
+
+    pub fn get_sandbox_id(&self) -> &str {
+        &self.sandbox_id
+    }
+    pub fn clear_sandbox_id(&mut self) {
+        self.sandbox_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_sandbox_id(&mut self, v: ::std::string::String) {
+        self.sandbox_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_sandbox_id(&mut self) -> &mut ::std::string::String {
+        &mut self.sandbox_id
+    }
+
+    // Take field
+    pub fn take_sandbox_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.sandbox_id, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for StartSandboxRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.sandbox_id)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.sandbox_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.sandbox_id);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.sandbox_id.is_empty() {
+            os.write_string(1, &self.sandbox_id)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> StartSandboxRequest {
+        StartSandboxRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "sandbox_id",
+                |m: &StartSandboxRequest| { &m.sandbox_id },
+                |m: &mut StartSandboxRequest| { &mut m.sandbox_id },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<StartSandboxRequest>(
+                "StartSandboxRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static StartSandboxRequest {
+        static instance: ::protobuf::rt::LazyV2<StartSandboxRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(StartSandboxRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for StartSandboxRequest {
+    fn clear(&mut self) {
+        self.sandbox_id.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for StartSandboxRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for StartSandboxRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
+pub struct StartSandboxResponse {
+    // message fields
+    pub pid: u32,
+    #[cfg_attr(feature = "with-serde", serde(default, with = "crate::json::timestamp"))]
+    pub created_at: ::protobuf::SingularPtrField<::protobuf::well_known_types::Timestamp>,
+    // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
+    pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a StartSandboxResponse {
+    fn default() -> &'a StartSandboxResponse {
+        <StartSandboxResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl StartSandboxResponse {
+    pub fn new() -> StartSandboxResponse {
+        ::std::default::Default::default()
+    }
+
+    // uint32 pid = 1;
+
+
+    pub fn get_pid(&self) -> u32 {
+        self.pid
+    }
+    pub fn clear_pid(&mut self) {
+        self.pid = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_pid(&mut self, v: u32) {
+        self.pid = v;
+    }
+
+    // .google.protobuf.Timestamp created_at = 2;
+
+
+    pub fn get_created_at(&self) -> &::protobuf::well_known_types::Timestamp {
+        self.created_at.as_ref().unwrap_or_else(|| <::protobuf::well_known_types::Timestamp as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_created_at(&mut self) {
+        self.created_at.clear();
+    }
+
+    pub fn has_created_at(&self) -> bool {
+        self.created_at.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_created_at(&mut self, v: ::protobuf::well_known_types::Timestamp) {
+        self.created_at = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_created_at(&mut self) -> &mut ::protobuf::well_known_types::Timestamp {
+        if self.created_at.is_none() {
+            self.created_at.set_default();
+        }
+        self.created_at.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_created_at(&mut self) -> ::protobuf::well_known_types::Timestamp {
+        self.created_at.take().unwrap_or_else(|| ::protobuf::well_known_types::Timestamp::new())
+    }
+}
+
+impl ::protobuf::Message for StartSandboxResponse {
+    fn is_initialized(&self) -> bool {
+        for v in &self.created_at {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.pid = tmp;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.created_at)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.pid != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.pid, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(ref v) = self.created_at.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.pid != 0 {
+            os.write_uint32(1, self.pid)?;
+        }
+        if let Some(ref v) = self.created_at.as_ref() {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> StartSandboxResponse {
+        StartSandboxResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "pid",
+                |m: &StartSandboxResponse| { &m.pid },
+                |m: &mut StartSandboxResponse| { &mut m.pid },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<::protobuf::well_known_types::Timestamp>>(
+                "created_at",
+                |m: &StartSandboxResponse| { &m.created_at },
+                |m: &mut StartSandboxResponse| { &mut m.created_at },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<StartSandboxResponse>(
+                "StartSandboxResponse",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static StartSandboxResponse {
+        static instance: ::protobuf::rt::LazyV2<StartSandboxResponse> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(StartSandboxResponse::new)
+    }
+}
+
+impl ::protobuf::Clear for StartSandboxResponse {
+    fn clear(&mut self) {
+        self.pid = 0;
+        self.created_at.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for StartSandboxResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for StartSandboxResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
+pub struct PlatformRequest {
+    // message fields
+    pub sandbox_id: ::std::string::String,
+    // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
+    pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a PlatformRequest {
+    fn default() -> &'a PlatformRequest {
+        <PlatformRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl PlatformRequest {
+    pub fn new() -> PlatformRequest {
+        ::std::default::Default::default()
+    }
+
+    // string sandbox_id = 1;
+
+
+    pub fn get_sandbox_id(&self) -> &str {
+        &self.sandbox_id
+    }
+    pub fn clear_sandbox_id(&mut self) {
+        self.sandbox_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_sandbox_id(&mut self, v: ::std::string::String) {
+        self.sandbox_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_sandbox_id(&mut self) -> &mut ::std::string::String {
+        &mut self.sandbox_id
+    }
+
+    // Take field
+    pub fn take_sandbox_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.sandbox_id, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for PlatformRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.sandbox_id)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.sandbox_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.sandbox_id);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.sandbox_id.is_empty() {
+            os.write_string(1, &self.sandbox_id)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> PlatformRequest {
+        PlatformRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "sandbox_id",
+                |m: &PlatformRequest| { &m.sandbox_id },
+                |m: &mut PlatformRequest| { &mut m.sandbox_id },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<PlatformRequest>(
+                "PlatformRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static PlatformRequest {
+        static instance: ::protobuf::rt::LazyV2<PlatformRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(PlatformRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for PlatformRequest {
+    fn clear(&mut self) {
+        self.sandbox_id.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for PlatformRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for PlatformRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
+pub struct PlatformResponse {
+    // message fields
+    pub platform: ::protobuf::SingularPtrField<super::platform::Platform>,
+    // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
+    pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a PlatformResponse {
+    fn default() -> &'a PlatformResponse {
+        <PlatformResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl PlatformResponse {
+    pub fn new() -> PlatformResponse {
+        ::std::default::Default::default()
+    }
+
+    // .containerd.types.Platform platform = 1;
+
+
+    pub fn get_platform(&self) -> &super::platform::Platform {
+        self.platform.as_ref().unwrap_or_else(|| <super::platform::Platform as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_platform(&mut self) {
+        self.platform.clear();
+    }
+
+    pub fn has_platform(&self) -> bool {
+        self.platform.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_platform(&mut self, v: super::platform::Platform) {
+        self.platform = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_platform(&mut self) -> &mut super::platform::Platform {
+        if self.platform.is_none() {
+            self.platform.set_default();
+        }
+        self.platform.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_platform(&mut self) -> super::platform::Platform {
+        self.platform.take().unwrap_or_else(|| super::platform::Platform::new())
+    }
+}
+
+impl ::protobuf::Message for PlatformResponse {
+    fn is_initialized(&self) -> bool {
+        for v in &self.platform {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.platform)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.platform.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.platform.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> PlatformResponse {
+        PlatformResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::platform::Platform>>(
+                "platform",
+                |m: &PlatformResponse| { &m.platform },
+                |m: &mut PlatformResponse| { &mut m.platform },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<PlatformResponse>(
+                "PlatformResponse",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static PlatformResponse {
+        static instance: ::protobuf::rt::LazyV2<PlatformResponse> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(PlatformResponse::new)
+    }
+}
+
+impl ::protobuf::Clear for PlatformResponse {
+    fn clear(&mut self) {
+        self.platform.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for PlatformResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for PlatformResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
+pub struct StopSandboxRequest {
+    // message fields
+    pub sandbox_id: ::std::string::String,
+    pub timeout_secs: u32,
+    // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
+    pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a StopSandboxRequest {
+    fn default() -> &'a StopSandboxRequest {
+        <StopSandboxRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl StopSandboxRequest {
+    pub fn new() -> StopSandboxRequest {
+        ::std::default::Default::default()
+    }
+
+    // string sandbox_id = 1;
+
+
+    pub fn get_sandbox_id(&self) -> &str {
+        &self.sandbox_id
+    }
+    pub fn clear_sandbox_id(&mut self) {
+        self.sandbox_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_sandbox_id(&mut self, v: ::std::string::String) {
+        self.sandbox_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_sandbox_id(&mut self) -> &mut ::std::string::String {
+        &mut self.sandbox_id
+    }
+
+    // Take field
+    pub fn take_sandbox_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.sandbox_id, ::std::string::String::new())
+    }
+
+    // uint32 timeout_secs = 2;
+
+
+    pub fn get_timeout_secs(&self) -> u32 {
+        self.timeout_secs
+    }
+    pub fn clear_timeout_secs(&mut self) {
+        self.timeout_secs = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_timeout_secs(&mut self, v: u32) {
+        self.timeout_secs = v;
+    }
+}
+
+impl ::protobuf::Message for StopSandboxRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.sandbox_id)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.timeout_secs = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.sandbox_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.sandbox_id);
+        }
+        if self.timeout_secs != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.timeout_secs, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.sandbox_id.is_empty() {
+            os.write_string(1, &self.sandbox_id)?;
+        }
+        if self.timeout_secs != 0 {
+            os.write_uint32(2, self.timeout_secs)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> StopSandboxRequest {
+        StopSandboxRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "sandbox_id",
+                |m: &StopSandboxRequest| { &m.sandbox_id },
+                |m: &mut StopSandboxRequest| { &mut m.sandbox_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "timeout_secs",
+                |m: &StopSandboxRequest| { &m.timeout_secs },
+                |m: &mut StopSandboxRequest| { &mut m.timeout_secs },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<StopSandboxRequest>(
+                "StopSandboxRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static StopSandboxRequest {
+        static instance: ::protobuf::rt::LazyV2<StopSandboxRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(StopSandboxRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for StopSandboxRequest {
+    fn clear(&mut self) {
+        self.sandbox_id.clear();
+        self.timeout_secs = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for StopSandboxRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for StopSandboxRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
+pub struct StopSandboxResponse {
+    // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
+    pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a StopSandboxResponse {
+    fn default() -> &'a StopSandboxResponse {
+        <StopSandboxResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl StopSandboxResponse {
+    pub fn new() -> StopSandboxResponse {
+        ::std::default::Default::default()
+    }
+}
+
+impl ::protobuf::Message for StopSandboxResponse {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> StopSandboxResponse {
+        StopSandboxResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let fields = ::std::vec::Vec::new();
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<StopSandboxResponse>(
+                "StopSandboxResponse",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static StopSandboxResponse {
+        static instance: ::protobuf::rt::LazyV2<StopSandboxResponse> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(StopSandboxResponse::new)
+    }
+}
+
+impl ::protobuf::Clear for StopSandboxResponse {
+    fn clear(&mut self) {
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for StopSandboxResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for StopSandboxResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
+pub struct WaitSandboxRequest {
+    // message fields
+    pub sandbox_id: ::std::string::String,
+    // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
+    pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a WaitSandboxRequest {
+    fn default() -> &'a WaitSandboxRequest {
+        <WaitSandboxRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl WaitSandboxRequest {
+    pub fn new() -> WaitSandboxRequest {
+        ::std::default::Default::default()
+    }
+
+    // string sandbox_id = 1;
+
+
+    pub fn get_sandbox_id(&self) -> &str {
+        &self.sandbox_id
+    }
+    pub fn clear_sandbox_id(&mut self) {
+        self.sandbox_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_sandbox_id(&mut self, v: ::std::string::String) {
+        self.sandbox_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_sandbox_id(&mut self) -> &mut ::std::string::String {
+        &mut self.sandbox_id
+    }
+
+    // Take field
+    pub fn take_sandbox_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.sandbox_id, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for WaitSandboxRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.sandbox_id)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.sandbox_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.sandbox_id);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.sandbox_id.is_empty() {
+            os.write_string(1, &self.sandbox_id)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> WaitSandboxRequest {
+        WaitSandboxRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "sandbox_id",
+                |m: &WaitSandboxRequest| { &m.sandbox_id },
+                |m: &mut WaitSandboxRequest| { &mut m.sandbox_id },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<WaitSandboxRequest>(
+                "WaitSandboxRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static WaitSandboxRequest {
+        static instance: ::protobuf::rt::LazyV2<WaitSandboxRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(WaitSandboxRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for WaitSandboxRequest {
+    fn clear(&mut self) {
+        self.sandbox_id.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for WaitSandboxRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for WaitSandboxRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
+pub struct WaitSandboxResponse {
+    // message fields
+    pub exit_status: u32,
+    #[cfg_attr(feature = "with-serde", serde(default, with = "crate::json::timestamp"))]
+    pub exited_at: ::protobuf::SingularPtrField<::protobuf::well_known_types::Timestamp>,
+    // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
+    pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a WaitSandboxResponse {
+    fn default() -> &'a WaitSandboxResponse {
+        <WaitSandboxResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl WaitSandboxResponse {
+    pub fn new() -> WaitSandboxResponse {
+        ::std::default::Default::default()
+    }
+
+    // uint32 exit_status = 1;
+
+
+    pub fn get_exit_status(&self) -> u32 {
+        self.exit_status
+    }
+    pub fn clear_exit_status(&mut self) {
+        self.exit_status = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_exit_status(&mut self, v: u32) {
+        self.exit_status = v;
+    }
+
+    // .google.protobuf.Timestamp exited_at = 2;
+
+
+    pub fn get_exited_at(&self) -> &::protobuf::well_known_types::Timestamp {
+        self.exited_at.as_ref().unwrap_or_else(|| <::protobuf::well_known_types::Timestamp as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_exited_at(&mut self) {
+        self.exited_at.clear();
+    }
+
+    pub fn has_exited_at(&self) -> bool {
+        self.exited_at.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_exited_at(&mut self, v: ::protobuf::well_known_types::Timestamp) {
+        self.exited_at = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_exited_at(&mut self) -> &mut ::protobuf::well_known_types::Timestamp {
+        if self.exited_at.is_none() {
+            self.exited_at.set_default();
+        }
+        self.exited_at.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_exited_at(&mut self) -> ::protobuf::well_known_types::Timestamp {
+        self.exited_at.take().unwrap_or_else(|| ::protobuf::well_known_types::Timestamp::new())
+    }
+}
+
+impl ::protobuf::Message for WaitSandboxResponse {
+    fn is_initialized(&self) -> bool {
+        for v in &self.exited_at {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.exit_status = tmp;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.exited_at)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.exit_status != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.exit_status, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(ref v) = self.exited_at.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.exit_status != 0 {
+            os.write_uint32(1, self.exit_status)?;
+        }
+        if let Some(ref v) = self.exited_at.as_ref() {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> WaitSandboxResponse {
+        WaitSandboxResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "exit_status",
+                |m: &WaitSandboxResponse| { &m.exit_status },
+                |m: &mut WaitSandboxResponse| { &mut m.exit_status },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<::protobuf::well_known_types::Timestamp>>(
+                "exited_at",
+                |m: &WaitSandboxResponse| { &m.exited_at },
+                |m: &mut WaitSandboxResponse| { &mut m.exited_at },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<WaitSandboxResponse>(
+                "WaitSandboxResponse",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static WaitSandboxResponse {
+        static instance: ::protobuf::rt::LazyV2<WaitSandboxResponse> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(WaitSandboxResponse::new)
+    }
+}
+
+impl ::protobuf::Clear for WaitSandboxResponse {
+    fn clear(&mut self) {
+        self.exit_status = 0;
+        self.exited_at.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for WaitSandboxResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for WaitSandboxResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
+pub struct SandboxStatusRequest {
+    // message fields
+    pub sandbox_id: ::std::string::String,
+    pub verbose: bool,
+    // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
+    pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a SandboxStatusRequest {
+    fn default() -> &'a SandboxStatusRequest {
+        <SandboxStatusRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl SandboxStatusRequest {
+    pub fn new() -> SandboxStatusRequest {
+        ::std::default::Default::default()
+    }
+
+    // string sandbox_id = 1;
+
+
+    pub fn get_sandbox_id(&self) -> &str {
+        &self.sandbox_id
+    }
+    pub fn clear_sandbox_id(&mut self) {
+        self.sandbox_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_sandbox_id(&mut self, v: ::std::string::String) {
+        self.sandbox_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_sandbox_id(&mut self) -> &mut ::std::string::String {
+        &mut self.sandbox_id
+    }
+
+    // Take field
+    pub fn take_sandbox_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.sandbox_id, ::std::string::String::new())
+    }
+
+    // bool verbose = 2;
+
+
+    pub fn get_verbose(&self) -> bool {
+        self.verbose
+    }
+    pub fn clear_verbose(&mut self) {
+        self.verbose = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_verbose(&mut self, v: bool) {
+        self.verbose = v;
+    }
+}
+
+impl ::protobuf::Message for SandboxStatusRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.sandbox_id)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.verbose = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.sandbox_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.sandbox_id);
+        }
+        if self.verbose != false {
+            my_size += 2;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.sandbox_id.is_empty() {
+            os.write_string(1, &self.sandbox_id)?;
+        }
+        if self.verbose != false {
+            os.write_bool(2, self.verbose)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> SandboxStatusRequest {
+        SandboxStatusRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "sandbox_id",
+                |m: &SandboxStatusRequest| { &m.sandbox_id },
+                |m: &mut SandboxStatusRequest| { &mut m.sandbox_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "verbose",
+                |m: &SandboxStatusRequest| { &m.verbose },
+                |m: &mut SandboxStatusRequest| { &mut m.verbose },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<SandboxStatusRequest>(
+                "SandboxStatusRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static SandboxStatusRequest {
+        static instance: ::protobuf::rt::LazyV2<SandboxStatusRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(SandboxStatusRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for SandboxStatusRequest {
+    fn clear(&mut self) {
+        self.sandbox_id.clear();
+        self.verbose = false;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for SandboxStatusRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for SandboxStatusRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
+pub struct SandboxStatusResponse {
+    // message fields
+    pub sandbox_id: ::std::string::String,
+    pub pid: u32,
+    pub state: ::std::string::String,
+    pub info: ::std::collections::HashMap<::std::string::String, ::std::string::String>,
+    #[cfg_attr(feature = "with-serde", serde(default, with = "crate::json::timestamp"))]
+    pub created_at: ::protobuf::SingularPtrField<::protobuf::well_known_types::Timestamp>,
+    #[cfg_attr(feature = "with-serde", serde(default, with = "crate::json::timestamp"))]
+    pub exited_at: ::protobuf::SingularPtrField<::protobuf::well_known_types::Timestamp>,
+    #[cfg_attr(feature = "with-serde", serde(default, with = "crate::json::any"))]
+    pub extra: ::protobuf::SingularPtrField<::protobuf::well_known_types::Any>,
+    // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
+    pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a SandboxStatusResponse {
+    fn default() -> &'a SandboxStatusResponse {
+        <SandboxStatusResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl SandboxStatusResponse {
+    pub fn new() -> SandboxStatusResponse {
+        ::std::default::Default::default()
+    }
+
+    // string sandbox_id = 1;
+
+
+    pub fn get_sandbox_id(&self) -> &str {
+        &self.sandbox_id
+    }
+    pub fn clear_sandbox_id(&mut self) {
+        self.sandbox_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_sandbox_id(&mut self, v: ::std::string::String) {
+        self.sandbox_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_sandbox_id(&mut self) -> &mut ::std::string::String {
+        &mut self.sandbox_id
+    }
+
+    // Take field
+    pub fn take_sandbox_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.sandbox_id, ::std::string::String::new())
+    }
+
+    // uint32 pid = 2;
+
+
+    pub fn get_pid(&self) -> u32 {
+        self.pid
+    }
+    pub fn clear_pid(&mut self) {
+        self.pid = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_pid(&mut self, v: u32) {
+        self.pid = v;
+    }
+
+    // string state = 3;
+
+
+    pub fn get_state(&self) -> &str {
+        &self.state
+    }
+    pub fn clear_state(&mut self) {
+        self.state.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_state(&mut self, v: ::std::string::String) {
+        self.state = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_state(&mut self) -> &mut ::std::string::String {
+        &mut self.state
+    }
+
+    // Take field
+    pub fn take_state(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.state, ::std::string::String::new())
+    }
+
+    // repeated .containerd.runtime.sandbox.v1.SandboxStatusResponse.info_MapEntry info = 4;
+
+
+    pub fn get_info(&self) -> &::std::collections::HashMap<::std::string::String, ::std::string::String> {
+        &self.info
+    }
+    pub fn clear_info(&mut self) {
+        self.info.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_info(&mut self, v: ::std::collections::HashMap<::std::string::String, ::std::string::String>) {
+        self.info = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_info(&mut self) -> &mut ::std::collections::HashMap<::std::string::String, ::std::string::String> {
+        &mut self.info
+    }
+
+    // Take field
+    pub fn take_info(&mut self) -> ::std::collections::HashMap<::std::string::String, ::std::string::String> {
+        ::std::mem::replace(&mut self.info, ::std::collections::HashMap::new())
+    }
+
+    // .google.protobuf.Timestamp created_at = 5;
+
+
+    pub fn get_created_at(&self) -> &::protobuf::well_known_types::Timestamp {
+        self.created_at.as_ref().unwrap_or_else(|| <::protobuf::well_known_types::Timestamp as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_created_at(&mut self) {
+        self.created_at.clear();
+    }
+
+    pub fn has_created_at(&self) -> bool {
+        self.created_at.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_created_at(&mut self, v: ::protobuf::well_known_types::Timestamp) {
+        self.created_at = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_created_at(&mut self) -> &mut ::protobuf::well_known_types::Timestamp {
+        if self.created_at.is_none() {
+            self.created_at.set_default();
+        }
+        self.created_at.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_created_at(&mut self) -> ::protobuf::well_known_types::Timestamp {
+        self.created_at.take().unwrap_or_else(|| ::protobuf::well_known_types::Timestamp::new())
+    }
+
+    // .google.protobuf.Timestamp exited_at = 6;
+
+
+    pub fn get_exited_at(&self) -> &::protobuf::well_known_types::Timestamp {
+        self.exited_at.as_ref().unwrap_or_else(|| <::protobuf::well_known_types::Timestamp as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_exited_at(&mut self) {
+        self.exited_at.clear();
+    }
+
+    pub fn has_exited_at(&self) -> bool {
+        self.exited_at.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_exited_at(&mut self, v: ::protobuf::well_known_types::Timestamp) {
+        self.exited_at = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_exited_at(&mut self) -> &mut ::protobuf::well_known_types::Timestamp {
+        if self.exited_at.is_none() {
+            self.exited_at.set_default();
+        }
+        self.exited_at.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_exited_at(&mut self) -> ::protobuf::well_known_types::Timestamp {
+        self.exited_at.take().unwrap_or_else(|| ::protobuf::well_known_types::Timestamp::new())
+    }
+
+    // .google.protobuf.Any extra = 7;
+
+
+    pub fn get_extra(&self) -> &::protobuf::well_known_types::Any {
+        self.extra.as_ref().unwrap_or_else(|| <::protobuf::well_known_types::Any as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_extra(&mut self) {
+        self.extra.clear();
+    }
+
+    pub fn has_extra(&self) -> bool {
+        self.extra.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_extra(&mut self, v: ::protobuf::well_known_types::Any) {
+        self.extra = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_extra(&mut self) -> &mut ::protobuf::well_known_types::Any {
+        if self.extra.is_none() {
+            self.extra.set_default();
+        }
+        self.extra.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_extra(&mut self) -> ::protobuf::well_known_types::Any {
+        self.extra.take().unwrap_or_else(|| ::protobuf::well_known_types::Any::new())
+    }
+}
+
+impl ::protobuf::Message for SandboxStatusResponse {
+    fn is_initialized(&self) -> bool {
+        for v in &self.created_at {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.exited_at {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.extra {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.sandbox_id)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.pid = tmp;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.state)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_map_into::<::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeString>(wire_type, is, &mut self.info)?;
+                },
+                5 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.created_at)?;
+                },
+                6 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.exited_at)?;
+                },
+                7 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.extra)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.sandbox_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.sandbox_id);
+        }
+        if self.pid != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.pid, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if !self.state.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.state);
+        }
+        my_size += ::protobuf::rt::compute_map_size::<::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeString>(4, &self.info);
+        if let Some(ref v) = self.created_at.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let Some(ref v) = self.exited_at.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let Some(ref v) = self.extra.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.sandbox_id.is_empty() {
+            os.write_string(1, &self.sandbox_id)?;
+        }
+        if self.pid != 0 {
+            os.write_uint32(2, self.pid)?;
+        }
+        if !self.state.is_empty() {
+            os.write_string(3, &self.state)?;
+        }
+        ::protobuf::rt::write_map_with_cached_sizes::<::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeString>(4, &self.info, os)?;
+        if let Some(ref v) = self.created_at.as_ref() {
+            os.write_tag(5, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let Some(ref v) = self.exited_at.as_ref() {
+            os.write_tag(6, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let Some(ref v) = self.extra.as_ref() {
+            os.write_tag(7, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> SandboxStatusResponse {
+        SandboxStatusResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "sandbox_id",
+                |m: &SandboxStatusResponse| { &m.sandbox_id },
+                |m: &mut SandboxStatusResponse| { &mut m.sandbox_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "pid",
+                |m: &SandboxStatusResponse| { &m.pid },
+                |m: &mut SandboxStatusResponse| { &mut m.pid },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "state",
+                |m: &SandboxStatusResponse| { &m.state },
+                |m: &mut SandboxStatusResponse| { &mut m.state },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_map_accessor::<_, ::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeString>(
+                "info",
+                |m: &SandboxStatusResponse| { &m.info },
+                |m: &mut SandboxStatusResponse| { &mut m.info },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<::protobuf::well_known_types::Timestamp>>(
+                "created_at",
+                |m: &SandboxStatusResponse| { &m.created_at },
+                |m: &mut SandboxStatusResponse| { &mut m.created_at },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<::protobuf::well_known_types::Timestamp>>(
+                "exited_at",
+                |m: &SandboxStatusResponse| { &m.exited_at },
+                |m: &mut SandboxStatusResponse| { &mut m.exited_at },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<::protobuf::well_known_types::Any>>(
+                "extra",
+                |m: &SandboxStatusResponse| { &m.extra },
+                |m: &mut SandboxStatusResponse| { &mut m.extra },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<SandboxStatusResponse>(
+                "SandboxStatusResponse",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static SandboxStatusResponse {
+        static instance: ::protobuf::rt::LazyV2<SandboxStatusResponse> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(SandboxStatusResponse::new)
+    }
+}
+
+impl ::protobuf::Clear for SandboxStatusResponse {
+    fn clear(&mut self) {
+        self.sandbox_id.clear();
+        self.pid = 0;
+        self.state.clear();
+        self.info.clear();
+        self.created_at.clear();
+        self.exited_at.clear();
+        self.extra.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for SandboxStatusResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for SandboxStatusResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
+pub struct PingRequest {
+    // message fields
+    pub sandbox_id: ::std::string::String,
+    // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
+    pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a PingRequest {
+    fn default() -> &'a PingRequest {
+        <PingRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl PingRequest {
+    pub fn new() -> PingRequest {
+        ::std::default::Default::default()
+    }
+
+    // string sandbox_id = 1;
+
+
+    pub fn get_sandbox_id(&self) -> &str {
+        &self.sandbox_id
+    }
+    pub fn clear_sandbox_id(&mut self) {
+        self.sandbox_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_sandbox_id(&mut self, v: ::std::string::String) {
+        self.sandbox_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_sandbox_id(&mut self) -> &mut ::std::string::String {
+        &mut self.sandbox_id
+    }
+
+    // Take field
+    pub fn take_sandbox_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.sandbox_id, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for PingRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.sandbox_id)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.sandbox_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.sandbox_id);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.sandbox_id.is_empty() {
+            os.write_string(1, &self.sandbox_id)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> PingRequest {
+        PingRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "sandbox_id",
+                |m: &PingRequest| { &m.sandbox_id },
+                |m: &mut PingRequest| { &mut m.sandbox_id },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<PingRequest>(
+                "PingRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static PingRequest {
+        static instance: ::protobuf::rt::LazyV2<PingRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(PingRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for PingRequest {
+    fn clear(&mut self) {
+        self.sandbox_id.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for PingRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for PingRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
+pub struct PingResponse {
+    // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
+    pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a PingResponse {
+    fn default() -> &'a PingResponse {
+        <PingResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl PingResponse {
+    pub fn new() -> PingResponse {
+        ::std::default::Default::default()
+    }
+}
+
+impl ::protobuf::Message for PingResponse {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> PingResponse {
+        PingResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let fields = ::std::vec::Vec::new();
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<PingResponse>(
+                "PingResponse",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static PingResponse {
+        static instance: ::protobuf::rt::LazyV2<PingResponse> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(PingResponse::new)
+    }
+}
+
+impl ::protobuf::Clear for PingResponse {
+    fn clear(&mut self) {
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for PingResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for PingResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
+pub struct ShutdownSandboxRequest {
+    // message fields
+    pub sandbox_id: ::std::string::String,
+    // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
+    pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ShutdownSandboxRequest {
+    fn default() -> &'a ShutdownSandboxRequest {
+        <ShutdownSandboxRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ShutdownSandboxRequest {
+    pub fn new() -> ShutdownSandboxRequest {
+        ::std::default::Default::default()
+    }
+
+    // string sandbox_id = 1;
+
+
+    pub fn get_sandbox_id(&self) -> &str {
+        &self.sandbox_id
+    }
+    pub fn clear_sandbox_id(&mut self) {
+        self.sandbox_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_sandbox_id(&mut self, v: ::std::string::String) {
+        self.sandbox_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_sandbox_id(&mut self) -> &mut ::std::string::String {
+        &mut self.sandbox_id
+    }
+
+    // Take field
+    pub fn take_sandbox_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.sandbox_id, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for ShutdownSandboxRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.sandbox_id)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.sandbox_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.sandbox_id);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.sandbox_id.is_empty() {
+            os.write_string(1, &self.sandbox_id)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ShutdownSandboxRequest {
+        ShutdownSandboxRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "sandbox_id",
+                |m: &ShutdownSandboxRequest| { &m.sandbox_id },
+                |m: &mut ShutdownSandboxRequest| { &mut m.sandbox_id },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<ShutdownSandboxRequest>(
+                "ShutdownSandboxRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static ShutdownSandboxRequest {
+        static instance: ::protobuf::rt::LazyV2<ShutdownSandboxRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(ShutdownSandboxRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for ShutdownSandboxRequest {
+    fn clear(&mut self) {
+        self.sandbox_id.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ShutdownSandboxRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ShutdownSandboxRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
+pub struct ShutdownSandboxResponse {
+    // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
+    pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ShutdownSandboxResponse {
+    fn default() -> &'a ShutdownSandboxResponse {
+        <ShutdownSandboxResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ShutdownSandboxResponse {
+    pub fn new() -> ShutdownSandboxResponse {
+        ::std::default::Default::default()
+    }
+}
+
+impl ::protobuf::Message for ShutdownSandboxResponse {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ShutdownSandboxResponse {
+        ShutdownSandboxResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let fields = ::std::vec::Vec::new();
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<ShutdownSandboxResponse>(
+                "ShutdownSandboxResponse",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static ShutdownSandboxResponse {
+        static instance: ::protobuf::rt::LazyV2<ShutdownSandboxResponse> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(ShutdownSandboxResponse::new)
+    }
+}
+
+impl ::protobuf::Clear for ShutdownSandboxResponse {
+    fn clear(&mut self) {
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ShutdownSandboxResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ShutdownSandboxResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \nEgithub.com/containerd/containerd/api/runtime/sandbox/v1/sandbox.proto\
+    \x12\x1dcontainerd.runtime.sandbox.v1\x1a\x19google/protobuf/any.proto\
+    \x1a\x1fgoogle/protobuf/timestamp.proto\x1a6github.com/containerd/contai\
+    nerd/api/types/mount.proto\x1a9github.com/containerd/containerd/api/type\
+    s/platform.proto\"\xe2\x01\n\x14CreateSandboxRequest\x12\x1f\n\nsandbox_\
+    id\x18\x01\x20\x01(\tR\tsandboxIdB\0\x12!\n\x0bbundle_path\x18\x02\x20\
+    \x01(\tR\nbundlePathB\0\x121\n\x06rootfs\x18\x03\x20\x03(\x0b2\x17.conta\
+    inerd.types.MountR\x06rootfsB\0\x120\n\x07options\x18\x04\x20\x01(\x0b2\
+    \x14.google.protobuf.AnyR\x07optionsB\0\x12\x1f\n\nnetns_path\x18\x05\
+    \x20\x01(\tR\tnetnsPathB\0:\0\"\x19\n\x15CreateSandboxResponse:\0\"8\n\
+    \x13StartSandboxRequest\x12\x1f\n\nsandbox_id\x18\x01\x20\x01(\tR\tsandb\
+    oxIdB\0:\0\"i\n\x14StartSandboxResponse\x12\x12\n\x03pid\x18\x01\x20\x01\
+    (\rR\x03pidB\0\x12;\n\ncreated_at\x18\x02\x20\x01(\x0b2\x1a.google.proto\
+    buf.TimestampR\tcreatedAtB\0:\0\"4\n\x0fPlatformRequest\x12\x1f\n\nsandb\
+    ox_id\x18\x01\x20\x01(\tR\tsandboxIdB\0:\0\"N\n\x10PlatformResponse\x128\
+    \n\x08platform\x18\x01\x20\x01(\x0b2\x1a.containerd.types.PlatformR\x08p\
+    latformB\0:\0\"\\\n\x12StopSandboxRequest\x12\x1f\n\nsandbox_id\x18\x01\
+    \x20\x01(\tR\tsandboxIdB\0\x12#\n\x0ctimeout_secs\x18\x02\x20\x01(\rR\
+    \x0btimeoutSecsB\0:\0\"\x17\n\x13StopSandboxResponse:\0\"7\n\x12WaitSand\
+    boxRequest\x12\x1f\n\nsandbox_id\x18\x01\x20\x01(\tR\tsandboxIdB\0:\0\"u\
+    \n\x13WaitSandboxResponse\x12!\n\x0bexit_status\x18\x01\x20\x01(\rR\nexi\
+    tStatusB\0\x129\n\texited_at\x18\x02\x20\x01(\x0b2\x1a.google.protobuf.T\
+    imestampR\x08exitedAtB\0:\0\"U\n\x14SandboxStatusRequest\x12\x1f\n\nsand\
+    box_id\x18\x01\x20\x01(\tR\tsandboxIdB\0\x12\x1a\n\x07verbose\x18\x02\
+    \x20\x01(\x08R\x07verboseB\0:\0\"\x9f\x03\n\x15SandboxStatusResponse\x12\
+    \x1f\n\nsandbox_id\x18\x01\x20\x01(\tR\tsandboxIdB\0\x12\x12\n\x03pid\
+    \x18\x02\x20\x01(\rR\x03pidB\0\x12\x16\n\x05state\x18\x03\x20\x01(\tR\
+    \x05stateB\0\x12X\n\x04info\x18\x04\x20\x03(\x0b2B.containerd.runtime.sa\
+    ndbox.v1.SandboxStatusResponse.info_MapEntryR\x04infoB\0\x12;\n\ncreated\
+    _at\x18\x05\x20\x01(\x0b2\x1a.google.protobuf.TimestampR\tcreatedAtB\0\
+    \x129\n\texited_at\x18\x06\x20\x01(\x0b2\x1a.google.protobuf.TimestampR\
+    \x08exitedAtB\0\x12,\n\x05extra\x18\x07\x20\x01(\x0b2\x14.google.protobu\
+    f.AnyR\x05extraB\0\x1a7\n\rinfo_MapEntry\x12\x0e\n\x03key\x18\x01(\tR\
+    \x03key\x12\x12\n\x05value\x18\x02(\tR\x05value:\x028\x01:\0\"0\n\x0bPin\
+    gRequest\x12\x1f\n\nsandbox_id\x18\x01\x20\x01(\tR\tsandboxIdB\0:\0\"\
+    \x10\n\x0cPingResponse:\0\";\n\x16ShutdownSandboxRequest\x12\x1f\n\nsand\
+    box_id\x18\x01\x20\x01(\tR\tsandboxIdB\0:\0\"\x1b\n\x17ShutdownSandboxRe\
+    sponse:\0B\0b\x06proto3\
+";
+
+static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
+
+fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
+    ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+}
+
+pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    file_descriptor_proto_lazy.get(|| {
+        parse_descriptor_proto()
+    })
+}
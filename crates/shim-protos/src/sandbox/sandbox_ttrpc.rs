@@ -0,0 +1,228 @@
+// This file is generated by ttrpc-compiler 0.5.0. Do not edit
+// @generated
+
+// https://github.com/Manishearth/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clipto_camel_casepy)]
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unsafe_code)]
+#![allow(unused_imports)]
+#![allow(unused_results)]
+use protobuf::{CodedInputStream, CodedOutputStream, Message};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct SandboxClient {
+    client: ::ttrpc::Client,
+}
+
+impl SandboxClient {
+    pub fn new(client: ::ttrpc::Client) -> Self {
+        SandboxClient {
+            client: client,
+        }
+    }
+
+    pub fn create_sandbox(&self, ctx: ttrpc::context::Context, req: &super::sandbox::CreateSandboxRequest) -> ::ttrpc::Result<super::sandbox::CreateSandboxResponse> {
+        let mut cres = super::sandbox::CreateSandboxResponse::new();
+        ::ttrpc::client_request!(self, ctx, req, "containerd.runtime.sandbox.v1.Sandbox", "CreateSandbox", cres);
+        Ok(cres)
+    }
+
+    pub fn start_sandbox(&self, ctx: ttrpc::context::Context, req: &super::sandbox::StartSandboxRequest) -> ::ttrpc::Result<super::sandbox::StartSandboxResponse> {
+        let mut cres = super::sandbox::StartSandboxResponse::new();
+        ::ttrpc::client_request!(self, ctx, req, "containerd.runtime.sandbox.v1.Sandbox", "StartSandbox", cres);
+        Ok(cres)
+    }
+
+    pub fn platform(&self, ctx: ttrpc::context::Context, req: &super::sandbox::PlatformRequest) -> ::ttrpc::Result<super::sandbox::PlatformResponse> {
+        let mut cres = super::sandbox::PlatformResponse::new();
+        ::ttrpc::client_request!(self, ctx, req, "containerd.runtime.sandbox.v1.Sandbox", "Platform", cres);
+        Ok(cres)
+    }
+
+    pub fn stop_sandbox(&self, ctx: ttrpc::context::Context, req: &super::sandbox::StopSandboxRequest) -> ::ttrpc::Result<super::sandbox::StopSandboxResponse> {
+        let mut cres = super::sandbox::StopSandboxResponse::new();
+        ::ttrpc::client_request!(self, ctx, req, "containerd.runtime.sandbox.v1.Sandbox", "StopSandbox", cres);
+        Ok(cres)
+    }
+
+    pub fn wait_sandbox(&self, ctx: ttrpc::context::Context, req: &super::sandbox::WaitSandboxRequest) -> ::ttrpc::Result<super::sandbox::WaitSandboxResponse> {
+        let mut cres = super::sandbox::WaitSandboxResponse::new();
+        ::ttrpc::client_request!(self, ctx, req, "containerd.runtime.sandbox.v1.Sandbox", "WaitSandbox", cres);
+        Ok(cres)
+    }
+
+    pub fn sandbox_status(&self, ctx: ttrpc::context::Context, req: &super::sandbox::SandboxStatusRequest) -> ::ttrpc::Result<super::sandbox::SandboxStatusResponse> {
+        let mut cres = super::sandbox::SandboxStatusResponse::new();
+        ::ttrpc::client_request!(self, ctx, req, "containerd.runtime.sandbox.v1.Sandbox", "SandboxStatus", cres);
+        Ok(cres)
+    }
+
+    pub fn ping_sandbox(&self, ctx: ttrpc::context::Context, req: &super::sandbox::PingRequest) -> ::ttrpc::Result<super::sandbox::PingResponse> {
+        let mut cres = super::sandbox::PingResponse::new();
+        ::ttrpc::client_request!(self, ctx, req, "containerd.runtime.sandbox.v1.Sandbox", "PingSandbox", cres);
+        Ok(cres)
+    }
+
+    pub fn shutdown_sandbox(&self, ctx: ttrpc::context::Context, req: &super::sandbox::ShutdownSandboxRequest) -> ::ttrpc::Result<super::sandbox::ShutdownSandboxResponse> {
+        let mut cres = super::sandbox::ShutdownSandboxResponse::new();
+        ::ttrpc::client_request!(self, ctx, req, "containerd.runtime.sandbox.v1.Sandbox", "ShutdownSandbox", cres);
+        Ok(cres)
+    }
+}
+
+struct CreateSandboxMethod {
+    service: Arc<std::boxed::Box<dyn Sandbox + Send + Sync>>,
+}
+
+impl ::ttrpc::MethodHandler for CreateSandboxMethod {
+    fn handler(&self, ctx: ::ttrpc::TtrpcContext, req: ::ttrpc::Request) -> ::ttrpc::Result<()> {
+        ::ttrpc::request_handler!(self, ctx, req, sandbox, CreateSandboxRequest, create_sandbox);
+        Ok(())
+    }
+}
+
+struct StartSandboxMethod {
+    service: Arc<std::boxed::Box<dyn Sandbox + Send + Sync>>,
+}
+
+impl ::ttrpc::MethodHandler for StartSandboxMethod {
+    fn handler(&self, ctx: ::ttrpc::TtrpcContext, req: ::ttrpc::Request) -> ::ttrpc::Result<()> {
+        ::ttrpc::request_handler!(self, ctx, req, sandbox, StartSandboxRequest, start_sandbox);
+        Ok(())
+    }
+}
+
+struct PlatformMethod {
+    service: Arc<std::boxed::Box<dyn Sandbox + Send + Sync>>,
+}
+
+impl ::ttrpc::MethodHandler for PlatformMethod {
+    fn handler(&self, ctx: ::ttrpc::TtrpcContext, req: ::ttrpc::Request) -> ::ttrpc::Result<()> {
+        ::ttrpc::request_handler!(self, ctx, req, sandbox, PlatformRequest, platform);
+        Ok(())
+    }
+}
+
+struct StopSandboxMethod {
+    service: Arc<std::boxed::Box<dyn Sandbox + Send + Sync>>,
+}
+
+impl ::ttrpc::MethodHandler for StopSandboxMethod {
+    fn handler(&self, ctx: ::ttrpc::TtrpcContext, req: ::ttrpc::Request) -> ::ttrpc::Result<()> {
+        ::ttrpc::request_handler!(self, ctx, req, sandbox, StopSandboxRequest, stop_sandbox);
+        Ok(())
+    }
+}
+
+struct WaitSandboxMethod {
+    service: Arc<std::boxed::Box<dyn Sandbox + Send + Sync>>,
+}
+
+impl ::ttrpc::MethodHandler for WaitSandboxMethod {
+    fn handler(&self, ctx: ::ttrpc::TtrpcContext, req: ::ttrpc::Request) -> ::ttrpc::Result<()> {
+        ::ttrpc::request_handler!(self, ctx, req, sandbox, WaitSandboxRequest, wait_sandbox);
+        Ok(())
+    }
+}
+
+struct SandboxStatusMethod {
+    service: Arc<std::boxed::Box<dyn Sandbox + Send + Sync>>,
+}
+
+impl ::ttrpc::MethodHandler for SandboxStatusMethod {
+    fn handler(&self, ctx: ::ttrpc::TtrpcContext, req: ::ttrpc::Request) -> ::ttrpc::Result<()> {
+        ::ttrpc::request_handler!(self, ctx, req, sandbox, SandboxStatusRequest, sandbox_status);
+        Ok(())
+    }
+}
+
+struct PingSandboxMethod {
+    service: Arc<std::boxed::Box<dyn Sandbox + Send + Sync>>,
+}
+
+impl ::ttrpc::MethodHandler for PingSandboxMethod {
+    fn handler(&self, ctx: ::ttrpc::TtrpcContext, req: ::ttrpc::Request) -> ::ttrpc::Result<()> {
+        ::ttrpc::request_handler!(self, ctx, req, sandbox, PingRequest, ping_sandbox);
+        Ok(())
+    }
+}
+
+struct ShutdownSandboxMethod {
+    service: Arc<std::boxed::Box<dyn Sandbox + Send + Sync>>,
+}
+
+impl ::ttrpc::MethodHandler for ShutdownSandboxMethod {
+    fn handler(&self, ctx: ::ttrpc::TtrpcContext, req: ::ttrpc::Request) -> ::ttrpc::Result<()> {
+        ::ttrpc::request_handler!(self, ctx, req, sandbox, ShutdownSandboxRequest, shutdown_sandbox);
+        Ok(())
+    }
+}
+
+pub trait Sandbox {
+    fn create_sandbox(&self, _ctx: &::ttrpc::TtrpcContext, _req: super::sandbox::CreateSandboxRequest) -> ::ttrpc::Result<super::sandbox::CreateSandboxResponse> {
+        Err(::ttrpc::Error::RpcStatus(::ttrpc::get_status(::ttrpc::Code::NOT_FOUND, "/containerd.runtime.sandbox.v1.Sandbox/CreateSandbox is not supported".to_string())))
+    }
+    fn start_sandbox(&self, _ctx: &::ttrpc::TtrpcContext, _req: super::sandbox::StartSandboxRequest) -> ::ttrpc::Result<super::sandbox::StartSandboxResponse> {
+        Err(::ttrpc::Error::RpcStatus(::ttrpc::get_status(::ttrpc::Code::NOT_FOUND, "/containerd.runtime.sandbox.v1.Sandbox/StartSandbox is not supported".to_string())))
+    }
+    fn platform(&self, _ctx: &::ttrpc::TtrpcContext, _req: super::sandbox::PlatformRequest) -> ::ttrpc::Result<super::sandbox::PlatformResponse> {
+        Err(::ttrpc::Error::RpcStatus(::ttrpc::get_status(::ttrpc::Code::NOT_FOUND, "/containerd.runtime.sandbox.v1.Sandbox/Platform is not supported".to_string())))
+    }
+    fn stop_sandbox(&self, _ctx: &::ttrpc::TtrpcContext, _req: super::sandbox::StopSandboxRequest) -> ::ttrpc::Result<super::sandbox::StopSandboxResponse> {
+        Err(::ttrpc::Error::RpcStatus(::ttrpc::get_status(::ttrpc::Code::NOT_FOUND, "/containerd.runtime.sandbox.v1.Sandbox/StopSandbox is not supported".to_string())))
+    }
+    fn wait_sandbox(&self, _ctx: &::ttrpc::TtrpcContext, _req: super::sandbox::WaitSandboxRequest) -> ::ttrpc::Result<super::sandbox::WaitSandboxResponse> {
+        Err(::ttrpc::Error::RpcStatus(::ttrpc::get_status(::ttrpc::Code::NOT_FOUND, "/containerd.runtime.sandbox.v1.Sandbox/WaitSandbox is not supported".to_string())))
+    }
+    fn sandbox_status(&self, _ctx: &::ttrpc::TtrpcContext, _req: super::sandbox::SandboxStatusRequest) -> ::ttrpc::Result<super::sandbox::SandboxStatusResponse> {
+        Err(::ttrpc::Error::RpcStatus(::ttrpc::get_status(::ttrpc::Code::NOT_FOUND, "/containerd.runtime.sandbox.v1.Sandbox/SandboxStatus is not supported".to_string())))
+    }
+    fn ping_sandbox(&self, _ctx: &::ttrpc::TtrpcContext, _req: super::sandbox::PingRequest) -> ::ttrpc::Result<super::sandbox::PingResponse> {
+        Err(::ttrpc::Error::RpcStatus(::ttrpc::get_status(::ttrpc::Code::NOT_FOUND, "/containerd.runtime.sandbox.v1.Sandbox/PingSandbox is not supported".to_string())))
+    }
+    fn shutdown_sandbox(&self, _ctx: &::ttrpc::TtrpcContext, _req: super::sandbox::ShutdownSandboxRequest) -> ::ttrpc::Result<super::sandbox::ShutdownSandboxResponse> {
+        Err(::ttrpc::Error::RpcStatus(::ttrpc::get_status(::ttrpc::Code::NOT_FOUND, "/containerd.runtime.sandbox.v1.Sandbox/ShutdownSandbox is not supported".to_string())))
+    }
+}
+
+pub fn create_sandbox(service: Arc<std::boxed::Box<dyn Sandbox + Send + Sync>>) -> HashMap <String, Box<dyn ::ttrpc::MethodHandler + Send + Sync>> {
+    let mut methods = HashMap::new();
+
+    methods.insert("/containerd.runtime.sandbox.v1.Sandbox/CreateSandbox".to_string(),
+                    std::boxed::Box::new(CreateSandboxMethod{service: service.clone()}) as std::boxed::Box<dyn ::ttrpc::MethodHandler + Send + Sync>);
+
+    methods.insert("/containerd.runtime.sandbox.v1.Sandbox/StartSandbox".to_string(),
+                    std::boxed::Box::new(StartSandboxMethod{service: service.clone()}) as std::boxed::Box<dyn ::ttrpc::MethodHandler + Send + Sync>);
+
+    methods.insert("/containerd.runtime.sandbox.v1.Sandbox/Platform".to_string(),
+                    std::boxed::Box::new(PlatformMethod{service: service.clone()}) as std::boxed::Box<dyn ::ttrpc::MethodHandler + Send + Sync>);
+
+    methods.insert("/containerd.runtime.sandbox.v1.Sandbox/StopSandbox".to_string(),
+                    std::boxed::Box::new(StopSandboxMethod{service: service.clone()}) as std::boxed::Box<dyn ::ttrpc::MethodHandler + Send + Sync>);
+
+    methods.insert("/containerd.runtime.sandbox.v1.Sandbox/WaitSandbox".to_string(),
+                    std::boxed::Box::new(WaitSandboxMethod{service: service.clone()}) as std::boxed::Box<dyn ::ttrpc::MethodHandler + Send + Sync>);
+
+    methods.insert("/containerd.runtime.sandbox.v1.Sandbox/SandboxStatus".to_string(),
+                    std::boxed::Box::new(SandboxStatusMethod{service: service.clone()}) as std::boxed::Box<dyn ::ttrpc::MethodHandler + Send + Sync>);
+
+    methods.insert("/containerd.runtime.sandbox.v1.Sandbox/PingSandbox".to_string(),
+                    std::boxed::Box::new(PingSandboxMethod{service: service.clone()}) as std::boxed::Box<dyn ::ttrpc::MethodHandler + Send + Sync>);
+
+    methods.insert("/containerd.runtime.sandbox.v1.Sandbox/ShutdownSandbox".to_string(),
+                    std::boxed::Box::new(ShutdownSandboxMethod{service: service.clone()}) as std::boxed::Box<dyn ::ttrpc::MethodHandler + Send + Sync>);
+
+    methods
+}
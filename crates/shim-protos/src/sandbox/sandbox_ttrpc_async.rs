@@ -0,0 +1,222 @@
+// This file is generated by ttrpc-compiler 0.5.0. Do not edit
+// @generated
+
+// https://github.com/Manishearth/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clipto_camel_casepy)]
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unsafe_code)]
+#![allow(unused_imports)]
+#![allow(unused_results)]
+use protobuf::{CodedInputStream, CodedOutputStream, Message};
+use std::collections::HashMap;
+use std::sync::Arc;
+use async_trait::async_trait;
+
+#[derive(Clone)]
+pub struct SandboxClient {
+    client: ::ttrpc::r#async::Client,
+}
+
+impl SandboxClient {
+    pub fn new(client: ::ttrpc::r#async::Client) -> Self {
+        SandboxClient {
+            client: client,
+        }
+    }
+
+    pub async fn create_sandbox(&self, ctx: ttrpc::context::Context, req: &super::sandbox::CreateSandboxRequest) -> ::ttrpc::Result<super::sandbox::CreateSandboxResponse> {
+        let mut cres = super::sandbox::CreateSandboxResponse::new();
+        ::ttrpc::async_client_request!(self, ctx, req, "containerd.runtime.sandbox.v1.Sandbox", "CreateSandbox", cres);
+    }
+
+    pub async fn start_sandbox(&self, ctx: ttrpc::context::Context, req: &super::sandbox::StartSandboxRequest) -> ::ttrpc::Result<super::sandbox::StartSandboxResponse> {
+        let mut cres = super::sandbox::StartSandboxResponse::new();
+        ::ttrpc::async_client_request!(self, ctx, req, "containerd.runtime.sandbox.v1.Sandbox", "StartSandbox", cres);
+    }
+
+    pub async fn platform(&self, ctx: ttrpc::context::Context, req: &super::sandbox::PlatformRequest) -> ::ttrpc::Result<super::sandbox::PlatformResponse> {
+        let mut cres = super::sandbox::PlatformResponse::new();
+        ::ttrpc::async_client_request!(self, ctx, req, "containerd.runtime.sandbox.v1.Sandbox", "Platform", cres);
+    }
+
+    pub async fn stop_sandbox(&self, ctx: ttrpc::context::Context, req: &super::sandbox::StopSandboxRequest) -> ::ttrpc::Result<super::sandbox::StopSandboxResponse> {
+        let mut cres = super::sandbox::StopSandboxResponse::new();
+        ::ttrpc::async_client_request!(self, ctx, req, "containerd.runtime.sandbox.v1.Sandbox", "StopSandbox", cres);
+    }
+
+    pub async fn wait_sandbox(&self, ctx: ttrpc::context::Context, req: &super::sandbox::WaitSandboxRequest) -> ::ttrpc::Result<super::sandbox::WaitSandboxResponse> {
+        let mut cres = super::sandbox::WaitSandboxResponse::new();
+        ::ttrpc::async_client_request!(self, ctx, req, "containerd.runtime.sandbox.v1.Sandbox", "WaitSandbox", cres);
+    }
+
+    pub async fn sandbox_status(&self, ctx: ttrpc::context::Context, req: &super::sandbox::SandboxStatusRequest) -> ::ttrpc::Result<super::sandbox::SandboxStatusResponse> {
+        let mut cres = super::sandbox::SandboxStatusResponse::new();
+        ::ttrpc::async_client_request!(self, ctx, req, "containerd.runtime.sandbox.v1.Sandbox", "SandboxStatus", cres);
+    }
+
+    pub async fn ping_sandbox(&self, ctx: ttrpc::context::Context, req: &super::sandbox::PingRequest) -> ::ttrpc::Result<super::sandbox::PingResponse> {
+        let mut cres = super::sandbox::PingResponse::new();
+        ::ttrpc::async_client_request!(self, ctx, req, "containerd.runtime.sandbox.v1.Sandbox", "PingSandbox", cres);
+    }
+
+    pub async fn shutdown_sandbox(&self, ctx: ttrpc::context::Context, req: &super::sandbox::ShutdownSandboxRequest) -> ::ttrpc::Result<super::sandbox::ShutdownSandboxResponse> {
+        let mut cres = super::sandbox::ShutdownSandboxResponse::new();
+        ::ttrpc::async_client_request!(self, ctx, req, "containerd.runtime.sandbox.v1.Sandbox", "ShutdownSandbox", cres);
+    }
+}
+
+struct CreateSandboxMethod {
+    service: Arc<std::boxed::Box<dyn Sandbox + Send + Sync>>,
+}
+
+#[async_trait]
+impl ::ttrpc::r#async::MethodHandler for CreateSandboxMethod {
+    async fn handler(&self, ctx: ::ttrpc::r#async::TtrpcContext, req: ::ttrpc::Request) -> ::ttrpc::Result<(u32, Vec<u8>)> {
+        ::ttrpc::async_request_handler!(self, ctx, req, sandbox, CreateSandboxRequest, create_sandbox);
+    }
+}
+
+struct StartSandboxMethod {
+    service: Arc<std::boxed::Box<dyn Sandbox + Send + Sync>>,
+}
+
+#[async_trait]
+impl ::ttrpc::r#async::MethodHandler for StartSandboxMethod {
+    async fn handler(&self, ctx: ::ttrpc::r#async::TtrpcContext, req: ::ttrpc::Request) -> ::ttrpc::Result<(u32, Vec<u8>)> {
+        ::ttrpc::async_request_handler!(self, ctx, req, sandbox, StartSandboxRequest, start_sandbox);
+    }
+}
+
+struct PlatformMethod {
+    service: Arc<std::boxed::Box<dyn Sandbox + Send + Sync>>,
+}
+
+#[async_trait]
+impl ::ttrpc::r#async::MethodHandler for PlatformMethod {
+    async fn handler(&self, ctx: ::ttrpc::r#async::TtrpcContext, req: ::ttrpc::Request) -> ::ttrpc::Result<(u32, Vec<u8>)> {
+        ::ttrpc::async_request_handler!(self, ctx, req, sandbox, PlatformRequest, platform);
+    }
+}
+
+struct StopSandboxMethod {
+    service: Arc<std::boxed::Box<dyn Sandbox + Send + Sync>>,
+}
+
+#[async_trait]
+impl ::ttrpc::r#async::MethodHandler for StopSandboxMethod {
+    async fn handler(&self, ctx: ::ttrpc::r#async::TtrpcContext, req: ::ttrpc::Request) -> ::ttrpc::Result<(u32, Vec<u8>)> {
+        ::ttrpc::async_request_handler!(self, ctx, req, sandbox, StopSandboxRequest, stop_sandbox);
+    }
+}
+
+struct WaitSandboxMethod {
+    service: Arc<std::boxed::Box<dyn Sandbox + Send + Sync>>,
+}
+
+#[async_trait]
+impl ::ttrpc::r#async::MethodHandler for WaitSandboxMethod {
+    async fn handler(&self, ctx: ::ttrpc::r#async::TtrpcContext, req: ::ttrpc::Request) -> ::ttrpc::Result<(u32, Vec<u8>)> {
+        ::ttrpc::async_request_handler!(self, ctx, req, sandbox, WaitSandboxRequest, wait_sandbox);
+    }
+}
+
+struct SandboxStatusMethod {
+    service: Arc<std::boxed::Box<dyn Sandbox + Send + Sync>>,
+}
+
+#[async_trait]
+impl ::ttrpc::r#async::MethodHandler for SandboxStatusMethod {
+    async fn handler(&self, ctx: ::ttrpc::r#async::TtrpcContext, req: ::ttrpc::Request) -> ::ttrpc::Result<(u32, Vec<u8>)> {
+        ::ttrpc::async_request_handler!(self, ctx, req, sandbox, SandboxStatusRequest, sandbox_status);
+    }
+}
+
+struct PingSandboxMethod {
+    service: Arc<std::boxed::Box<dyn Sandbox + Send + Sync>>,
+}
+
+#[async_trait]
+impl ::ttrpc::r#async::MethodHandler for PingSandboxMethod {
+    async fn handler(&self, ctx: ::ttrpc::r#async::TtrpcContext, req: ::ttrpc::Request) -> ::ttrpc::Result<(u32, Vec<u8>)> {
+        ::ttrpc::async_request_handler!(self, ctx, req, sandbox, PingRequest, ping_sandbox);
+    }
+}
+
+struct ShutdownSandboxMethod {
+    service: Arc<std::boxed::Box<dyn Sandbox + Send + Sync>>,
+}
+
+#[async_trait]
+impl ::ttrpc::r#async::MethodHandler for ShutdownSandboxMethod {
+    async fn handler(&self, ctx: ::ttrpc::r#async::TtrpcContext, req: ::ttrpc::Request) -> ::ttrpc::Result<(u32, Vec<u8>)> {
+        ::ttrpc::async_request_handler!(self, ctx, req, sandbox, ShutdownSandboxRequest, shutdown_sandbox);
+    }
+}
+
+#[async_trait]
+pub trait Sandbox: Sync {
+    async fn create_sandbox(&self, _ctx: &::ttrpc::r#async::TtrpcContext, _req: super::sandbox::CreateSandboxRequest) -> ::ttrpc::Result<super::sandbox::CreateSandboxResponse> {
+        Err(::ttrpc::Error::RpcStatus(::ttrpc::get_status(::ttrpc::Code::NOT_FOUND, "/containerd.runtime.sandbox.v1.Sandbox/CreateSandbox is not supported".to_string())))
+    }
+    async fn start_sandbox(&self, _ctx: &::ttrpc::r#async::TtrpcContext, _req: super::sandbox::StartSandboxRequest) -> ::ttrpc::Result<super::sandbox::StartSandboxResponse> {
+        Err(::ttrpc::Error::RpcStatus(::ttrpc::get_status(::ttrpc::Code::NOT_FOUND, "/containerd.runtime.sandbox.v1.Sandbox/StartSandbox is not supported".to_string())))
+    }
+    async fn platform(&self, _ctx: &::ttrpc::r#async::TtrpcContext, _req: super::sandbox::PlatformRequest) -> ::ttrpc::Result<super::sandbox::PlatformResponse> {
+        Err(::ttrpc::Error::RpcStatus(::ttrpc::get_status(::ttrpc::Code::NOT_FOUND, "/containerd.runtime.sandbox.v1.Sandbox/Platform is not supported".to_string())))
+    }
+    async fn stop_sandbox(&self, _ctx: &::ttrpc::r#async::TtrpcContext, _req: super::sandbox::StopSandboxRequest) -> ::ttrpc::Result<super::sandbox::StopSandboxResponse> {
+        Err(::ttrpc::Error::RpcStatus(::ttrpc::get_status(::ttrpc::Code::NOT_FOUND, "/containerd.runtime.sandbox.v1.Sandbox/StopSandbox is not supported".to_string())))
+    }
+    async fn wait_sandbox(&self, _ctx: &::ttrpc::r#async::TtrpcContext, _req: super::sandbox::WaitSandboxRequest) -> ::ttrpc::Result<super::sandbox::WaitSandboxResponse> {
+        Err(::ttrpc::Error::RpcStatus(::ttrpc::get_status(::ttrpc::Code::NOT_FOUND, "/containerd.runtime.sandbox.v1.Sandbox/WaitSandbox is not supported".to_string())))
+    }
+    async fn sandbox_status(&self, _ctx: &::ttrpc::r#async::TtrpcContext, _req: super::sandbox::SandboxStatusRequest) -> ::ttrpc::Result<super::sandbox::SandboxStatusResponse> {
+        Err(::ttrpc::Error::RpcStatus(::ttrpc::get_status(::ttrpc::Code::NOT_FOUND, "/containerd.runtime.sandbox.v1.Sandbox/SandboxStatus is not supported".to_string())))
+    }
+    async fn ping_sandbox(&self, _ctx: &::ttrpc::r#async::TtrpcContext, _req: super::sandbox::PingRequest) -> ::ttrpc::Result<super::sandbox::PingResponse> {
+        Err(::ttrpc::Error::RpcStatus(::ttrpc::get_status(::ttrpc::Code::NOT_FOUND, "/containerd.runtime.sandbox.v1.Sandbox/PingSandbox is not supported".to_string())))
+    }
+    async fn shutdown_sandbox(&self, _ctx: &::ttrpc::r#async::TtrpcContext, _req: super::sandbox::ShutdownSandboxRequest) -> ::ttrpc::Result<super::sandbox::ShutdownSandboxResponse> {
+        Err(::ttrpc::Error::RpcStatus(::ttrpc::get_status(::ttrpc::Code::NOT_FOUND, "/containerd.runtime.sandbox.v1.Sandbox/ShutdownSandbox is not supported".to_string())))
+    }
+}
+
+pub fn create_sandbox(service: Arc<std::boxed::Box<dyn Sandbox + Send + Sync>>) -> HashMap <String, Box<dyn ::ttrpc::r#async::MethodHandler + Send + Sync>> {
+    let mut methods = HashMap::new();
+
+    methods.insert("/containerd.runtime.sandbox.v1.Sandbox/CreateSandbox".to_string(),
+                    std::boxed::Box::new(CreateSandboxMethod{service: service.clone()}) as std::boxed::Box<dyn ::ttrpc::r#async::MethodHandler + Send + Sync>);
+
+    methods.insert("/containerd.runtime.sandbox.v1.Sandbox/StartSandbox".to_string(),
+                    std::boxed::Box::new(StartSandboxMethod{service: service.clone()}) as std::boxed::Box<dyn ::ttrpc::r#async::MethodHandler + Send + Sync>);
+
+    methods.insert("/containerd.runtime.sandbox.v1.Sandbox/Platform".to_string(),
+                    std::boxed::Box::new(PlatformMethod{service: service.clone()}) as std::boxed::Box<dyn ::ttrpc::r#async::MethodHandler + Send + Sync>);
+
+    methods.insert("/containerd.runtime.sandbox.v1.Sandbox/StopSandbox".to_string(),
+                    std::boxed::Box::new(StopSandboxMethod{service: service.clone()}) as std::boxed::Box<dyn ::ttrpc::r#async::MethodHandler + Send + Sync>);
+
+    methods.insert("/containerd.runtime.sandbox.v1.Sandbox/WaitSandbox".to_string(),
+                    std::boxed::Box::new(WaitSandboxMethod{service: service.clone()}) as std::boxed::Box<dyn ::ttrpc::r#async::MethodHandler + Send + Sync>);
+
+    methods.insert("/containerd.runtime.sandbox.v1.Sandbox/SandboxStatus".to_string(),
+                    std::boxed::Box::new(SandboxStatusMethod{service: service.clone()}) as std::boxed::Box<dyn ::ttrpc::r#async::MethodHandler + Send + Sync>);
+
+    methods.insert("/containerd.runtime.sandbox.v1.Sandbox/PingSandbox".to_string(),
+                    std::boxed::Box::new(PingSandboxMethod{service: service.clone()}) as std::boxed::Box<dyn ::ttrpc::r#async::MethodHandler + Send + Sync>);
+
+    methods.insert("/containerd.runtime.sandbox.v1.Sandbox/ShutdownSandbox".to_string(),
+                    std::boxed::Box::new(ShutdownSandboxMethod{service: service.clone()}) as std::boxed::Box<dyn ::ttrpc::r#async::MethodHandler + Send + Sync>);
+
+    methods
+}
@@ -24,11 +24,14 @@
 // const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_2_27_1;
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct ForwardRequest {
     // message fields
     pub envelope: ::protobuf::SingularPtrField<Envelope>,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub cached_size: ::protobuf::CachedSize,
 }
 
@@ -198,14 +201,19 @@ impl ::protobuf::reflect::ProtobufValue for ForwardRequest {
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct Envelope {
     // message fields
+    #[cfg_attr(feature = "with-serde", serde(default, with = "crate::json::timestamp"))]
     pub timestamp: ::protobuf::SingularPtrField<::protobuf::well_known_types::Timestamp>,
     pub namespace: ::std::string::String,
     pub topic: ::std::string::String,
+    #[cfg_attr(feature = "with-serde", serde(default, with = "crate::json::any"))]
     pub event: ::protobuf::SingularPtrField<::protobuf::well_known_types::Any>,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub cached_size: ::protobuf::CachedSize,
 }
 
@@ -24,6 +24,7 @@
 // const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_2_27_1;
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct CreateTaskRequest {
     // message fields
     pub id: ::std::string::String,
@@ -35,9 +36,12 @@ pub struct CreateTaskRequest {
     pub stderr: ::std::string::String,
     pub checkpoint: ::std::string::String,
     pub parent_checkpoint: ::std::string::String,
+    #[cfg_attr(feature = "with-serde", serde(default, with = "crate::json::any"))]
     pub options: ::protobuf::SingularPtrField<::protobuf::well_known_types::Any>,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub cached_size: ::protobuf::CachedSize,
 }
 
@@ -576,11 +580,14 @@ impl ::protobuf::reflect::ProtobufValue for CreateTaskRequest {
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct CreateTaskResponse {
     // message fields
     pub pid: u32,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub cached_size: ::protobuf::CachedSize,
 }
 
@@ -728,12 +735,15 @@ impl ::protobuf::reflect::ProtobufValue for CreateTaskResponse {
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct DeleteRequest {
     // message fields
     pub id: ::std::string::String,
     pub exec_id: ::std::string::String,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub cached_size: ::protobuf::CachedSize,
 }
 
@@ -929,13 +939,17 @@ impl ::protobuf::reflect::ProtobufValue for DeleteRequest {
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct DeleteResponse {
     // message fields
     pub pid: u32,
     pub exit_status: u32,
+    #[cfg_attr(feature = "with-serde", serde(default, with = "crate::json::timestamp"))]
     pub exited_at: ::protobuf::SingularPtrField<::protobuf::well_known_types::Timestamp>,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub cached_size: ::protobuf::CachedSize,
 }
 
@@ -1173,6 +1187,7 @@ impl ::protobuf::reflect::ProtobufValue for DeleteResponse {
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct ExecProcessRequest {
     // message fields
     pub id: ::std::string::String,
@@ -1181,9 +1196,12 @@ pub struct ExecProcessRequest {
     pub stdin: ::std::string::String,
     pub stdout: ::std::string::String,
     pub stderr: ::std::string::String,
+    #[cfg_attr(feature = "with-serde", serde(default, with = "crate::json::any"))]
     pub spec: ::protobuf::SingularPtrField<::protobuf::well_known_types::Any>,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub cached_size: ::protobuf::CachedSize,
 }
 
@@ -1592,9 +1610,12 @@ impl ::protobuf::reflect::ProtobufValue for ExecProcessRequest {
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct ExecProcessResponse {
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub cached_size: ::protobuf::CachedSize,
 }
 
@@ -1708,6 +1729,7 @@ impl ::protobuf::reflect::ProtobufValue for ExecProcessResponse {
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct ResizePtyRequest {
     // message fields
     pub id: ::std::string::String,
@@ -1715,7 +1737,9 @@ pub struct ResizePtyRequest {
     pub width: u32,
     pub height: u32,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub cached_size: ::protobuf::CachedSize,
 }
 
@@ -1979,12 +2003,15 @@ impl ::protobuf::reflect::ProtobufValue for ResizePtyRequest {
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct StateRequest {
     // message fields
     pub id: ::std::string::String,
     pub exec_id: ::std::string::String,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub cached_size: ::protobuf::CachedSize,
 }
 
@@ -2180,6 +2207,7 @@ impl ::protobuf::reflect::ProtobufValue for StateRequest {
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct StateResponse {
     // message fields
     pub id: ::std::string::String,
@@ -2191,10 +2219,13 @@ pub struct StateResponse {
     pub stderr: ::std::string::String,
     pub terminal: bool,
     pub exit_status: u32,
+    #[cfg_attr(feature = "with-serde", serde(default, with = "crate::json::timestamp"))]
     pub exited_at: ::protobuf::SingularPtrField<::protobuf::well_known_types::Timestamp>,
     pub exec_id: ::std::string::String,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub cached_size: ::protobuf::CachedSize,
 }
 
@@ -2742,6 +2773,7 @@ impl ::protobuf::reflect::ProtobufValue for StateResponse {
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct KillRequest {
     // message fields
     pub id: ::std::string::String,
@@ -2749,7 +2781,9 @@ pub struct KillRequest {
     pub signal: u32,
     pub all: bool,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub cached_size: ::protobuf::CachedSize,
 }
 
@@ -3013,13 +3047,16 @@ impl ::protobuf::reflect::ProtobufValue for KillRequest {
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct CloseIORequest {
     // message fields
     pub id: ::std::string::String,
     pub exec_id: ::std::string::String,
     pub stdin: bool,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub cached_size: ::protobuf::CachedSize,
 }
 
@@ -3249,11 +3286,14 @@ impl ::protobuf::reflect::ProtobufValue for CloseIORequest {
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct PidsRequest {
     // message fields
     pub id: ::std::string::String,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub cached_size: ::protobuf::CachedSize,
 }
 
@@ -3408,11 +3448,14 @@ impl ::protobuf::reflect::ProtobufValue for PidsRequest {
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct PidsResponse {
     // message fields
     pub processes: ::protobuf::RepeatedField<super::task::ProcessInfo>,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub cached_size: ::protobuf::CachedSize,
 }
 
@@ -3574,13 +3617,17 @@ impl ::protobuf::reflect::ProtobufValue for PidsResponse {
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct CheckpointTaskRequest {
     // message fields
     pub id: ::std::string::String,
     pub path: ::std::string::String,
+    #[cfg_attr(feature = "with-serde", serde(default, with = "crate::json::any"))]
     pub options: ::protobuf::SingularPtrField<::protobuf::well_known_types::Any>,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub cached_size: ::protobuf::CachedSize,
 }
 
@@ -3832,13 +3879,17 @@ impl ::protobuf::reflect::ProtobufValue for CheckpointTaskRequest {
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct UpdateTaskRequest {
     // message fields
     pub id: ::std::string::String,
+    #[cfg_attr(feature = "with-serde", serde(default, with = "crate::json::any"))]
     pub resources: ::protobuf::SingularPtrField<::protobuf::well_known_types::Any>,
     pub annotations: ::std::collections::HashMap<::std::string::String, ::std::string::String>,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub cached_size: ::protobuf::CachedSize,
 }
 
@@ -4085,12 +4136,15 @@ impl ::protobuf::reflect::ProtobufValue for UpdateTaskRequest {
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct StartRequest {
     // message fields
     pub id: ::std::string::String,
     pub exec_id: ::std::string::String,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub cached_size: ::protobuf::CachedSize,
 }
 
@@ -4286,11 +4340,14 @@ impl ::protobuf::reflect::ProtobufValue for StartRequest {
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct StartResponse {
     // message fields
     pub pid: u32,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub cached_size: ::protobuf::CachedSize,
 }
 
@@ -4438,12 +4495,15 @@ impl ::protobuf::reflect::ProtobufValue for StartResponse {
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct WaitRequest {
     // message fields
     pub id: ::std::string::String,
     pub exec_id: ::std::string::String,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub cached_size: ::protobuf::CachedSize,
 }
 
@@ -4639,12 +4699,16 @@ impl ::protobuf::reflect::ProtobufValue for WaitRequest {
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct WaitResponse {
     // message fields
     pub exit_status: u32,
+    #[cfg_attr(feature = "with-serde", serde(default, with = "crate::json::timestamp"))]
     pub exited_at: ::protobuf::SingularPtrField<::protobuf::well_known_types::Timestamp>,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub cached_size: ::protobuf::CachedSize,
 }
 
@@ -4848,11 +4912,14 @@ impl ::protobuf::reflect::ProtobufValue for WaitResponse {
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct StatsRequest {
     // message fields
     pub id: ::std::string::String,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub cached_size: ::protobuf::CachedSize,
 }
 
@@ -5007,11 +5074,15 @@ impl ::protobuf::reflect::ProtobufValue for StatsRequest {
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct StatsResponse {
     // message fields
+    #[cfg_attr(feature = "with-serde", serde(default, with = "crate::json::any"))]
     pub stats: ::protobuf::SingularPtrField<::protobuf::well_known_types::Any>,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub cached_size: ::protobuf::CachedSize,
 }
 
@@ -5181,11 +5252,14 @@ impl ::protobuf::reflect::ProtobufValue for StatsResponse {
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct ConnectRequest {
     // message fields
     pub id: ::std::string::String,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub cached_size: ::protobuf::CachedSize,
 }
 
@@ -5340,13 +5414,16 @@ impl ::protobuf::reflect::ProtobufValue for ConnectRequest {
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct ConnectResponse {
     // message fields
     pub shim_pid: u32,
     pub task_pid: u32,
     pub version: ::std::string::String,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub cached_size: ::protobuf::CachedSize,
 }
 
@@ -5569,12 +5646,15 @@ impl ::protobuf::reflect::ProtobufValue for ConnectResponse {
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct ShutdownRequest {
     // message fields
     pub id: ::std::string::String,
     pub now: bool,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub cached_size: ::protobuf::CachedSize,
 }
 
@@ -5763,11 +5843,14 @@ impl ::protobuf::reflect::ProtobufValue for ShutdownRequest {
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct PauseRequest {
     // message fields
     pub id: ::std::string::String,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub cached_size: ::protobuf::CachedSize,
 }
 
@@ -5922,11 +6005,14 @@ impl ::protobuf::reflect::ProtobufValue for PauseRequest {
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct ResumeRequest {
     // message fields
     pub id: ::std::string::String,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub cached_size: ::protobuf::CachedSize,
 }
 
@@ -0,0 +1,8 @@
+pub mod shim;
+pub mod shim_ttrpc;
+#[cfg(feature = "async")]
+pub mod shim_ttrpc_async;
+
+pub(crate) mod empty;
+pub(crate) mod mount;
+pub(crate) mod task;
@@ -0,0 +1,6258 @@
+// This file is generated by rust-protobuf 2.27.1. Do not edit
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_imports)]
+#![allow(unused_results)]
+//! Generated file from `github.com/containerd/containerd/api/runtime/task/v3/shim.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+// const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_2_27_1;
+
+#[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
+pub struct CreateTaskRequest {
+    // message fields
+    pub id: ::std::string::String,
+    pub bundle: ::std::string::String,
+    pub rootfs: ::protobuf::RepeatedField<super::mount::Mount>,
+    pub terminal: bool,
+    pub stdin: ::std::string::String,
+    pub stdout: ::std::string::String,
+    pub stderr: ::std::string::String,
+    pub checkpoint: ::std::string::String,
+    pub parent_checkpoint: ::std::string::String,
+    #[cfg_attr(feature = "with-serde", serde(default, with = "crate::json::any"))]
+    pub options: ::protobuf::SingularPtrField<::protobuf::well_known_types::Any>,
+    // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
+    pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a CreateTaskRequest {
+    fn default() -> &'a CreateTaskRequest {
+        <CreateTaskRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl CreateTaskRequest {
+    pub fn new() -> CreateTaskRequest {
+        ::std::default::Default::default()
+    }
+
+    // string id = 1;
+
+
+    pub fn get_id(&self) -> &str {
+        &self.id
+    }
+    pub fn clear_id(&mut self) {
+        self.id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_id(&mut self, v: ::std::string::String) {
+        self.id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_id(&mut self) -> &mut ::std::string::String {
+        &mut self.id
+    }
+
+    // Take field
+    pub fn take_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.id, ::std::string::String::new())
+    }
+
+    // string bundle = 2;
+
+
+    pub fn get_bundle(&self) -> &str {
+        &self.bundle
+    }
+    pub fn clear_bundle(&mut self) {
+        self.bundle.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_bundle(&mut self, v: ::std::string::String) {
+        self.bundle = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_bundle(&mut self) -> &mut ::std::string::String {
+        &mut self.bundle
+    }
+
+    // Take field
+    pub fn take_bundle(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.bundle, ::std::string::String::new())
+    }
+
+    // repeated .containerd.types.Mount rootfs = 3;
+
+
+    pub fn get_rootfs(&self) -> &[super::mount::Mount] {
+        &self.rootfs
+    }
+    pub fn clear_rootfs(&mut self) {
+        self.rootfs.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_rootfs(&mut self, v: ::protobuf::RepeatedField<super::mount::Mount>) {
+        self.rootfs = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_rootfs(&mut self) -> &mut ::protobuf::RepeatedField<super::mount::Mount> {
+        &mut self.rootfs
+    }
+
+    // Take field
+    pub fn take_rootfs(&mut self) -> ::protobuf::RepeatedField<super::mount::Mount> {
+        ::std::mem::replace(&mut self.rootfs, ::protobuf::RepeatedField::new())
+    }
+
+    // bool terminal = 4;
+
+
+    pub fn get_terminal(&self) -> bool {
+        self.terminal
+    }
+    pub fn clear_terminal(&mut self) {
+        self.terminal = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_terminal(&mut self, v: bool) {
+        self.terminal = v;
+    }
+
+    // string stdin = 5;
+
+
+    pub fn get_stdin(&self) -> &str {
+        &self.stdin
+    }
+    pub fn clear_stdin(&mut self) {
+        self.stdin.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_stdin(&mut self, v: ::std::string::String) {
+        self.stdin = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_stdin(&mut self) -> &mut ::std::string::String {
+        &mut self.stdin
+    }
+
+    // Take field
+    pub fn take_stdin(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.stdin, ::std::string::String::new())
+    }
+
+    // string stdout = 6;
+
+
+    pub fn get_stdout(&self) -> &str {
+        &self.stdout
+    }
+    pub fn clear_stdout(&mut self) {
+        self.stdout.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_stdout(&mut self, v: ::std::string::String) {
+        self.stdout = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_stdout(&mut self) -> &mut ::std::string::String {
+        &mut self.stdout
+    }
+
+    // Take field
+    pub fn take_stdout(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.stdout, ::std::string::String::new())
+    }
+
+    // string stderr = 7;
+
+
+    pub fn get_stderr(&self) -> &str {
+        &self.stderr
+    }
+    pub fn clear_stderr(&mut self) {
+        self.stderr.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_stderr(&mut self, v: ::std::string::String) {
+        self.stderr = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_stderr(&mut self) -> &mut ::std::string::String {
+        &mut self.stderr
+    }
+
+    // Take field
+    pub fn take_stderr(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.stderr, ::std::string::String::new())
+    }
+
+    // string checkpoint = 8;
+
+
+    pub fn get_checkpoint(&self) -> &str {
+        &self.checkpoint
+    }
+    pub fn clear_checkpoint(&mut self) {
+        self.checkpoint.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_checkpoint(&mut self, v: ::std::string::String) {
+        self.checkpoint = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_checkpoint(&mut self) -> &mut ::std::string::String {
+        &mut self.checkpoint
+    }
+
+    // Take field
+    pub fn take_checkpoint(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.checkpoint, ::std::string::String::new())
+    }
+
+    // string parent_checkpoint = 9;
+
+
+    pub fn get_parent_checkpoint(&self) -> &str {
+        &self.parent_checkpoint
+    }
+    pub fn clear_parent_checkpoint(&mut self) {
+        self.parent_checkpoint.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_parent_checkpoint(&mut self, v: ::std::string::String) {
+        self.parent_checkpoint = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_parent_checkpoint(&mut self) -> &mut ::std::string::String {
+        &mut self.parent_checkpoint
+    }
+
+    // Take field
+    pub fn take_parent_checkpoint(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.parent_checkpoint, ::std::string::String::new())
+    }
+
+    // .google.protobuf.Any options = 10;
+
+
+    pub fn get_options(&self) -> &::protobuf::well_known_types::Any {
+        self.options.as_ref().unwrap_or_else(|| <::protobuf::well_known_types::Any as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_options(&mut self) {
+        self.options.clear();
+    }
+
+    pub fn has_options(&self) -> bool {
+        self.options.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_options(&mut self, v: ::protobuf::well_known_types::Any) {
+        self.options = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_options(&mut self) -> &mut ::protobuf::well_known_types::Any {
+        if self.options.is_none() {
+            self.options.set_default();
+        }
+        self.options.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_options(&mut self) -> ::protobuf::well_known_types::Any {
+        self.options.take().unwrap_or_else(|| ::protobuf::well_known_types::Any::new())
+    }
+}
+
+impl ::protobuf::Message for CreateTaskRequest {
+    fn is_initialized(&self) -> bool {
+        for v in &self.rootfs {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.options {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.bundle)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.rootfs)?;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.terminal = tmp;
+                },
+                5 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.stdin)?;
+                },
+                6 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.stdout)?;
+                },
+                7 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.stderr)?;
+                },
+                8 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.checkpoint)?;
+                },
+                9 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.parent_checkpoint)?;
+                },
+                10 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.options)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.id);
+        }
+        if !self.bundle.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.bundle);
+        }
+        for value in &self.rootfs {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        if self.terminal != false {
+            my_size += 2;
+        }
+        if !self.stdin.is_empty() {
+            my_size += ::protobuf::rt::string_size(5, &self.stdin);
+        }
+        if !self.stdout.is_empty() {
+            my_size += ::protobuf::rt::string_size(6, &self.stdout);
+        }
+        if !self.stderr.is_empty() {
+            my_size += ::protobuf::rt::string_size(7, &self.stderr);
+        }
+        if !self.checkpoint.is_empty() {
+            my_size += ::protobuf::rt::string_size(8, &self.checkpoint);
+        }
+        if !self.parent_checkpoint.is_empty() {
+            my_size += ::protobuf::rt::string_size(9, &self.parent_checkpoint);
+        }
+        if let Some(ref v) = self.options.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.id.is_empty() {
+            os.write_string(1, &self.id)?;
+        }
+        if !self.bundle.is_empty() {
+            os.write_string(2, &self.bundle)?;
+        }
+        for v in &self.rootfs {
+            os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        if self.terminal != false {
+            os.write_bool(4, self.terminal)?;
+        }
+        if !self.stdin.is_empty() {
+            os.write_string(5, &self.stdin)?;
+        }
+        if !self.stdout.is_empty() {
+            os.write_string(6, &self.stdout)?;
+        }
+        if !self.stderr.is_empty() {
+            os.write_string(7, &self.stderr)?;
+        }
+        if !self.checkpoint.is_empty() {
+            os.write_string(8, &self.checkpoint)?;
+        }
+        if !self.parent_checkpoint.is_empty() {
+            os.write_string(9, &self.parent_checkpoint)?;
+        }
+        if let Some(ref v) = self.options.as_ref() {
+            os.write_tag(10, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> CreateTaskRequest {
+        CreateTaskRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "id",
+                |m: &CreateTaskRequest| { &m.id },
+                |m: &mut CreateTaskRequest| { &mut m.id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "bundle",
+                |m: &CreateTaskRequest| { &m.bundle },
+                |m: &mut CreateTaskRequest| { &mut m.bundle },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::mount::Mount>>(
+                "rootfs",
+                |m: &CreateTaskRequest| { &m.rootfs },
+                |m: &mut CreateTaskRequest| { &mut m.rootfs },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "terminal",
+                |m: &CreateTaskRequest| { &m.terminal },
+                |m: &mut CreateTaskRequest| { &mut m.terminal },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "stdin",
+                |m: &CreateTaskRequest| { &m.stdin },
+                |m: &mut CreateTaskRequest| { &mut m.stdin },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "stdout",
+                |m: &CreateTaskRequest| { &m.stdout },
+                |m: &mut CreateTaskRequest| { &mut m.stdout },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "stderr",
+                |m: &CreateTaskRequest| { &m.stderr },
+                |m: &mut CreateTaskRequest| { &mut m.stderr },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "checkpoint",
+                |m: &CreateTaskRequest| { &m.checkpoint },
+                |m: &mut CreateTaskRequest| { &mut m.checkpoint },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "parent_checkpoint",
+                |m: &CreateTaskRequest| { &m.parent_checkpoint },
+                |m: &mut CreateTaskRequest| { &mut m.parent_checkpoint },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<::protobuf::well_known_types::Any>>(
+                "options",
+                |m: &CreateTaskRequest| { &m.options },
+                |m: &mut CreateTaskRequest| { &mut m.options },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<CreateTaskRequest>(
+                "CreateTaskRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static CreateTaskRequest {
+        static instance: ::protobuf::rt::LazyV2<CreateTaskRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(CreateTaskRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for CreateTaskRequest {
+    fn clear(&mut self) {
+        self.id.clear();
+        self.bundle.clear();
+        self.rootfs.clear();
+        self.terminal = false;
+        self.stdin.clear();
+        self.stdout.clear();
+        self.stderr.clear();
+        self.checkpoint.clear();
+        self.parent_checkpoint.clear();
+        self.options.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for CreateTaskRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CreateTaskRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
+pub struct CreateTaskResponse {
+    // message fields
+    pub pid: u32,
+    // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
+    pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a CreateTaskResponse {
+    fn default() -> &'a CreateTaskResponse {
+        <CreateTaskResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl CreateTaskResponse {
+    pub fn new() -> CreateTaskResponse {
+        ::std::default::Default::default()
+    }
+
+    // uint32 pid = 1;
+
+
+    pub fn get_pid(&self) -> u32 {
+        self.pid
+    }
+    pub fn clear_pid(&mut self) {
+        self.pid = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_pid(&mut self, v: u32) {
+        self.pid = v;
+    }
+}
+
+impl ::protobuf::Message for CreateTaskResponse {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.pid = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.pid != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.pid, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.pid != 0 {
+            os.write_uint32(1, self.pid)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> CreateTaskResponse {
+        CreateTaskResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "pid",
+                |m: &CreateTaskResponse| { &m.pid },
+                |m: &mut CreateTaskResponse| { &mut m.pid },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<CreateTaskResponse>(
+                "CreateTaskResponse",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static CreateTaskResponse {
+        static instance: ::protobuf::rt::LazyV2<CreateTaskResponse> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(CreateTaskResponse::new)
+    }
+}
+
+impl ::protobuf::Clear for CreateTaskResponse {
+    fn clear(&mut self) {
+        self.pid = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for CreateTaskResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CreateTaskResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
+pub struct DeleteRequest {
+    // message fields
+    pub id: ::std::string::String,
+    pub exec_id: ::std::string::String,
+    // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
+    pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a DeleteRequest {
+    fn default() -> &'a DeleteRequest {
+        <DeleteRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl DeleteRequest {
+    pub fn new() -> DeleteRequest {
+        ::std::default::Default::default()
+    }
+
+    // string id = 1;
+
+
+    pub fn get_id(&self) -> &str {
+        &self.id
+    }
+    pub fn clear_id(&mut self) {
+        self.id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_id(&mut self, v: ::std::string::String) {
+        self.id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_id(&mut self) -> &mut ::std::string::String {
+        &mut self.id
+    }
+
+    // Take field
+    pub fn take_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.id, ::std::string::String::new())
+    }
+
+    // string exec_id = 2;
+
+
+    pub fn get_exec_id(&self) -> &str {
+        &self.exec_id
+    }
+    pub fn clear_exec_id(&mut self) {
+        self.exec_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_exec_id(&mut self, v: ::std::string::String) {
+        self.exec_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_exec_id(&mut self) -> &mut ::std::string::String {
+        &mut self.exec_id
+    }
+
+    // Take field
+    pub fn take_exec_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.exec_id, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for DeleteRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.exec_id)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.id);
+        }
+        if !self.exec_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.exec_id);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.id.is_empty() {
+            os.write_string(1, &self.id)?;
+        }
+        if !self.exec_id.is_empty() {
+            os.write_string(2, &self.exec_id)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> DeleteRequest {
+        DeleteRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "id",
+                |m: &DeleteRequest| { &m.id },
+                |m: &mut DeleteRequest| { &mut m.id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "exec_id",
+                |m: &DeleteRequest| { &m.exec_id },
+                |m: &mut DeleteRequest| { &mut m.exec_id },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<DeleteRequest>(
+                "DeleteRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static DeleteRequest {
+        static instance: ::protobuf::rt::LazyV2<DeleteRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(DeleteRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for DeleteRequest {
+    fn clear(&mut self) {
+        self.id.clear();
+        self.exec_id.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for DeleteRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for DeleteRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
+pub struct DeleteResponse {
+    // message fields
+    pub pid: u32,
+    pub exit_status: u32,
+    #[cfg_attr(feature = "with-serde", serde(default, with = "crate::json::timestamp"))]
+    pub exited_at: ::protobuf::SingularPtrField<::protobuf::well_known_types::Timestamp>,
+    // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
+    pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a DeleteResponse {
+    fn default() -> &'a DeleteResponse {
+        <DeleteResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl DeleteResponse {
+    pub fn new() -> DeleteResponse {
+        ::std::default::Default::default()
+    }
+
+    // uint32 pid = 1;
+
+
+    pub fn get_pid(&self) -> u32 {
+        self.pid
+    }
+    pub fn clear_pid(&mut self) {
+        self.pid = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_pid(&mut self, v: u32) {
+        self.pid = v;
+    }
+
+    // uint32 exit_status = 2;
+
+
+    pub fn get_exit_status(&self) -> u32 {
+        self.exit_status
+    }
+    pub fn clear_exit_status(&mut self) {
+        self.exit_status = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_exit_status(&mut self, v: u32) {
+        self.exit_status = v;
+    }
+
+    // .google.protobuf.Timestamp exited_at = 3;
+
+
+    pub fn get_exited_at(&self) -> &::protobuf::well_known_types::Timestamp {
+        self.exited_at.as_ref().unwrap_or_else(|| <::protobuf::well_known_types::Timestamp as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_exited_at(&mut self) {
+        self.exited_at.clear();
+    }
+
+    pub fn has_exited_at(&self) -> bool {
+        self.exited_at.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_exited_at(&mut self, v: ::protobuf::well_known_types::Timestamp) {
+        self.exited_at = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_exited_at(&mut self) -> &mut ::protobuf::well_known_types::Timestamp {
+        if self.exited_at.is_none() {
+            self.exited_at.set_default();
+        }
+        self.exited_at.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_exited_at(&mut self) -> ::protobuf::well_known_types::Timestamp {
+        self.exited_at.take().unwrap_or_else(|| ::protobuf::well_known_types::Timestamp::new())
+    }
+}
+
+impl ::protobuf::Message for DeleteResponse {
+    fn is_initialized(&self) -> bool {
+        for v in &self.exited_at {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.pid = tmp;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.exit_status = tmp;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.exited_at)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.pid != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.pid, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.exit_status != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.exit_status, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(ref v) = self.exited_at.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.pid != 0 {
+            os.write_uint32(1, self.pid)?;
+        }
+        if self.exit_status != 0 {
+            os.write_uint32(2, self.exit_status)?;
+        }
+        if let Some(ref v) = self.exited_at.as_ref() {
+            os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> DeleteResponse {
+        DeleteResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "pid",
+                |m: &DeleteResponse| { &m.pid },
+                |m: &mut DeleteResponse| { &mut m.pid },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "exit_status",
+                |m: &DeleteResponse| { &m.exit_status },
+                |m: &mut DeleteResponse| { &mut m.exit_status },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<::protobuf::well_known_types::Timestamp>>(
+                "exited_at",
+                |m: &DeleteResponse| { &m.exited_at },
+                |m: &mut DeleteResponse| { &mut m.exited_at },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<DeleteResponse>(
+                "DeleteResponse",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static DeleteResponse {
+        static instance: ::protobuf::rt::LazyV2<DeleteResponse> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(DeleteResponse::new)
+    }
+}
+
+impl ::protobuf::Clear for DeleteResponse {
+    fn clear(&mut self) {
+        self.pid = 0;
+        self.exit_status = 0;
+        self.exited_at.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for DeleteResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for DeleteResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
+pub struct ExecProcessRequest {
+    // message fields
+    pub id: ::std::string::String,
+    pub exec_id: ::std::string::String,
+    pub terminal: bool,
+    pub stdin: ::std::string::String,
+    pub stdout: ::std::string::String,
+    pub stderr: ::std::string::String,
+    #[cfg_attr(feature = "with-serde", serde(default, with = "crate::json::any"))]
+    pub spec: ::protobuf::SingularPtrField<::protobuf::well_known_types::Any>,
+    // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
+    pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ExecProcessRequest {
+    fn default() -> &'a ExecProcessRequest {
+        <ExecProcessRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ExecProcessRequest {
+    pub fn new() -> ExecProcessRequest {
+        ::std::default::Default::default()
+    }
+
+    // string id = 1;
+
+
+    pub fn get_id(&self) -> &str {
+        &self.id
+    }
+    pub fn clear_id(&mut self) {
+        self.id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_id(&mut self, v: ::std::string::String) {
+        self.id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_id(&mut self) -> &mut ::std::string::String {
+        &mut self.id
+    }
+
+    // Take field
+    pub fn take_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.id, ::std::string::String::new())
+    }
+
+    // string exec_id = 2;
+
+
+    pub fn get_exec_id(&self) -> &str {
+        &self.exec_id
+    }
+    pub fn clear_exec_id(&mut self) {
+        self.exec_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_exec_id(&mut self, v: ::std::string::String) {
+        self.exec_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_exec_id(&mut self) -> &mut ::std::string::String {
+        &mut self.exec_id
+    }
+
+    // Take field
+    pub fn take_exec_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.exec_id, ::std::string::String::new())
+    }
+
+    // bool terminal = 3;
+
+
+    pub fn get_terminal(&self) -> bool {
+        self.terminal
+    }
+    pub fn clear_terminal(&mut self) {
+        self.terminal = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_terminal(&mut self, v: bool) {
+        self.terminal = v;
+    }
+
+    // string stdin = 4;
+
+
+    pub fn get_stdin(&self) -> &str {
+        &self.stdin
+    }
+    pub fn clear_stdin(&mut self) {
+        self.stdin.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_stdin(&mut self, v: ::std::string::String) {
+        self.stdin = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_stdin(&mut self) -> &mut ::std::string::String {
+        &mut self.stdin
+    }
+
+    // Take field
+    pub fn take_stdin(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.stdin, ::std::string::String::new())
+    }
+
+    // string stdout = 5;
+
+
+    pub fn get_stdout(&self) -> &str {
+        &self.stdout
+    }
+    pub fn clear_stdout(&mut self) {
+        self.stdout.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_stdout(&mut self, v: ::std::string::String) {
+        self.stdout = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_stdout(&mut self) -> &mut ::std::string::String {
+        &mut self.stdout
+    }
+
+    // Take field
+    pub fn take_stdout(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.stdout, ::std::string::String::new())
+    }
+
+    // string stderr = 6;
+
+
+    pub fn get_stderr(&self) -> &str {
+        &self.stderr
+    }
+    pub fn clear_stderr(&mut self) {
+        self.stderr.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_stderr(&mut self, v: ::std::string::String) {
+        self.stderr = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_stderr(&mut self) -> &mut ::std::string::String {
+        &mut self.stderr
+    }
+
+    // Take field
+    pub fn take_stderr(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.stderr, ::std::string::String::new())
+    }
+
+    // .google.protobuf.Any spec = 7;
+
+
+    pub fn get_spec(&self) -> &::protobuf::well_known_types::Any {
+        self.spec.as_ref().unwrap_or_else(|| <::protobuf::well_known_types::Any as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_spec(&mut self) {
+        self.spec.clear();
+    }
+
+    pub fn has_spec(&self) -> bool {
+        self.spec.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_spec(&mut self, v: ::protobuf::well_known_types::Any) {
+        self.spec = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_spec(&mut self) -> &mut ::protobuf::well_known_types::Any {
+        if self.spec.is_none() {
+            self.spec.set_default();
+        }
+        self.spec.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_spec(&mut self) -> ::protobuf::well_known_types::Any {
+        self.spec.take().unwrap_or_else(|| ::protobuf::well_known_types::Any::new())
+    }
+}
+
+impl ::protobuf::Message for ExecProcessRequest {
+    fn is_initialized(&self) -> bool {
+        for v in &self.spec {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.exec_id)?;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.terminal = tmp;
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.stdin)?;
+                },
+                5 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.stdout)?;
+                },
+                6 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.stderr)?;
+                },
+                7 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.spec)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.id);
+        }
+        if !self.exec_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.exec_id);
+        }
+        if self.terminal != false {
+            my_size += 2;
+        }
+        if !self.stdin.is_empty() {
+            my_size += ::protobuf::rt::string_size(4, &self.stdin);
+        }
+        if !self.stdout.is_empty() {
+            my_size += ::protobuf::rt::string_size(5, &self.stdout);
+        }
+        if !self.stderr.is_empty() {
+            my_size += ::protobuf::rt::string_size(6, &self.stderr);
+        }
+        if let Some(ref v) = self.spec.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.id.is_empty() {
+            os.write_string(1, &self.id)?;
+        }
+        if !self.exec_id.is_empty() {
+            os.write_string(2, &self.exec_id)?;
+        }
+        if self.terminal != false {
+            os.write_bool(3, self.terminal)?;
+        }
+        if !self.stdin.is_empty() {
+            os.write_string(4, &self.stdin)?;
+        }
+        if !self.stdout.is_empty() {
+            os.write_string(5, &self.stdout)?;
+        }
+        if !self.stderr.is_empty() {
+            os.write_string(6, &self.stderr)?;
+        }
+        if let Some(ref v) = self.spec.as_ref() {
+            os.write_tag(7, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ExecProcessRequest {
+        ExecProcessRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "id",
+                |m: &ExecProcessRequest| { &m.id },
+                |m: &mut ExecProcessRequest| { &mut m.id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "exec_id",
+                |m: &ExecProcessRequest| { &m.exec_id },
+                |m: &mut ExecProcessRequest| { &mut m.exec_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "terminal",
+                |m: &ExecProcessRequest| { &m.terminal },
+                |m: &mut ExecProcessRequest| { &mut m.terminal },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "stdin",
+                |m: &ExecProcessRequest| { &m.stdin },
+                |m: &mut ExecProcessRequest| { &mut m.stdin },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "stdout",
+                |m: &ExecProcessRequest| { &m.stdout },
+                |m: &mut ExecProcessRequest| { &mut m.stdout },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "stderr",
+                |m: &ExecProcessRequest| { &m.stderr },
+                |m: &mut ExecProcessRequest| { &mut m.stderr },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<::protobuf::well_known_types::Any>>(
+                "spec",
+                |m: &ExecProcessRequest| { &m.spec },
+                |m: &mut ExecProcessRequest| { &mut m.spec },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<ExecProcessRequest>(
+                "ExecProcessRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static ExecProcessRequest {
+        static instance: ::protobuf::rt::LazyV2<ExecProcessRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(ExecProcessRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for ExecProcessRequest {
+    fn clear(&mut self) {
+        self.id.clear();
+        self.exec_id.clear();
+        self.terminal = false;
+        self.stdin.clear();
+        self.stdout.clear();
+        self.stderr.clear();
+        self.spec.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ExecProcessRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ExecProcessRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
+pub struct ExecProcessResponse {
+    // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
+    pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ExecProcessResponse {
+    fn default() -> &'a ExecProcessResponse {
+        <ExecProcessResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ExecProcessResponse {
+    pub fn new() -> ExecProcessResponse {
+        ::std::default::Default::default()
+    }
+}
+
+impl ::protobuf::Message for ExecProcessResponse {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ExecProcessResponse {
+        ExecProcessResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let fields = ::std::vec::Vec::new();
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<ExecProcessResponse>(
+                "ExecProcessResponse",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static ExecProcessResponse {
+        static instance: ::protobuf::rt::LazyV2<ExecProcessResponse> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(ExecProcessResponse::new)
+    }
+}
+
+impl ::protobuf::Clear for ExecProcessResponse {
+    fn clear(&mut self) {
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ExecProcessResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ExecProcessResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
+pub struct ResizePtyRequest {
+    // message fields
+    pub id: ::std::string::String,
+    pub exec_id: ::std::string::String,
+    pub width: u32,
+    pub height: u32,
+    // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
+    pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ResizePtyRequest {
+    fn default() -> &'a ResizePtyRequest {
+        <ResizePtyRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ResizePtyRequest {
+    pub fn new() -> ResizePtyRequest {
+        ::std::default::Default::default()
+    }
+
+    // string id = 1;
+
+
+    pub fn get_id(&self) -> &str {
+        &self.id
+    }
+    pub fn clear_id(&mut self) {
+        self.id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_id(&mut self, v: ::std::string::String) {
+        self.id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_id(&mut self) -> &mut ::std::string::String {
+        &mut self.id
+    }
+
+    // Take field
+    pub fn take_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.id, ::std::string::String::new())
+    }
+
+    // string exec_id = 2;
+
+
+    pub fn get_exec_id(&self) -> &str {
+        &self.exec_id
+    }
+    pub fn clear_exec_id(&mut self) {
+        self.exec_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_exec_id(&mut self, v: ::std::string::String) {
+        self.exec_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_exec_id(&mut self) -> &mut ::std::string::String {
+        &mut self.exec_id
+    }
+
+    // Take field
+    pub fn take_exec_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.exec_id, ::std::string::String::new())
+    }
+
+    // uint32 width = 3;
+
+
+    pub fn get_width(&self) -> u32 {
+        self.width
+    }
+    pub fn clear_width(&mut self) {
+        self.width = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_width(&mut self, v: u32) {
+        self.width = v;
+    }
+
+    // uint32 height = 4;
+
+
+    pub fn get_height(&self) -> u32 {
+        self.height
+    }
+    pub fn clear_height(&mut self) {
+        self.height = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_height(&mut self, v: u32) {
+        self.height = v;
+    }
+}
+
+impl ::protobuf::Message for ResizePtyRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.exec_id)?;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.width = tmp;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.height = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.id);
+        }
+        if !self.exec_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.exec_id);
+        }
+        if self.width != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.width, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.height != 0 {
+            my_size += ::protobuf::rt::value_size(4, self.height, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.id.is_empty() {
+            os.write_string(1, &self.id)?;
+        }
+        if !self.exec_id.is_empty() {
+            os.write_string(2, &self.exec_id)?;
+        }
+        if self.width != 0 {
+            os.write_uint32(3, self.width)?;
+        }
+        if self.height != 0 {
+            os.write_uint32(4, self.height)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ResizePtyRequest {
+        ResizePtyRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "id",
+                |m: &ResizePtyRequest| { &m.id },
+                |m: &mut ResizePtyRequest| { &mut m.id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "exec_id",
+                |m: &ResizePtyRequest| { &m.exec_id },
+                |m: &mut ResizePtyRequest| { &mut m.exec_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "width",
+                |m: &ResizePtyRequest| { &m.width },
+                |m: &mut ResizePtyRequest| { &mut m.width },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "height",
+                |m: &ResizePtyRequest| { &m.height },
+                |m: &mut ResizePtyRequest| { &mut m.height },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<ResizePtyRequest>(
+                "ResizePtyRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static ResizePtyRequest {
+        static instance: ::protobuf::rt::LazyV2<ResizePtyRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(ResizePtyRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for ResizePtyRequest {
+    fn clear(&mut self) {
+        self.id.clear();
+        self.exec_id.clear();
+        self.width = 0;
+        self.height = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ResizePtyRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ResizePtyRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
+pub struct StateRequest {
+    // message fields
+    pub id: ::std::string::String,
+    pub exec_id: ::std::string::String,
+    // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
+    pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a StateRequest {
+    fn default() -> &'a StateRequest {
+        <StateRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl StateRequest {
+    pub fn new() -> StateRequest {
+        ::std::default::Default::default()
+    }
+
+    // string id = 1;
+
+
+    pub fn get_id(&self) -> &str {
+        &self.id
+    }
+    pub fn clear_id(&mut self) {
+        self.id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_id(&mut self, v: ::std::string::String) {
+        self.id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_id(&mut self) -> &mut ::std::string::String {
+        &mut self.id
+    }
+
+    // Take field
+    pub fn take_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.id, ::std::string::String::new())
+    }
+
+    // string exec_id = 2;
+
+
+    pub fn get_exec_id(&self) -> &str {
+        &self.exec_id
+    }
+    pub fn clear_exec_id(&mut self) {
+        self.exec_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_exec_id(&mut self, v: ::std::string::String) {
+        self.exec_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_exec_id(&mut self) -> &mut ::std::string::String {
+        &mut self.exec_id
+    }
+
+    // Take field
+    pub fn take_exec_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.exec_id, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for StateRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.exec_id)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.id);
+        }
+        if !self.exec_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.exec_id);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.id.is_empty() {
+            os.write_string(1, &self.id)?;
+        }
+        if !self.exec_id.is_empty() {
+            os.write_string(2, &self.exec_id)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> StateRequest {
+        StateRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "id",
+                |m: &StateRequest| { &m.id },
+                |m: &mut StateRequest| { &mut m.id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "exec_id",
+                |m: &StateRequest| { &m.exec_id },
+                |m: &mut StateRequest| { &mut m.exec_id },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<StateRequest>(
+                "StateRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static StateRequest {
+        static instance: ::protobuf::rt::LazyV2<StateRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(StateRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for StateRequest {
+    fn clear(&mut self) {
+        self.id.clear();
+        self.exec_id.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for StateRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for StateRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
+pub struct StateResponse {
+    // message fields
+    pub id: ::std::string::String,
+    pub bundle: ::std::string::String,
+    pub pid: u32,
+    pub status: super::task::Status,
+    pub stdin: ::std::string::String,
+    pub stdout: ::std::string::String,
+    pub stderr: ::std::string::String,
+    pub terminal: bool,
+    pub exit_status: u32,
+    #[cfg_attr(feature = "with-serde", serde(default, with = "crate::json::timestamp"))]
+    pub exited_at: ::protobuf::SingularPtrField<::protobuf::well_known_types::Timestamp>,
+    pub exec_id: ::std::string::String,
+    // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
+    pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a StateResponse {
+    fn default() -> &'a StateResponse {
+        <StateResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl StateResponse {
+    pub fn new() -> StateResponse {
+        ::std::default::Default::default()
+    }
+
+    // string id = 1;
+
+
+    pub fn get_id(&self) -> &str {
+        &self.id
+    }
+    pub fn clear_id(&mut self) {
+        self.id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_id(&mut self, v: ::std::string::String) {
+        self.id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_id(&mut self) -> &mut ::std::string::String {
+        &mut self.id
+    }
+
+    // Take field
+    pub fn take_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.id, ::std::string::String::new())
+    }
+
+    // string bundle = 2;
+
+
+    pub fn get_bundle(&self) -> &str {
+        &self.bundle
+    }
+    pub fn clear_bundle(&mut self) {
+        self.bundle.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_bundle(&mut self, v: ::std::string::String) {
+        self.bundle = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_bundle(&mut self) -> &mut ::std::string::String {
+        &mut self.bundle
+    }
+
+    // Take field
+    pub fn take_bundle(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.bundle, ::std::string::String::new())
+    }
+
+    // uint32 pid = 3;
+
+
+    pub fn get_pid(&self) -> u32 {
+        self.pid
+    }
+    pub fn clear_pid(&mut self) {
+        self.pid = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_pid(&mut self, v: u32) {
+        self.pid = v;
+    }
+
+    // .containerd.v1.types.Status status = 4;
+
+
+    pub fn get_status(&self) -> super::task::Status {
+        self.status
+    }
+    pub fn clear_status(&mut self) {
+        self.status = super::task::Status::UNKNOWN;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_status(&mut self, v: super::task::Status) {
+        self.status = v;
+    }
+
+    // string stdin = 5;
+
+
+    pub fn get_stdin(&self) -> &str {
+        &self.stdin
+    }
+    pub fn clear_stdin(&mut self) {
+        self.stdin.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_stdin(&mut self, v: ::std::string::String) {
+        self.stdin = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_stdin(&mut self) -> &mut ::std::string::String {
+        &mut self.stdin
+    }
+
+    // Take field
+    pub fn take_stdin(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.stdin, ::std::string::String::new())
+    }
+
+    // string stdout = 6;
+
+
+    pub fn get_stdout(&self) -> &str {
+        &self.stdout
+    }
+    pub fn clear_stdout(&mut self) {
+        self.stdout.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_stdout(&mut self, v: ::std::string::String) {
+        self.stdout = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_stdout(&mut self) -> &mut ::std::string::String {
+        &mut self.stdout
+    }
+
+    // Take field
+    pub fn take_stdout(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.stdout, ::std::string::String::new())
+    }
+
+    // string stderr = 7;
+
+
+    pub fn get_stderr(&self) -> &str {
+        &self.stderr
+    }
+    pub fn clear_stderr(&mut self) {
+        self.stderr.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_stderr(&mut self, v: ::std::string::String) {
+        self.stderr = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_stderr(&mut self) -> &mut ::std::string::String {
+        &mut self.stderr
+    }
+
+    // Take field
+    pub fn take_stderr(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.stderr, ::std::string::String::new())
+    }
+
+    // bool terminal = 8;
+
+
+    pub fn get_terminal(&self) -> bool {
+        self.terminal
+    }
+    pub fn clear_terminal(&mut self) {
+        self.terminal = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_terminal(&mut self, v: bool) {
+        self.terminal = v;
+    }
+
+    // uint32 exit_status = 9;
+
+
+    pub fn get_exit_status(&self) -> u32 {
+        self.exit_status
+    }
+    pub fn clear_exit_status(&mut self) {
+        self.exit_status = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_exit_status(&mut self, v: u32) {
+        self.exit_status = v;
+    }
+
+    // .google.protobuf.Timestamp exited_at = 10;
+
+
+    pub fn get_exited_at(&self) -> &::protobuf::well_known_types::Timestamp {
+        self.exited_at.as_ref().unwrap_or_else(|| <::protobuf::well_known_types::Timestamp as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_exited_at(&mut self) {
+        self.exited_at.clear();
+    }
+
+    pub fn has_exited_at(&self) -> bool {
+        self.exited_at.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_exited_at(&mut self, v: ::protobuf::well_known_types::Timestamp) {
+        self.exited_at = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_exited_at(&mut self) -> &mut ::protobuf::well_known_types::Timestamp {
+        if self.exited_at.is_none() {
+            self.exited_at.set_default();
+        }
+        self.exited_at.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_exited_at(&mut self) -> ::protobuf::well_known_types::Timestamp {
+        self.exited_at.take().unwrap_or_else(|| ::protobuf::well_known_types::Timestamp::new())
+    }
+
+    // string exec_id = 11;
+
+
+    pub fn get_exec_id(&self) -> &str {
+        &self.exec_id
+    }
+    pub fn clear_exec_id(&mut self) {
+        self.exec_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_exec_id(&mut self, v: ::std::string::String) {
+        self.exec_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_exec_id(&mut self) -> &mut ::std::string::String {
+        &mut self.exec_id
+    }
+
+    // Take field
+    pub fn take_exec_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.exec_id, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for StateResponse {
+    fn is_initialized(&self) -> bool {
+        for v in &self.exited_at {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.bundle)?;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.pid = tmp;
+                },
+                4 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.status, 4, &mut self.unknown_fields)?
+                },
+                5 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.stdin)?;
+                },
+                6 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.stdout)?;
+                },
+                7 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.stderr)?;
+                },
+                8 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.terminal = tmp;
+                },
+                9 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.exit_status = tmp;
+                },
+                10 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.exited_at)?;
+                },
+                11 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.exec_id)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.id);
+        }
+        if !self.bundle.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.bundle);
+        }
+        if self.pid != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.pid, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.status != super::task::Status::UNKNOWN {
+            my_size += ::protobuf::rt::enum_size(4, self.status);
+        }
+        if !self.stdin.is_empty() {
+            my_size += ::protobuf::rt::string_size(5, &self.stdin);
+        }
+        if !self.stdout.is_empty() {
+            my_size += ::protobuf::rt::string_size(6, &self.stdout);
+        }
+        if !self.stderr.is_empty() {
+            my_size += ::protobuf::rt::string_size(7, &self.stderr);
+        }
+        if self.terminal != false {
+            my_size += 2;
+        }
+        if self.exit_status != 0 {
+            my_size += ::protobuf::rt::value_size(9, self.exit_status, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(ref v) = self.exited_at.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if !self.exec_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(11, &self.exec_id);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.id.is_empty() {
+            os.write_string(1, &self.id)?;
+        }
+        if !self.bundle.is_empty() {
+            os.write_string(2, &self.bundle)?;
+        }
+        if self.pid != 0 {
+            os.write_uint32(3, self.pid)?;
+        }
+        if self.status != super::task::Status::UNKNOWN {
+            os.write_enum(4, ::protobuf::ProtobufEnum::value(&self.status))?;
+        }
+        if !self.stdin.is_empty() {
+            os.write_string(5, &self.stdin)?;
+        }
+        if !self.stdout.is_empty() {
+            os.write_string(6, &self.stdout)?;
+        }
+        if !self.stderr.is_empty() {
+            os.write_string(7, &self.stderr)?;
+        }
+        if self.terminal != false {
+            os.write_bool(8, self.terminal)?;
+        }
+        if self.exit_status != 0 {
+            os.write_uint32(9, self.exit_status)?;
+        }
+        if let Some(ref v) = self.exited_at.as_ref() {
+            os.write_tag(10, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if !self.exec_id.is_empty() {
+            os.write_string(11, &self.exec_id)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> StateResponse {
+        StateResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "id",
+                |m: &StateResponse| { &m.id },
+                |m: &mut StateResponse| { &mut m.id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "bundle",
+                |m: &StateResponse| { &m.bundle },
+                |m: &mut StateResponse| { &mut m.bundle },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "pid",
+                |m: &StateResponse| { &m.pid },
+                |m: &mut StateResponse| { &mut m.pid },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<super::task::Status>>(
+                "status",
+                |m: &StateResponse| { &m.status },
+                |m: &mut StateResponse| { &mut m.status },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "stdin",
+                |m: &StateResponse| { &m.stdin },
+                |m: &mut StateResponse| { &mut m.stdin },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "stdout",
+                |m: &StateResponse| { &m.stdout },
+                |m: &mut StateResponse| { &mut m.stdout },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "stderr",
+                |m: &StateResponse| { &m.stderr },
+                |m: &mut StateResponse| { &mut m.stderr },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "terminal",
+                |m: &StateResponse| { &m.terminal },
+                |m: &mut StateResponse| { &mut m.terminal },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "exit_status",
+                |m: &StateResponse| { &m.exit_status },
+                |m: &mut StateResponse| { &mut m.exit_status },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<::protobuf::well_known_types::Timestamp>>(
+                "exited_at",
+                |m: &StateResponse| { &m.exited_at },
+                |m: &mut StateResponse| { &mut m.exited_at },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "exec_id",
+                |m: &StateResponse| { &m.exec_id },
+                |m: &mut StateResponse| { &mut m.exec_id },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<StateResponse>(
+                "StateResponse",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static StateResponse {
+        static instance: ::protobuf::rt::LazyV2<StateResponse> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(StateResponse::new)
+    }
+}
+
+impl ::protobuf::Clear for StateResponse {
+    fn clear(&mut self) {
+        self.id.clear();
+        self.bundle.clear();
+        self.pid = 0;
+        self.status = super::task::Status::UNKNOWN;
+        self.stdin.clear();
+        self.stdout.clear();
+        self.stderr.clear();
+        self.terminal = false;
+        self.exit_status = 0;
+        self.exited_at.clear();
+        self.exec_id.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for StateResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for StateResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
+pub struct KillRequest {
+    // message fields
+    pub id: ::std::string::String,
+    pub exec_id: ::std::string::String,
+    pub signal: u32,
+    pub all: bool,
+    // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
+    pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a KillRequest {
+    fn default() -> &'a KillRequest {
+        <KillRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl KillRequest {
+    pub fn new() -> KillRequest {
+        ::std::default::Default::default()
+    }
+
+    // string id = 1;
+
+
+    pub fn get_id(&self) -> &str {
+        &self.id
+    }
+    pub fn clear_id(&mut self) {
+        self.id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_id(&mut self, v: ::std::string::String) {
+        self.id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_id(&mut self) -> &mut ::std::string::String {
+        &mut self.id
+    }
+
+    // Take field
+    pub fn take_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.id, ::std::string::String::new())
+    }
+
+    // string exec_id = 2;
+
+
+    pub fn get_exec_id(&self) -> &str {
+        &self.exec_id
+    }
+    pub fn clear_exec_id(&mut self) {
+        self.exec_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_exec_id(&mut self, v: ::std::string::String) {
+        self.exec_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_exec_id(&mut self) -> &mut ::std::string::String {
+        &mut self.exec_id
+    }
+
+    // Take field
+    pub fn take_exec_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.exec_id, ::std::string::String::new())
+    }
+
+    // uint32 signal = 3;
+
+
+    pub fn get_signal(&self) -> u32 {
+        self.signal
+    }
+    pub fn clear_signal(&mut self) {
+        self.signal = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_signal(&mut self, v: u32) {
+        self.signal = v;
+    }
+
+    // bool all = 4;
+
+
+    pub fn get_all(&self) -> bool {
+        self.all
+    }
+    pub fn clear_all(&mut self) {
+        self.all = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_all(&mut self, v: bool) {
+        self.all = v;
+    }
+}
+
+impl ::protobuf::Message for KillRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.exec_id)?;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.signal = tmp;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.all = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.id);
+        }
+        if !self.exec_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.exec_id);
+        }
+        if self.signal != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.signal, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.all != false {
+            my_size += 2;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.id.is_empty() {
+            os.write_string(1, &self.id)?;
+        }
+        if !self.exec_id.is_empty() {
+            os.write_string(2, &self.exec_id)?;
+        }
+        if self.signal != 0 {
+            os.write_uint32(3, self.signal)?;
+        }
+        if self.all != false {
+            os.write_bool(4, self.all)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> KillRequest {
+        KillRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "id",
+                |m: &KillRequest| { &m.id },
+                |m: &mut KillRequest| { &mut m.id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "exec_id",
+                |m: &KillRequest| { &m.exec_id },
+                |m: &mut KillRequest| { &mut m.exec_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "signal",
+                |m: &KillRequest| { &m.signal },
+                |m: &mut KillRequest| { &mut m.signal },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "all",
+                |m: &KillRequest| { &m.all },
+                |m: &mut KillRequest| { &mut m.all },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<KillRequest>(
+                "KillRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static KillRequest {
+        static instance: ::protobuf::rt::LazyV2<KillRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(KillRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for KillRequest {
+    fn clear(&mut self) {
+        self.id.clear();
+        self.exec_id.clear();
+        self.signal = 0;
+        self.all = false;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for KillRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for KillRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
+pub struct CloseIORequest {
+    // message fields
+    pub id: ::std::string::String,
+    pub exec_id: ::std::string::String,
+    pub stdin: bool,
+    // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
+    pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a CloseIORequest {
+    fn default() -> &'a CloseIORequest {
+        <CloseIORequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl CloseIORequest {
+    pub fn new() -> CloseIORequest {
+        ::std::default::Default::default()
+    }
+
+    // string id = 1;
+
+
+    pub fn get_id(&self) -> &str {
+        &self.id
+    }
+    pub fn clear_id(&mut self) {
+        self.id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_id(&mut self, v: ::std::string::String) {
+        self.id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_id(&mut self) -> &mut ::std::string::String {
+        &mut self.id
+    }
+
+    // Take field
+    pub fn take_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.id, ::std::string::String::new())
+    }
+
+    // string exec_id = 2;
+
+
+    pub fn get_exec_id(&self) -> &str {
+        &self.exec_id
+    }
+    pub fn clear_exec_id(&mut self) {
+        self.exec_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_exec_id(&mut self, v: ::std::string::String) {
+        self.exec_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_exec_id(&mut self) -> &mut ::std::string::String {
+        &mut self.exec_id
+    }
+
+    // Take field
+    pub fn take_exec_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.exec_id, ::std::string::String::new())
+    }
+
+    // bool stdin = 3;
+
+
+    pub fn get_stdin(&self) -> bool {
+        self.stdin
+    }
+    pub fn clear_stdin(&mut self) {
+        self.stdin = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_stdin(&mut self, v: bool) {
+        self.stdin = v;
+    }
+}
+
+impl ::protobuf::Message for CloseIORequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.exec_id)?;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.stdin = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.id);
+        }
+        if !self.exec_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.exec_id);
+        }
+        if self.stdin != false {
+            my_size += 2;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.id.is_empty() {
+            os.write_string(1, &self.id)?;
+        }
+        if !self.exec_id.is_empty() {
+            os.write_string(2, &self.exec_id)?;
+        }
+        if self.stdin != false {
+            os.write_bool(3, self.stdin)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> CloseIORequest {
+        CloseIORequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "id",
+                |m: &CloseIORequest| { &m.id },
+                |m: &mut CloseIORequest| { &mut m.id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "exec_id",
+                |m: &CloseIORequest| { &m.exec_id },
+                |m: &mut CloseIORequest| { &mut m.exec_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "stdin",
+                |m: &CloseIORequest| { &m.stdin },
+                |m: &mut CloseIORequest| { &mut m.stdin },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<CloseIORequest>(
+                "CloseIORequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static CloseIORequest {
+        static instance: ::protobuf::rt::LazyV2<CloseIORequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(CloseIORequest::new)
+    }
+}
+
+impl ::protobuf::Clear for CloseIORequest {
+    fn clear(&mut self) {
+        self.id.clear();
+        self.exec_id.clear();
+        self.stdin = false;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for CloseIORequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CloseIORequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
+pub struct PidsRequest {
+    // message fields
+    pub id: ::std::string::String,
+    // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
+    pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a PidsRequest {
+    fn default() -> &'a PidsRequest {
+        <PidsRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl PidsRequest {
+    pub fn new() -> PidsRequest {
+        ::std::default::Default::default()
+    }
+
+    // string id = 1;
+
+
+    pub fn get_id(&self) -> &str {
+        &self.id
+    }
+    pub fn clear_id(&mut self) {
+        self.id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_id(&mut self, v: ::std::string::String) {
+        self.id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_id(&mut self) -> &mut ::std::string::String {
+        &mut self.id
+    }
+
+    // Take field
+    pub fn take_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.id, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for PidsRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.id)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.id);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.id.is_empty() {
+            os.write_string(1, &self.id)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> PidsRequest {
+        PidsRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "id",
+                |m: &PidsRequest| { &m.id },
+                |m: &mut PidsRequest| { &mut m.id },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<PidsRequest>(
+                "PidsRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static PidsRequest {
+        static instance: ::protobuf::rt::LazyV2<PidsRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(PidsRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for PidsRequest {
+    fn clear(&mut self) {
+        self.id.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for PidsRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for PidsRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
+pub struct PidsResponse {
+    // message fields
+    pub processes: ::protobuf::RepeatedField<super::task::ProcessInfo>,
+    // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
+    pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a PidsResponse {
+    fn default() -> &'a PidsResponse {
+        <PidsResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl PidsResponse {
+    pub fn new() -> PidsResponse {
+        ::std::default::Default::default()
+    }
+
+    // repeated .containerd.v1.types.ProcessInfo processes = 1;
+
+
+    pub fn get_processes(&self) -> &[super::task::ProcessInfo] {
+        &self.processes
+    }
+    pub fn clear_processes(&mut self) {
+        self.processes.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_processes(&mut self, v: ::protobuf::RepeatedField<super::task::ProcessInfo>) {
+        self.processes = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_processes(&mut self) -> &mut ::protobuf::RepeatedField<super::task::ProcessInfo> {
+        &mut self.processes
+    }
+
+    // Take field
+    pub fn take_processes(&mut self) -> ::protobuf::RepeatedField<super::task::ProcessInfo> {
+        ::std::mem::replace(&mut self.processes, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for PidsResponse {
+    fn is_initialized(&self) -> bool {
+        for v in &self.processes {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.processes)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.processes {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.processes {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> PidsResponse {
+        PidsResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::task::ProcessInfo>>(
+                "processes",
+                |m: &PidsResponse| { &m.processes },
+                |m: &mut PidsResponse| { &mut m.processes },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<PidsResponse>(
+                "PidsResponse",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static PidsResponse {
+        static instance: ::protobuf::rt::LazyV2<PidsResponse> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(PidsResponse::new)
+    }
+}
+
+impl ::protobuf::Clear for PidsResponse {
+    fn clear(&mut self) {
+        self.processes.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for PidsResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for PidsResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
+pub struct CheckpointTaskRequest {
+    // message fields
+    pub id: ::std::string::String,
+    pub path: ::std::string::String,
+    #[cfg_attr(feature = "with-serde", serde(default, with = "crate::json::any"))]
+    pub options: ::protobuf::SingularPtrField<::protobuf::well_known_types::Any>,
+    // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
+    pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a CheckpointTaskRequest {
+    fn default() -> &'a CheckpointTaskRequest {
+        <CheckpointTaskRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl CheckpointTaskRequest {
+    pub fn new() -> CheckpointTaskRequest {
+        ::std::default::Default::default()
+    }
+
+    // string id = 1;
+
+
+    pub fn get_id(&self) -> &str {
+        &self.id
+    }
+    pub fn clear_id(&mut self) {
+        self.id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_id(&mut self, v: ::std::string::String) {
+        self.id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_id(&mut self) -> &mut ::std::string::String {
+        &mut self.id
+    }
+
+    // Take field
+    pub fn take_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.id, ::std::string::String::new())
+    }
+
+    // string path = 2;
+
+
+    pub fn get_path(&self) -> &str {
+        &self.path
+    }
+    pub fn clear_path(&mut self) {
+        self.path.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_path(&mut self, v: ::std::string::String) {
+        self.path = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_path(&mut self) -> &mut ::std::string::String {
+        &mut self.path
+    }
+
+    // Take field
+    pub fn take_path(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.path, ::std::string::String::new())
+    }
+
+    // .google.protobuf.Any options = 3;
+
+
+    pub fn get_options(&self) -> &::protobuf::well_known_types::Any {
+        self.options.as_ref().unwrap_or_else(|| <::protobuf::well_known_types::Any as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_options(&mut self) {
+        self.options.clear();
+    }
+
+    pub fn has_options(&self) -> bool {
+        self.options.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_options(&mut self, v: ::protobuf::well_known_types::Any) {
+        self.options = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_options(&mut self) -> &mut ::protobuf::well_known_types::Any {
+        if self.options.is_none() {
+            self.options.set_default();
+        }
+        self.options.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_options(&mut self) -> ::protobuf::well_known_types::Any {
+        self.options.take().unwrap_or_else(|| ::protobuf::well_known_types::Any::new())
+    }
+}
+
+impl ::protobuf::Message for CheckpointTaskRequest {
+    fn is_initialized(&self) -> bool {
+        for v in &self.options {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.path)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.options)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.id);
+        }
+        if !self.path.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.path);
+        }
+        if let Some(ref v) = self.options.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.id.is_empty() {
+            os.write_string(1, &self.id)?;
+        }
+        if !self.path.is_empty() {
+            os.write_string(2, &self.path)?;
+        }
+        if let Some(ref v) = self.options.as_ref() {
+            os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> CheckpointTaskRequest {
+        CheckpointTaskRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "id",
+                |m: &CheckpointTaskRequest| { &m.id },
+                |m: &mut CheckpointTaskRequest| { &mut m.id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "path",
+                |m: &CheckpointTaskRequest| { &m.path },
+                |m: &mut CheckpointTaskRequest| { &mut m.path },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<::protobuf::well_known_types::Any>>(
+                "options",
+                |m: &CheckpointTaskRequest| { &m.options },
+                |m: &mut CheckpointTaskRequest| { &mut m.options },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<CheckpointTaskRequest>(
+                "CheckpointTaskRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static CheckpointTaskRequest {
+        static instance: ::protobuf::rt::LazyV2<CheckpointTaskRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(CheckpointTaskRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for CheckpointTaskRequest {
+    fn clear(&mut self) {
+        self.id.clear();
+        self.path.clear();
+        self.options.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for CheckpointTaskRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CheckpointTaskRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
+pub struct UpdateTaskRequest {
+    // message fields
+    pub id: ::std::string::String,
+    #[cfg_attr(feature = "with-serde", serde(default, with = "crate::json::any"))]
+    pub resources: ::protobuf::SingularPtrField<::protobuf::well_known_types::Any>,
+    pub annotations: ::std::collections::HashMap<::std::string::String, ::std::string::String>,
+    // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
+    pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a UpdateTaskRequest {
+    fn default() -> &'a UpdateTaskRequest {
+        <UpdateTaskRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl UpdateTaskRequest {
+    pub fn new() -> UpdateTaskRequest {
+        ::std::default::Default::default()
+    }
+
+    // string id = 1;
+
+
+    pub fn get_id(&self) -> &str {
+        &self.id
+    }
+    pub fn clear_id(&mut self) {
+        self.id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_id(&mut self, v: ::std::string::String) {
+        self.id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_id(&mut self) -> &mut ::std::string::String {
+        &mut self.id
+    }
+
+    // Take field
+    pub fn take_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.id, ::std::string::String::new())
+    }
+
+    // .google.protobuf.Any resources = 2;
+
+
+    pub fn get_resources(&self) -> &::protobuf::well_known_types::Any {
+        self.resources.as_ref().unwrap_or_else(|| <::protobuf::well_known_types::Any as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_resources(&mut self) {
+        self.resources.clear();
+    }
+
+    pub fn has_resources(&self) -> bool {
+        self.resources.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_resources(&mut self, v: ::protobuf::well_known_types::Any) {
+        self.resources = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_resources(&mut self) -> &mut ::protobuf::well_known_types::Any {
+        if self.resources.is_none() {
+            self.resources.set_default();
+        }
+        self.resources.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_resources(&mut self) -> ::protobuf::well_known_types::Any {
+        self.resources.take().unwrap_or_else(|| ::protobuf::well_known_types::Any::new())
+    }
+
+    // repeated .containerd.task.v3.UpdateTaskRequest.annotations_MapEntry annotations = 3;
+
+
+    pub fn get_annotations(&self) -> &::std::collections::HashMap<::std::string::String, ::std::string::String> {
+        &self.annotations
+    }
+    pub fn clear_annotations(&mut self) {
+        self.annotations.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_annotations(&mut self, v: ::std::collections::HashMap<::std::string::String, ::std::string::String>) {
+        self.annotations = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_annotations(&mut self) -> &mut ::std::collections::HashMap<::std::string::String, ::std::string::String> {
+        &mut self.annotations
+    }
+
+    // Take field
+    pub fn take_annotations(&mut self) -> ::std::collections::HashMap<::std::string::String, ::std::string::String> {
+        ::std::mem::replace(&mut self.annotations, ::std::collections::HashMap::new())
+    }
+}
+
+impl ::protobuf::Message for UpdateTaskRequest {
+    fn is_initialized(&self) -> bool {
+        for v in &self.resources {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.resources)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_map_into::<::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeString>(wire_type, is, &mut self.annotations)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.id);
+        }
+        if let Some(ref v) = self.resources.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::compute_map_size::<::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeString>(3, &self.annotations);
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.id.is_empty() {
+            os.write_string(1, &self.id)?;
+        }
+        if let Some(ref v) = self.resources.as_ref() {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        ::protobuf::rt::write_map_with_cached_sizes::<::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeString>(3, &self.annotations, os)?;
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> UpdateTaskRequest {
+        UpdateTaskRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "id",
+                |m: &UpdateTaskRequest| { &m.id },
+                |m: &mut UpdateTaskRequest| { &mut m.id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<::protobuf::well_known_types::Any>>(
+                "resources",
+                |m: &UpdateTaskRequest| { &m.resources },
+                |m: &mut UpdateTaskRequest| { &mut m.resources },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_map_accessor::<_, ::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeString>(
+                "annotations",
+                |m: &UpdateTaskRequest| { &m.annotations },
+                |m: &mut UpdateTaskRequest| { &mut m.annotations },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<UpdateTaskRequest>(
+                "UpdateTaskRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static UpdateTaskRequest {
+        static instance: ::protobuf::rt::LazyV2<UpdateTaskRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(UpdateTaskRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for UpdateTaskRequest {
+    fn clear(&mut self) {
+        self.id.clear();
+        self.resources.clear();
+        self.annotations.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for UpdateTaskRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for UpdateTaskRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
+pub struct StartRequest {
+    // message fields
+    pub id: ::std::string::String,
+    pub exec_id: ::std::string::String,
+    // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
+    pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a StartRequest {
+    fn default() -> &'a StartRequest {
+        <StartRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl StartRequest {
+    pub fn new() -> StartRequest {
+        ::std::default::Default::default()
+    }
+
+    // string id = 1;
+
+
+    pub fn get_id(&self) -> &str {
+        &self.id
+    }
+    pub fn clear_id(&mut self) {
+        self.id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_id(&mut self, v: ::std::string::String) {
+        self.id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_id(&mut self) -> &mut ::std::string::String {
+        &mut self.id
+    }
+
+    // Take field
+    pub fn take_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.id, ::std::string::String::new())
+    }
+
+    // string exec_id = 2;
+
+
+    pub fn get_exec_id(&self) -> &str {
+        &self.exec_id
+    }
+    pub fn clear_exec_id(&mut self) {
+        self.exec_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_exec_id(&mut self, v: ::std::string::String) {
+        self.exec_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_exec_id(&mut self) -> &mut ::std::string::String {
+        &mut self.exec_id
+    }
+
+    // Take field
+    pub fn take_exec_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.exec_id, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for StartRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.exec_id)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.id);
+        }
+        if !self.exec_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.exec_id);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.id.is_empty() {
+            os.write_string(1, &self.id)?;
+        }
+        if !self.exec_id.is_empty() {
+            os.write_string(2, &self.exec_id)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> StartRequest {
+        StartRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "id",
+                |m: &StartRequest| { &m.id },
+                |m: &mut StartRequest| { &mut m.id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "exec_id",
+                |m: &StartRequest| { &m.exec_id },
+                |m: &mut StartRequest| { &mut m.exec_id },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<StartRequest>(
+                "StartRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static StartRequest {
+        static instance: ::protobuf::rt::LazyV2<StartRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(StartRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for StartRequest {
+    fn clear(&mut self) {
+        self.id.clear();
+        self.exec_id.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for StartRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for StartRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
+pub struct StartResponse {
+    // message fields
+    pub pid: u32,
+    // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
+    pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a StartResponse {
+    fn default() -> &'a StartResponse {
+        <StartResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl StartResponse {
+    pub fn new() -> StartResponse {
+        ::std::default::Default::default()
+    }
+
+    // uint32 pid = 1;
+
+
+    pub fn get_pid(&self) -> u32 {
+        self.pid
+    }
+    pub fn clear_pid(&mut self) {
+        self.pid = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_pid(&mut self, v: u32) {
+        self.pid = v;
+    }
+}
+
+impl ::protobuf::Message for StartResponse {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.pid = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.pid != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.pid, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.pid != 0 {
+            os.write_uint32(1, self.pid)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> StartResponse {
+        StartResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "pid",
+                |m: &StartResponse| { &m.pid },
+                |m: &mut StartResponse| { &mut m.pid },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<StartResponse>(
+                "StartResponse",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static StartResponse {
+        static instance: ::protobuf::rt::LazyV2<StartResponse> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(StartResponse::new)
+    }
+}
+
+impl ::protobuf::Clear for StartResponse {
+    fn clear(&mut self) {
+        self.pid = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for StartResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for StartResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
+pub struct WaitRequest {
+    // message fields
+    pub id: ::std::string::String,
+    pub exec_id: ::std::string::String,
+    // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
+    pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a WaitRequest {
+    fn default() -> &'a WaitRequest {
+        <WaitRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl WaitRequest {
+    pub fn new() -> WaitRequest {
+        ::std::default::Default::default()
+    }
+
+    // string id = 1;
+
+
+    pub fn get_id(&self) -> &str {
+        &self.id
+    }
+    pub fn clear_id(&mut self) {
+        self.id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_id(&mut self, v: ::std::string::String) {
+        self.id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_id(&mut self) -> &mut ::std::string::String {
+        &mut self.id
+    }
+
+    // Take field
+    pub fn take_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.id, ::std::string::String::new())
+    }
+
+    // string exec_id = 2;
+
+
+    pub fn get_exec_id(&self) -> &str {
+        &self.exec_id
+    }
+    pub fn clear_exec_id(&mut self) {
+        self.exec_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_exec_id(&mut self, v: ::std::string::String) {
+        self.exec_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_exec_id(&mut self) -> &mut ::std::string::String {
+        &mut self.exec_id
+    }
+
+    // Take field
+    pub fn take_exec_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.exec_id, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for WaitRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.exec_id)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.id);
+        }
+        if !self.exec_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.exec_id);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.id.is_empty() {
+            os.write_string(1, &self.id)?;
+        }
+        if !self.exec_id.is_empty() {
+            os.write_string(2, &self.exec_id)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> WaitRequest {
+        WaitRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "id",
+                |m: &WaitRequest| { &m.id },
+                |m: &mut WaitRequest| { &mut m.id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "exec_id",
+                |m: &WaitRequest| { &m.exec_id },
+                |m: &mut WaitRequest| { &mut m.exec_id },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<WaitRequest>(
+                "WaitRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static WaitRequest {
+        static instance: ::protobuf::rt::LazyV2<WaitRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(WaitRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for WaitRequest {
+    fn clear(&mut self) {
+        self.id.clear();
+        self.exec_id.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for WaitRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for WaitRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
+pub struct WaitResponse {
+    // message fields
+    pub exit_status: u32,
+    #[cfg_attr(feature = "with-serde", serde(default, with = "crate::json::timestamp"))]
+    pub exited_at: ::protobuf::SingularPtrField<::protobuf::well_known_types::Timestamp>,
+    // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
+    pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a WaitResponse {
+    fn default() -> &'a WaitResponse {
+        <WaitResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl WaitResponse {
+    pub fn new() -> WaitResponse {
+        ::std::default::Default::default()
+    }
+
+    // uint32 exit_status = 1;
+
+
+    pub fn get_exit_status(&self) -> u32 {
+        self.exit_status
+    }
+    pub fn clear_exit_status(&mut self) {
+        self.exit_status = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_exit_status(&mut self, v: u32) {
+        self.exit_status = v;
+    }
+
+    // .google.protobuf.Timestamp exited_at = 2;
+
+
+    pub fn get_exited_at(&self) -> &::protobuf::well_known_types::Timestamp {
+        self.exited_at.as_ref().unwrap_or_else(|| <::protobuf::well_known_types::Timestamp as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_exited_at(&mut self) {
+        self.exited_at.clear();
+    }
+
+    pub fn has_exited_at(&self) -> bool {
+        self.exited_at.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_exited_at(&mut self, v: ::protobuf::well_known_types::Timestamp) {
+        self.exited_at = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_exited_at(&mut self) -> &mut ::protobuf::well_known_types::Timestamp {
+        if self.exited_at.is_none() {
+            self.exited_at.set_default();
+        }
+        self.exited_at.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_exited_at(&mut self) -> ::protobuf::well_known_types::Timestamp {
+        self.exited_at.take().unwrap_or_else(|| ::protobuf::well_known_types::Timestamp::new())
+    }
+}
+
+impl ::protobuf::Message for WaitResponse {
+    fn is_initialized(&self) -> bool {
+        for v in &self.exited_at {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.exit_status = tmp;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.exited_at)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.exit_status != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.exit_status, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(ref v) = self.exited_at.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.exit_status != 0 {
+            os.write_uint32(1, self.exit_status)?;
+        }
+        if let Some(ref v) = self.exited_at.as_ref() {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> WaitResponse {
+        WaitResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "exit_status",
+                |m: &WaitResponse| { &m.exit_status },
+                |m: &mut WaitResponse| { &mut m.exit_status },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<::protobuf::well_known_types::Timestamp>>(
+                "exited_at",
+                |m: &WaitResponse| { &m.exited_at },
+                |m: &mut WaitResponse| { &mut m.exited_at },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<WaitResponse>(
+                "WaitResponse",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static WaitResponse {
+        static instance: ::protobuf::rt::LazyV2<WaitResponse> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(WaitResponse::new)
+    }
+}
+
+impl ::protobuf::Clear for WaitResponse {
+    fn clear(&mut self) {
+        self.exit_status = 0;
+        self.exited_at.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for WaitResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for WaitResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
+pub struct StatsRequest {
+    // message fields
+    pub id: ::std::string::String,
+    // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
+    pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a StatsRequest {
+    fn default() -> &'a StatsRequest {
+        <StatsRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl StatsRequest {
+    pub fn new() -> StatsRequest {
+        ::std::default::Default::default()
+    }
+
+    // string id = 1;
+
+
+    pub fn get_id(&self) -> &str {
+        &self.id
+    }
+    pub fn clear_id(&mut self) {
+        self.id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_id(&mut self, v: ::std::string::String) {
+        self.id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_id(&mut self) -> &mut ::std::string::String {
+        &mut self.id
+    }
+
+    // Take field
+    pub fn take_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.id, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for StatsRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.id)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.id);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.id.is_empty() {
+            os.write_string(1, &self.id)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> StatsRequest {
+        StatsRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "id",
+                |m: &StatsRequest| { &m.id },
+                |m: &mut StatsRequest| { &mut m.id },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<StatsRequest>(
+                "StatsRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static StatsRequest {
+        static instance: ::protobuf::rt::LazyV2<StatsRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(StatsRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for StatsRequest {
+    fn clear(&mut self) {
+        self.id.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for StatsRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for StatsRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
+pub struct StatsResponse {
+    // message fields
+    #[cfg_attr(feature = "with-serde", serde(default, with = "crate::json::any"))]
+    pub stats: ::protobuf::SingularPtrField<::protobuf::well_known_types::Any>,
+    // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
+    pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a StatsResponse {
+    fn default() -> &'a StatsResponse {
+        <StatsResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl StatsResponse {
+    pub fn new() -> StatsResponse {
+        ::std::default::Default::default()
+    }
+
+    // .google.protobuf.Any stats = 1;
+
+
+    pub fn get_stats(&self) -> &::protobuf::well_known_types::Any {
+        self.stats.as_ref().unwrap_or_else(|| <::protobuf::well_known_types::Any as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_stats(&mut self) {
+        self.stats.clear();
+    }
+
+    pub fn has_stats(&self) -> bool {
+        self.stats.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_stats(&mut self, v: ::protobuf::well_known_types::Any) {
+        self.stats = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_stats(&mut self) -> &mut ::protobuf::well_known_types::Any {
+        if self.stats.is_none() {
+            self.stats.set_default();
+        }
+        self.stats.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_stats(&mut self) -> ::protobuf::well_known_types::Any {
+        self.stats.take().unwrap_or_else(|| ::protobuf::well_known_types::Any::new())
+    }
+}
+
+impl ::protobuf::Message for StatsResponse {
+    fn is_initialized(&self) -> bool {
+        for v in &self.stats {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.stats)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.stats.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.stats.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> StatsResponse {
+        StatsResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<::protobuf::well_known_types::Any>>(
+                "stats",
+                |m: &StatsResponse| { &m.stats },
+                |m: &mut StatsResponse| { &mut m.stats },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<StatsResponse>(
+                "StatsResponse",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static StatsResponse {
+        static instance: ::protobuf::rt::LazyV2<StatsResponse> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(StatsResponse::new)
+    }
+}
+
+impl ::protobuf::Clear for StatsResponse {
+    fn clear(&mut self) {
+        self.stats.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for StatsResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for StatsResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
+pub struct ConnectRequest {
+    // message fields
+    pub id: ::std::string::String,
+    // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
+    pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ConnectRequest {
+    fn default() -> &'a ConnectRequest {
+        <ConnectRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ConnectRequest {
+    pub fn new() -> ConnectRequest {
+        ::std::default::Default::default()
+    }
+
+    // string id = 1;
+
+
+    pub fn get_id(&self) -> &str {
+        &self.id
+    }
+    pub fn clear_id(&mut self) {
+        self.id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_id(&mut self, v: ::std::string::String) {
+        self.id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_id(&mut self) -> &mut ::std::string::String {
+        &mut self.id
+    }
+
+    // Take field
+    pub fn take_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.id, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for ConnectRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.id)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.id);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.id.is_empty() {
+            os.write_string(1, &self.id)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ConnectRequest {
+        ConnectRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "id",
+                |m: &ConnectRequest| { &m.id },
+                |m: &mut ConnectRequest| { &mut m.id },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<ConnectRequest>(
+                "ConnectRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static ConnectRequest {
+        static instance: ::protobuf::rt::LazyV2<ConnectRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(ConnectRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for ConnectRequest {
+    fn clear(&mut self) {
+        self.id.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ConnectRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ConnectRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
+pub struct ConnectResponse {
+    // message fields
+    pub shim_pid: u32,
+    pub task_pid: u32,
+    pub version: ::std::string::String,
+    // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
+    pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ConnectResponse {
+    fn default() -> &'a ConnectResponse {
+        <ConnectResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ConnectResponse {
+    pub fn new() -> ConnectResponse {
+        ::std::default::Default::default()
+    }
+
+    // uint32 shim_pid = 1;
+
+
+    pub fn get_shim_pid(&self) -> u32 {
+        self.shim_pid
+    }
+    pub fn clear_shim_pid(&mut self) {
+        self.shim_pid = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_shim_pid(&mut self, v: u32) {
+        self.shim_pid = v;
+    }
+
+    // uint32 task_pid = 2;
+
+
+    pub fn get_task_pid(&self) -> u32 {
+        self.task_pid
+    }
+    pub fn clear_task_pid(&mut self) {
+        self.task_pid = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_task_pid(&mut self, v: u32) {
+        self.task_pid = v;
+    }
+
+    // string version = 3;
+
+
+    pub fn get_version(&self) -> &str {
+        &self.version
+    }
+    pub fn clear_version(&mut self) {
+        self.version.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_version(&mut self, v: ::std::string::String) {
+        self.version = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_version(&mut self) -> &mut ::std::string::String {
+        &mut self.version
+    }
+
+    // Take field
+    pub fn take_version(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.version, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for ConnectResponse {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.shim_pid = tmp;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.task_pid = tmp;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.version)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.shim_pid != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.shim_pid, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.task_pid != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.task_pid, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if !self.version.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.version);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.shim_pid != 0 {
+            os.write_uint32(1, self.shim_pid)?;
+        }
+        if self.task_pid != 0 {
+            os.write_uint32(2, self.task_pid)?;
+        }
+        if !self.version.is_empty() {
+            os.write_string(3, &self.version)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ConnectResponse {
+        ConnectResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "shim_pid",
+                |m: &ConnectResponse| { &m.shim_pid },
+                |m: &mut ConnectResponse| { &mut m.shim_pid },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "task_pid",
+                |m: &ConnectResponse| { &m.task_pid },
+                |m: &mut ConnectResponse| { &mut m.task_pid },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "version",
+                |m: &ConnectResponse| { &m.version },
+                |m: &mut ConnectResponse| { &mut m.version },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<ConnectResponse>(
+                "ConnectResponse",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static ConnectResponse {
+        static instance: ::protobuf::rt::LazyV2<ConnectResponse> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(ConnectResponse::new)
+    }
+}
+
+impl ::protobuf::Clear for ConnectResponse {
+    fn clear(&mut self) {
+        self.shim_pid = 0;
+        self.task_pid = 0;
+        self.version.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ConnectResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ConnectResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
+pub struct ShutdownRequest {
+    // message fields
+    pub id: ::std::string::String,
+    pub now: bool,
+    // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
+    pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ShutdownRequest {
+    fn default() -> &'a ShutdownRequest {
+        <ShutdownRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ShutdownRequest {
+    pub fn new() -> ShutdownRequest {
+        ::std::default::Default::default()
+    }
+
+    // string id = 1;
+
+
+    pub fn get_id(&self) -> &str {
+        &self.id
+    }
+    pub fn clear_id(&mut self) {
+        self.id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_id(&mut self, v: ::std::string::String) {
+        self.id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_id(&mut self) -> &mut ::std::string::String {
+        &mut self.id
+    }
+
+    // Take field
+    pub fn take_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.id, ::std::string::String::new())
+    }
+
+    // bool now = 2;
+
+
+    pub fn get_now(&self) -> bool {
+        self.now
+    }
+    pub fn clear_now(&mut self) {
+        self.now = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_now(&mut self, v: bool) {
+        self.now = v;
+    }
+}
+
+impl ::protobuf::Message for ShutdownRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.id)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.now = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.id);
+        }
+        if self.now != false {
+            my_size += 2;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.id.is_empty() {
+            os.write_string(1, &self.id)?;
+        }
+        if self.now != false {
+            os.write_bool(2, self.now)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ShutdownRequest {
+        ShutdownRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "id",
+                |m: &ShutdownRequest| { &m.id },
+                |m: &mut ShutdownRequest| { &mut m.id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "now",
+                |m: &ShutdownRequest| { &m.now },
+                |m: &mut ShutdownRequest| { &mut m.now },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<ShutdownRequest>(
+                "ShutdownRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static ShutdownRequest {
+        static instance: ::protobuf::rt::LazyV2<ShutdownRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(ShutdownRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for ShutdownRequest {
+    fn clear(&mut self) {
+        self.id.clear();
+        self.now = false;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ShutdownRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ShutdownRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
+pub struct PauseRequest {
+    // message fields
+    pub id: ::std::string::String,
+    // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
+    pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a PauseRequest {
+    fn default() -> &'a PauseRequest {
+        <PauseRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl PauseRequest {
+    pub fn new() -> PauseRequest {
+        ::std::default::Default::default()
+    }
+
+    // string id = 1;
+
+
+    pub fn get_id(&self) -> &str {
+        &self.id
+    }
+    pub fn clear_id(&mut self) {
+        self.id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_id(&mut self, v: ::std::string::String) {
+        self.id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_id(&mut self) -> &mut ::std::string::String {
+        &mut self.id
+    }
+
+    // Take field
+    pub fn take_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.id, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for PauseRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.id)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.id);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.id.is_empty() {
+            os.write_string(1, &self.id)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> PauseRequest {
+        PauseRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "id",
+                |m: &PauseRequest| { &m.id },
+                |m: &mut PauseRequest| { &mut m.id },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<PauseRequest>(
+                "PauseRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static PauseRequest {
+        static instance: ::protobuf::rt::LazyV2<PauseRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(PauseRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for PauseRequest {
+    fn clear(&mut self) {
+        self.id.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for PauseRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for PauseRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
+pub struct ResumeRequest {
+    // message fields
+    pub id: ::std::string::String,
+    // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
+    pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ResumeRequest {
+    fn default() -> &'a ResumeRequest {
+        <ResumeRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ResumeRequest {
+    pub fn new() -> ResumeRequest {
+        ::std::default::Default::default()
+    }
+
+    // string id = 1;
+
+
+    pub fn get_id(&self) -> &str {
+        &self.id
+    }
+    pub fn clear_id(&mut self) {
+        self.id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_id(&mut self, v: ::std::string::String) {
+        self.id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_id(&mut self) -> &mut ::std::string::String {
+        &mut self.id
+    }
+
+    // Take field
+    pub fn take_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.id, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for ResumeRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.id)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.id);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.id.is_empty() {
+            os.write_string(1, &self.id)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ResumeRequest {
+        ResumeRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "id",
+                |m: &ResumeRequest| { &m.id },
+                |m: &mut ResumeRequest| { &mut m.id },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<ResumeRequest>(
+                "ResumeRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static ResumeRequest {
+        static instance: ::protobuf::rt::LazyV2<ResumeRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(ResumeRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for ResumeRequest {
+    fn clear(&mut self) {
+        self.id.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ResumeRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ResumeRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n?github.com/containerd/containerd/api/runtime/task/v3/shim.proto\x12\
+    \x12containerd.task.v3\x1a\x19google/protobuf/any.proto\x1a\x1bgoogle/pr\
+    otobuf/empty.proto\x1a\x14gogoproto/gogo.proto\x1a\x1fgoogle/protobuf/ti\
+    mestamp.proto\x1a6github.com/containerd/containerd/api/types/mount.proto\
+    \x1a:github.com/containerd/containerd/api/types/task/task.protoX\x02\"\
+    \xe1\x02\n\x11CreateTaskRequest\x12\x10\n\x02id\x18\x01\x20\x01(\tR\x02i\
+    dB\0\x12\x18\n\x06bundle\x18\x02\x20\x01(\tR\x06bundleB\0\x121\n\x06root\
+    fs\x18\x03\x20\x03(\x0b2\x17.containerd.types.MountR\x06rootfsB\0\x12\
+    \x1c\n\x08terminal\x18\x04\x20\x01(\x08R\x08terminalB\0\x12\x16\n\x05std\
+    in\x18\x05\x20\x01(\tR\x05stdinB\0\x12\x18\n\x06stdout\x18\x06\x20\x01(\
+    \tR\x06stdoutB\0\x12\x18\n\x06stderr\x18\x07\x20\x01(\tR\x06stderrB\0\
+    \x12\x20\n\ncheckpoint\x18\x08\x20\x01(\tR\ncheckpointB\0\x12-\n\x11pare\
+    nt_checkpoint\x18\t\x20\x01(\tR\x10parentCheckpointB\0\x120\n\x07options\
+    \x18\n\x20\x01(\x0b2\x14.google.protobuf.AnyR\x07optionsB\0:\0\"*\n\x12C\
+    reateTaskResponse\x12\x12\n\x03pid\x18\x01\x20\x01(\rR\x03pidB\0:\0\">\n\
+    \rDeleteRequest\x12\x10\n\x02id\x18\x01\x20\x01(\tR\x02idB\0\x12\x19\n\
+    \x07exec_id\x18\x02\x20\x01(\tR\x06execIdB\0:\0\"\x8c\x01\n\x0eDeleteRes\
+    ponse\x12\x12\n\x03pid\x18\x01\x20\x01(\rR\x03pidB\0\x12!\n\x0bexit_stat\
+    us\x18\x02\x20\x01(\rR\nexitStatusB\0\x12A\n\texited_at\x18\x03\x20\x01(\
+    \x0b2\x1a.google.protobuf.TimestampR\x08exitedAtB\x08\x90\xdf\x1f\x01\
+    \xc8\xde\x1f\0:\0\"\xd9\x01\n\x12ExecProcessRequest\x12\x10\n\x02id\x18\
+    \x01\x20\x01(\tR\x02idB\0\x12\x19\n\x07exec_id\x18\x02\x20\x01(\tR\x06ex\
+    ecIdB\0\x12\x1c\n\x08terminal\x18\x03\x20\x01(\x08R\x08terminalB\0\x12\
+    \x16\n\x05stdin\x18\x04\x20\x01(\tR\x05stdinB\0\x12\x18\n\x06stdout\x18\
+    \x05\x20\x01(\tR\x06stdoutB\0\x12\x18\n\x06stderr\x18\x06\x20\x01(\tR\
+    \x06stderrB\0\x12*\n\x04spec\x18\x07\x20\x01(\x0b2\x14.google.protobuf.A\
+    nyR\x04specB\0:\0\"\x17\n\x13ExecProcessResponse:\0\"s\n\x10ResizePtyReq\
+    uest\x12\x10\n\x02id\x18\x01\x20\x01(\tR\x02idB\0\x12\x19\n\x07exec_id\
+    \x18\x02\x20\x01(\tR\x06execIdB\0\x12\x16\n\x05width\x18\x03\x20\x01(\rR\
+    \x05widthB\0\x12\x18\n\x06height\x18\x04\x20\x01(\rR\x06heightB\0:\0\"=\
+    \n\x0cStateRequest\x12\x10\n\x02id\x18\x01\x20\x01(\tR\x02idB\0\x12\x19\
+    \n\x07exec_id\x18\x02\x20\x01(\tR\x06execIdB\0:\0\"\xf3\x02\n\rStateResp\
+    onse\x12\x10\n\x02id\x18\x01\x20\x01(\tR\x02idB\0\x12\x18\n\x06bundle\
+    \x18\x02\x20\x01(\tR\x06bundleB\0\x12\x12\n\x03pid\x18\x03\x20\x01(\rR\
+    \x03pidB\0\x125\n\x06status\x18\x04\x20\x01(\x0e2\x1b.containerd.v1.type\
+    s.StatusR\x06statusB\0\x12\x16\n\x05stdin\x18\x05\x20\x01(\tR\x05stdinB\
+    \0\x12\x18\n\x06stdout\x18\x06\x20\x01(\tR\x06stdoutB\0\x12\x18\n\x06std\
+    err\x18\x07\x20\x01(\tR\x06stderrB\0\x12\x1c\n\x08terminal\x18\x08\x20\
+    \x01(\x08R\x08terminalB\0\x12!\n\x0bexit_status\x18\t\x20\x01(\rR\nexitS\
+    tatusB\0\x12A\n\texited_at\x18\n\x20\x01(\x0b2\x1a.google.protobuf.Times\
+    tampR\x08exitedAtB\x08\x90\xdf\x1f\x01\xc8\xde\x1f\0\x12\x19\n\x07exec_i\
+    d\x18\x0b\x20\x01(\tR\x06execIdB\0:\0\"j\n\x0bKillRequest\x12\x10\n\x02i\
+    d\x18\x01\x20\x01(\tR\x02idB\0\x12\x19\n\x07exec_id\x18\x02\x20\x01(\tR\
+    \x06execIdB\0\x12\x18\n\x06signal\x18\x03\x20\x01(\rR\x06signalB\0\x12\
+    \x12\n\x03all\x18\x04\x20\x01(\x08R\x03allB\0:\0\"W\n\x0eCloseIORequest\
+    \x12\x10\n\x02id\x18\x01\x20\x01(\tR\x02idB\0\x12\x19\n\x07exec_id\x18\
+    \x02\x20\x01(\tR\x06execIdB\0\x12\x16\n\x05stdin\x18\x03\x20\x01(\x08R\
+    \x05stdinB\0:\0\"!\n\x0bPidsRequest\x12\x10\n\x02id\x18\x01\x20\x01(\tR\
+    \x02idB\0:\0\"R\n\x0cPidsResponse\x12@\n\tprocesses\x18\x01\x20\x03(\x0b\
+    2\x20.containerd.v1.types.ProcessInfoR\tprocessesB\0:\0\"s\n\x15Checkpoi\
+    ntTaskRequest\x12\x10\n\x02id\x18\x01\x20\x01(\tR\x02idB\0\x12\x14\n\x04\
+    path\x18\x02\x20\x01(\tR\x04pathB\0\x120\n\x07options\x18\x03\x20\x01(\
+    \x0b2\x14.google.protobuf.AnyR\x07optionsB\0:\0\"\xfd\x01\n\x11UpdateTas\
+    kRequest\x12\x10\n\x02id\x18\x01\x20\x01(\tR\x02idB\0\x124\n\tresources\
+    \x18\x02\x20\x01(\x0b2\x14.google.protobuf.AnyR\tresourcesB\0\x12^\n\x0b\
+    annotations\x18\x03\x20\x03(\x0b2:.containerd.task.v3.UpdateTaskRequest.\
+    annotations_MapEntryR\x0bannotationsB\0\x1a>\n\x14annotations_MapEntry\
+    \x12\x0e\n\x03key\x18\x01(\tR\x03key\x12\x12\n\x05value\x18\x02(\tR\x05v\
+    alue:\x028\x01:\0\"=\n\x0cStartRequest\x12\x10\n\x02id\x18\x01\x20\x01(\
+    \tR\x02idB\0\x12\x19\n\x07exec_id\x18\x02\x20\x01(\tR\x06execIdB\0:\0\"%\
+    \n\rStartResponse\x12\x12\n\x03pid\x18\x01\x20\x01(\rR\x03pidB\0:\0\"<\n\
+    \x0bWaitRequest\x12\x10\n\x02id\x18\x01\x20\x01(\tR\x02idB\0\x12\x19\n\
+    \x07exec_id\x18\x02\x20\x01(\tR\x06execIdB\0:\0\"v\n\x0cWaitResponse\x12\
+    !\n\x0bexit_status\x18\x01\x20\x01(\rR\nexitStatusB\0\x12A\n\texited_at\
+    \x18\x02\x20\x01(\x0b2\x1a.google.protobuf.TimestampR\x08exitedAtB\x08\
+    \x90\xdf\x1f\x01\xc8\xde\x1f\0:\0\"\"\n\x0cStatsRequest\x12\x10\n\x02id\
+    \x18\x01\x20\x01(\tR\x02idB\0:\0\"?\n\rStatsResponse\x12,\n\x05stats\x18\
+    \x01\x20\x01(\x0b2\x14.google.protobuf.AnyR\x05statsB\0:\0\"$\n\x0eConne\
+    ctRequest\x12\x10\n\x02id\x18\x01\x20\x01(\tR\x02idB\0:\0\"i\n\x0fConnec\
+    tResponse\x12\x1b\n\x08shim_pid\x18\x01\x20\x01(\rR\x07shimPidB\0\x12\
+    \x1b\n\x08task_pid\x18\x02\x20\x01(\rR\x07taskPidB\0\x12\x1a\n\x07versio\
+    n\x18\x03\x20\x01(\tR\x07versionB\0:\0\"9\n\x0fShutdownRequest\x12\x10\n\
+    \x02id\x18\x01\x20\x01(\tR\x02idB\0\x12\x12\n\x03now\x18\x02\x20\x01(\
+    \x08R\x03nowB\0:\0\"\"\n\x0cPauseRequest\x12\x10\n\x02id\x18\x01\x20\x01\
+    (\tR\x02idB\0:\0\"#\n\rResumeRequest\x12\x10\n\x02id\x18\x01\x20\x01(\tR\
+    \x02idB\0:\0B\0b\x06proto3\
+";
+
+static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
+
+fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
+    ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+}
+
+pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    file_descriptor_proto_lazy.get(|| {
+        parse_descriptor_proto()
+    })
+}
@@ -0,0 +1,3 @@
+/// Reexport types::task::* to avoid manually editing other auto-generated source files.
+
+pub use crate::types::task::*;
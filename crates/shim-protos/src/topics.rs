@@ -14,7 +14,7 @@
    limitations under the License.
 */
 
-//! Task event topic typically used in shim implementations.
+//! Event topics of containerd, the task events are typically published by shim implementations.
 
 pub const TASK_CREATE_EVENT_TOPIC: &str = "/tasks/create";
 pub const TASK_START_EVENT_TOPIC: &str = "/tasks/start";
@@ -27,3 +27,21 @@ pub const TASK_PAUSED_EVENT_TOPIC: &str = "/tasks/paused";
 pub const TASK_RESUMED_EVENT_TOPIC: &str = "/tasks/resumed";
 pub const TASK_CHECKPOINTED_EVENT_TOPIC: &str = "/tasks/checkpointed";
 pub const TASK_UNKNOWN_TOPIC: &str = "/tasks/?";
+
+pub const CONTAINER_CREATE_EVENT_TOPIC: &str = "/containers/create";
+pub const CONTAINER_UPDATE_EVENT_TOPIC: &str = "/containers/update";
+pub const CONTAINER_DELETE_EVENT_TOPIC: &str = "/containers/delete";
+
+pub const CONTENT_DELETE_EVENT_TOPIC: &str = "/content/delete";
+
+pub const IMAGE_CREATE_EVENT_TOPIC: &str = "/images/create";
+pub const IMAGE_UPDATE_EVENT_TOPIC: &str = "/images/update";
+pub const IMAGE_DELETE_EVENT_TOPIC: &str = "/images/delete";
+
+pub const NAMESPACE_CREATE_EVENT_TOPIC: &str = "/namespaces/create";
+pub const NAMESPACE_UPDATE_EVENT_TOPIC: &str = "/namespaces/update";
+pub const NAMESPACE_DELETE_EVENT_TOPIC: &str = "/namespaces/delete";
+
+pub const SNAPSHOT_PREPARE_EVENT_TOPIC: &str = "/snapshot/prepare";
+pub const SNAPSHOT_COMMIT_EVENT_TOPIC: &str = "/snapshot/commit";
+pub const SNAPSHOT_REMOVE_EVENT_TOPIC: &str = "/snapshot/remove";
@@ -3,3 +3,4 @@
 pub mod mount;
 pub mod task;
 pub mod empty;
+pub mod platform;
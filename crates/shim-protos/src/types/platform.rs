@@ -0,0 +1,290 @@
+// This file is generated by rust-protobuf 2.27.1. Do not edit
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_imports)]
+#![allow(unused_results)]
+//! Generated file from `github.com/containerd/containerd/api/types/platform.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+// const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_2_27_1;
+
+#[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
+pub struct Platform {
+    // message fields
+    pub os: ::std::string::String,
+    pub architecture: ::std::string::String,
+    pub variant: ::std::string::String,
+    // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
+    pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a Platform {
+    fn default() -> &'a Platform {
+        <Platform as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Platform {
+    pub fn new() -> Platform {
+        ::std::default::Default::default()
+    }
+
+    // string os = 1;
+
+
+    pub fn get_os(&self) -> &str {
+        &self.os
+    }
+    pub fn clear_os(&mut self) {
+        self.os.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_os(&mut self, v: ::std::string::String) {
+        self.os = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_os(&mut self) -> &mut ::std::string::String {
+        &mut self.os
+    }
+
+    // Take field
+    pub fn take_os(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.os, ::std::string::String::new())
+    }
+
+    // string architecture = 2;
+
+
+    pub fn get_architecture(&self) -> &str {
+        &self.architecture
+    }
+    pub fn clear_architecture(&mut self) {
+        self.architecture.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_architecture(&mut self, v: ::std::string::String) {
+        self.architecture = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_architecture(&mut self) -> &mut ::std::string::String {
+        &mut self.architecture
+    }
+
+    // Take field
+    pub fn take_architecture(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.architecture, ::std::string::String::new())
+    }
+
+    // string variant = 3;
+
+
+    pub fn get_variant(&self) -> &str {
+        &self.variant
+    }
+    pub fn clear_variant(&mut self) {
+        self.variant.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_variant(&mut self, v: ::std::string::String) {
+        self.variant = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_variant(&mut self) -> &mut ::std::string::String {
+        &mut self.variant
+    }
+
+    // Take field
+    pub fn take_variant(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.variant, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for Platform {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.os)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.architecture)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.variant)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.os.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.os);
+        }
+        if !self.architecture.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.architecture);
+        }
+        if !self.variant.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.variant);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.os.is_empty() {
+            os.write_string(1, &self.os)?;
+        }
+        if !self.architecture.is_empty() {
+            os.write_string(2, &self.architecture)?;
+        }
+        if !self.variant.is_empty() {
+            os.write_string(3, &self.variant)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> Platform {
+        Platform::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "os",
+                |m: &Platform| { &m.os },
+                |m: &mut Platform| { &mut m.os },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "architecture",
+                |m: &Platform| { &m.architecture },
+                |m: &mut Platform| { &mut m.architecture },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "variant",
+                |m: &Platform| { &m.variant },
+                |m: &mut Platform| { &mut m.variant },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<Platform>(
+                "Platform",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static Platform {
+        static instance: ::protobuf::rt::LazyV2<Platform> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(Platform::new)
+    }
+}
+
+impl ::protobuf::Clear for Platform {
+    fn clear(&mut self) {
+        self.os.clear();
+        self.architecture.clear();
+        self.variant.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Platform {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Platform {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n9github.com/containerd/containerd/api/types/platform.proto\x12\x10cont\
+    ainerd.types\x1a\x14gogoproto/gogo.protoX\0\"f\n\x08Platform\x12\x16\n\
+    \x02os\x18\x01\x20\x01(\tR\x02osB\x06\xe2\xde\x1f\x02OS\x12$\n\x0carchit\
+    ecture\x18\x02\x20\x01(\tR\x0carchitectureB\0\x12\x1a\n\x07variant\x18\
+    \x03\x20\x01(\tR\x07variantB\0:\0B\0b\x06proto3\
+";
+
+static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
+
+fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
+    ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+}
+
+pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    file_descriptor_proto_lazy.get(|| {
+        parse_descriptor_proto()
+    })
+}
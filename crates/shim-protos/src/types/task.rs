@@ -24,6 +24,7 @@
 // const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_2_27_1;
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct Process {
     // message fields
     pub container_id: ::std::string::String,
@@ -35,9 +36,12 @@ pub struct Process {
     pub stderr: ::std::string::String,
     pub terminal: bool,
     pub exit_status: u32,
+    #[cfg_attr(feature = "with-serde", serde(default, with = "crate::json::timestamp"))]
     pub exited_at: ::protobuf::SingularPtrField<::protobuf::well_known_types::Timestamp>,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub cached_size: ::protobuf::CachedSize,
 }
 
@@ -544,12 +548,16 @@ impl ::protobuf::reflect::ProtobufValue for Process {
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct ProcessInfo {
     // message fields
     pub pid: u32,
+    #[cfg_attr(feature = "with-serde", serde(default, with = "crate::json::any"))]
     pub info: ::protobuf::SingularPtrField<::protobuf::well_known_types::Any>,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub cached_size: ::protobuf::CachedSize,
 }
 
@@ -753,6 +761,7 @@ impl ::protobuf::reflect::ProtobufValue for ProcessInfo {
 }
 
 #[derive(Clone,PartialEq,Eq,Debug,Hash)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub enum Status {
     UNKNOWN = 0,
     CREATED = 1,
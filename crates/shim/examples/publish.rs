@@ -14,6 +14,7 @@
    limitations under the License.
 */
 use std::env;
+use std::time::Duration;
 
 use containerd_shim::publisher::RemotePublisher;
 use containerd_shim::Context;
@@ -43,6 +44,9 @@ fn main() {
     publisher
         .publish(ctx, "/tasks/oom", "default", Box::new(event))
         .expect("Publish failed");
+    publisher
+        .flush(Duration::from_secs(5))
+        .expect("Flush failed");
 
     println!("Done");
 }
@@ -73,6 +77,10 @@ async fn main() {
         .publish(ctx, "/tasks/oom", "default", Box::new(event))
         .await
         .expect("Publish failed");
+    publisher
+        .flush(Duration::from_secs(5))
+        .await
+        .expect("Flush failed");
 
     println!("Done");
 }
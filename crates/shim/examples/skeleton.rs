@@ -23,7 +23,7 @@ mod skeleton {
     use log::info;
 
     use containerd_shim as shim;
-    use shim::synchronous::publisher::RemotePublisher;
+    use shim::synchronous::publisher::EventPublisher;
     use shim::{api, Config, DeleteResponse, ExitSignal, TtrpcContext, TtrpcResult};
 
     #[derive(Clone)]
@@ -54,7 +54,7 @@ mod skeleton {
             self.exit.wait();
         }
 
-        fn create_task_service(&self, _publisher: RemotePublisher) -> Self::T {
+        fn create_task_service(&self, _publisher: Box<dyn EventPublisher>) -> Self::T {
             self.clone()
         }
     }
@@ -20,7 +20,7 @@ use async_trait::async_trait;
 use log::info;
 
 use containerd_shim::asynchronous::{run, spawn, ExitSignal, Shim};
-use containerd_shim::publisher::RemotePublisher;
+use containerd_shim::publisher::EventPublisher;
 use containerd_shim::{Config, Error, StartOpts, TtrpcResult};
 use containerd_shim_protos::api;
 use containerd_shim_protos::api::DeleteResponse;
@@ -56,7 +56,7 @@ impl Shim for Service {
         self.exit.wait().await;
     }
 
-    async fn create_task_service(&self, _publisher: RemotePublisher) -> Self::T {
+    async fn create_task_service(&self, _publisher: Box<dyn EventPublisher>) -> Self::T {
         self.clone()
     }
 }
@@ -0,0 +1,197 @@
+/*
+   Copyright The containerd Authors.
+
+   Licensed under the Apache License, Version 2.0 (the "License");
+   you may not use this file except in compliance with the License.
+   You may obtain a copy of the License at
+
+       http://www.apache.org/licenses/LICENSE-2.0
+
+   Unless required by applicable law or agreed to in writing, software
+   distributed under the License is distributed on an "AS IS" BASIS,
+   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+   See the License for the specific language governing permissions and
+   limitations under the License.
+*/
+
+use std::time::{Duration, Instant};
+
+use oci_spec::runtime::{Process, Spec};
+
+use crate::api::*;
+use crate::client::{
+    create_request, decode_metrics, exec_request, ttrpc_address, write_spec, CallOpts, ExitStatus,
+    CONNECT_INTERVAL, DEFAULT_CONNECT_TIMEOUT,
+};
+use crate::error::{Error, Result};
+use crate::io::Stdio;
+use crate::protos::cgroups::metrics::Metrics;
+use crate::protos::shim_async::{Client, TaskClient};
+use crate::protos::ttrpc::context::Context;
+use crate::util::asyncify;
+
+/// A client of the task service of a shim.
+///
+/// Requests carry the namespace and the timeout set to the client, except
+/// [ShimClient::wait] which blocks until the process exits.
+#[derive(Clone)]
+pub struct ShimClient {
+    client: TaskClient,
+    opts: CallOpts,
+}
+
+impl ShimClient {
+    /// Connect to the shim at the address, which is `unix://`, `vsock://` or a socket path,
+    /// retrying for [DEFAULT_CONNECT_TIMEOUT] while the shim is not listening yet.
+    pub async fn connect(address: &str) -> Result<Self> {
+        Self::connect_timeout(address, DEFAULT_CONNECT_TIMEOUT).await
+    }
+
+    /// Connect to the shim at the address, retrying until the timeout.
+    pub async fn connect_timeout(address: &str, timeout: Duration) -> Result<Self> {
+        let address = ttrpc_address(address);
+        let deadline = Instant::now() + timeout;
+        let client = loop {
+            match Client::connect(&address) {
+                Ok(client) => break client,
+                Err(e) if Instant::now() >= deadline => {
+                    return Err(other!("connect {}: {}", address, e))
+                }
+                Err(_) => tokio::time::sleep(CONNECT_INTERVAL).await,
+            }
+        };
+        Ok(Self {
+            client: TaskClient::new(client),
+            opts: CallOpts::default(),
+        })
+    }
+
+    /// Send the requests in the namespace.
+    pub fn with_namespace(mut self, namespace: &str) -> Self {
+        self.opts.namespace = namespace.to_string();
+        self
+    }
+
+    /// Fail the requests not responded within the timeout.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.opts.timeout = Some(timeout);
+        self
+    }
+
+    /// The generated client, to send the requests without a typed method.
+    pub fn task_client(&self) -> &TaskClient {
+        &self.client
+    }
+
+    /// Context of the requests, with the namespace and the timeout.
+    pub fn context(&self) -> Context {
+        self.opts.context()
+    }
+
+    /// Create the task, writing the spec into the bundle if it is given, returns the pid.
+    pub async fn create(
+        &self,
+        id: &str,
+        bundle: &str,
+        spec: Option<&Spec>,
+        stdio: &Stdio,
+    ) -> Result<u32> {
+        let bundle_path = bundle.to_string();
+        let spec = spec.cloned();
+        asyncify(move || write_spec(&bundle_path, spec.as_ref())).await?;
+        let req = create_request(id, bundle, stdio);
+        let resp = self.client.create(self.context(), &req).await?;
+        Ok(resp.pid)
+    }
+
+    /// Start the task, or the exec process if `exec_id` is not empty, returns the pid.
+    pub async fn start(&self, id: &str, exec_id: &str) -> Result<u32> {
+        let req = StartRequest {
+            id: id.to_string(),
+            exec_id: exec_id.to_string(),
+            ..Default::default()
+        };
+        let resp = self.client.start(self.context(), &req).await?;
+        Ok(resp.pid)
+    }
+
+    /// Add an exec process to the task, to be started by [ShimClient::start].
+    pub async fn exec(
+        &self,
+        id: &str,
+        exec_id: &str,
+        process: &Process,
+        stdio: &Stdio,
+    ) -> Result<()> {
+        let req = exec_request(id, exec_id, process, stdio)?;
+        self.client.exec(self.context(), &req).await?;
+        Ok(())
+    }
+
+    pub async fn state(&self, id: &str, exec_id: &str) -> Result<StateResponse> {
+        let req = StateRequest {
+            id: id.to_string(),
+            exec_id: exec_id.to_string(),
+            ..Default::default()
+        };
+        Ok(self.client.state(self.context(), &req).await?)
+    }
+
+    pub async fn kill(&self, id: &str, exec_id: &str, signal: u32, all: bool) -> Result<()> {
+        let req = KillRequest {
+            id: id.to_string(),
+            exec_id: exec_id.to_string(),
+            signal,
+            all,
+            ..Default::default()
+        };
+        self.client.kill(self.context(), &req).await?;
+        Ok(())
+    }
+
+    /// Wait for the task to exit, regardless of the timeout of the client.
+    pub async fn wait(&self, id: &str) -> Result<ExitStatus> {
+        let req = WaitRequest {
+            id: id.to_string(),
+            ..Default::default()
+        };
+        let opts = CallOpts {
+            timeout: None,
+            ..self.opts.clone()
+        };
+        let resp = self.client.wait(opts.context(), &req).await?;
+        Ok(ExitStatus::new(resp.exit_status, resp.exited_at.as_ref()))
+    }
+
+    /// Delete the task, or the exec process if `exec_id` is not empty.
+    pub async fn delete(&self, id: &str, exec_id: &str) -> Result<ExitStatus> {
+        let req = DeleteRequest {
+            id: id.to_string(),
+            exec_id: exec_id.to_string(),
+            ..Default::default()
+        };
+        let resp = self.client.delete(self.context(), &req).await?;
+        Ok(ExitStatus::new(resp.exit_status, resp.exited_at.as_ref()))
+    }
+
+    /// Cgroups metrics of the task.
+    pub async fn stats(&self, id: &str) -> Result<Metrics> {
+        let req = StatsRequest {
+            id: id.to_string(),
+            ..Default::default()
+        };
+        let resp = self.client.stats(self.context(), &req).await?;
+        decode_metrics(resp.get_stats())
+    }
+
+    /// Shut down the shim, immediately if `now`, otherwise once it has no task.
+    pub async fn shutdown(&self, id: &str, now: bool) -> Result<()> {
+        let req = ShutdownRequest {
+            id: id.to_string(),
+            now,
+            ..Default::default()
+        };
+        self.client.shutdown(self.context(), &req).await?;
+        Ok(())
+    }
+}
@@ -15,10 +15,12 @@
 */
 
 use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::Arc;
 
 use async_trait::async_trait;
-use log::debug;
-use oci_spec::runtime::LinuxResources;
+use log::{debug, warn};
+use oci_spec::runtime::{LinuxNamespaceType, LinuxResources, Spec};
 use time::OffsetDateTime;
 use tokio::sync::oneshot::Receiver;
 
@@ -28,14 +30,28 @@ use containerd_shim_protos::api::{
 use containerd_shim_protos::cgroups::metrics::Metrics;
 
 use crate::asynchronous::processes::Process;
+use crate::asynchronous::util::read_spec;
 use crate::error::Result;
+use crate::util::Deadline;
 use crate::Error;
 
+/// A container of the [TaskService](crate::asynchronous::task::TaskService).
+///
+/// The methods running the runtime take the [Deadline] of the request, see there
+/// for what is expected once it passes.
 #[async_trait]
 pub trait Container {
-    async fn start(&mut self, exec_id: Option<&str>) -> Result<i32>;
+    /// Start the process, an exec that is half started at the deadline is rolled back,
+    /// while the init process is left for the client to delete.
+    async fn start(&mut self, exec_id: Option<&str>, deadline: Deadline) -> Result<i32>;
     async fn state(&self, exec_id: Option<&str>) -> Result<StateResponse>;
-    async fn kill(&mut self, exec_id: Option<&str>, signal: u32, all: bool) -> Result<()>;
+    async fn kill(
+        &mut self,
+        exec_id: Option<&str>,
+        signal: u32,
+        all: bool,
+        deadline: Deadline,
+    ) -> Result<()>;
     async fn wait_channel(&mut self, exec_id: Option<&str>) -> Result<Receiver<()>>;
     async fn get_exit_info(
         &self,
@@ -44,31 +60,151 @@ pub trait Container {
     async fn delete(
         &mut self,
         exec_id_opt: Option<&str>,
+        deadline: Deadline,
     ) -> Result<(i32, i32, Option<OffsetDateTime>)>;
-    async fn exec(&mut self, req: ExecProcessRequest) -> Result<()>;
+    async fn exec(&mut self, req: ExecProcessRequest, deadline: Deadline) -> Result<()>;
     async fn resize_pty(&mut self, exec_id: Option<&str>, height: u32, width: u32) -> Result<()>;
     async fn pid(&self) -> i32;
     async fn id(&self) -> String;
-    async fn update(&mut self, resources: &LinuxResources) -> Result<()>;
-    async fn stats(&self) -> Result<Metrics>;
-    async fn all_processes(&self) -> Result<Vec<ProcessInfo>>;
+    async fn update(&mut self, resources: &LinuxResources, deadline: Deadline) -> Result<()>;
+    async fn stats(&self, deadline: Deadline) -> Result<Metrics>;
+    async fn all_processes(&self, deadline: Deadline) -> Result<Vec<ProcessInfo>>;
+
+    /// Pids of the processes of the container by exec id, which is `None` for the init process.
+    async fn process_pids(&self) -> Vec<(Option<String>, i32)> {
+        vec![(None, self.pid().await)]
+    }
 }
 
 #[async_trait]
 pub trait ContainerFactory<C> {
-    async fn create(&self, ns: &str, req: &CreateTaskRequest) -> Result<C>;
+    /// Create the container, the work done is rolled back if the deadline passes.
+    async fn create(&self, ns: &str, req: &CreateTaskRequest, deadline: Deadline) -> Result<C>;
     async fn cleanup(&self, ns: &str, c: &C) -> Result<()>;
+
+    /// Persist the state of the container, so that it can be restored
+    /// by `restore` after the shim restarts.
+    async fn save(&self, _ns: &str, _c: &C) -> Result<()> {
+        Ok(())
+    }
+
+    /// Rebuild the containers that were saved by a previous shim instance.
+    async fn restore(&self, _ns: &str) -> Result<Vec<C>> {
+        Ok(vec![])
+    }
 }
 
 #[async_trait]
 pub trait ProcessFactory<E> {
-    async fn create(&self, req: &ExecProcessRequest) -> Result<E>;
+    async fn create(&self, req: &ExecProcessRequest, deadline: Deadline) -> Result<E>;
+}
+
+/// What the [ContainerHooks] are told about the container.
+#[derive(Clone, Debug)]
+pub struct HookContext {
+    pub id: String,
+    pub bundle: String,
+    /// `None` if the spec in the bundle can not be read.
+    pub spec: Option<Spec>,
+    /// Pid of the init process, 0 before it is created.
+    pub pid: i32,
+    /// Whether the init process has exited, its pid is not valid anymore then.
+    pub exited: bool,
+}
+
+impl HookContext {
+    /// Context of a container which is not created yet, with the spec read from the bundle.
+    pub async fn new(id: &str, bundle: &str, pid: i32) -> Self {
+        let spec = load_spec(id, bundle).await;
+        Self {
+            id: id.to_string(),
+            bundle: bundle.to_string(),
+            spec,
+            pid,
+            exited: false,
+        }
+    }
+
+    /// Path of the namespace of the container, that of the init process while it is running,
+    /// otherwise the one to join in the spec, `None` if it is yet to be created.
+    ///
+    /// Once the init process has exited, a namespace created for the container is gone
+    /// and `None` is returned, the one joined by the spec is still returned.
+    pub fn namespace_path(&self, typ: LinuxNamespaceType) -> Option<PathBuf> {
+        if self.pid > 0 && !self.exited {
+            let name = match typ {
+                LinuxNamespaceType::Mount => "mnt",
+                LinuxNamespaceType::Cgroup => "cgroup",
+                LinuxNamespaceType::Uts => "uts",
+                LinuxNamespaceType::Ipc => "ipc",
+                LinuxNamespaceType::User => "user",
+                LinuxNamespaceType::Pid => "pid",
+                LinuxNamespaceType::Network => "net",
+            };
+            return Some(PathBuf::from(format!("/proc/{}/ns/{}", self.pid, name)));
+        }
+        self.spec
+            .as_ref()?
+            .linux()
+            .as_ref()?
+            .namespaces()
+            .as_ref()?
+            .iter()
+            .find(|ns| ns.typ() == typ)
+            .and_then(|ns| ns.path().clone())
+    }
+}
+
+/// Callbacks around the lifecycle of a container, to set up and tear down what the
+/// runtime doesn't, such as the network interfaces or the devices of the container.
+///
+/// An error of a `pre_` hook or of `post_create` fails the operation, the other hooks
+/// can not undo what is done, so they are expected to log their failures.
+#[async_trait]
+pub trait ContainerHooks: Send + Sync {
+    /// Called before the container is created, with the bundle and the spec.
+    async fn pre_create(&self, _ctx: &HookContext) -> Result<()> {
+        Ok(())
+    }
+
+    /// Called when the init process is created, before it starts to run.
+    async fn post_create(&self, _ctx: &HookContext) -> Result<()> {
+        Ok(())
+    }
+
+    async fn pre_start(&self, _ctx: &HookContext) -> Result<()> {
+        Ok(())
+    }
+
+    async fn post_start(&self, _ctx: &HookContext) {}
+
+    /// Called when the init process exited.
+    async fn post_exit(&self, _ctx: &HookContext, _exit_code: i32) {}
+
+    /// Called before the container is deleted.
+    async fn pre_delete(&self, _ctx: &HookContext) {}
+}
+
+async fn load_spec(id: &str, bundle: &str) -> Option<Spec> {
+    read_spec(bundle)
+        .await
+        .map_err(|e| warn!("failed to read spec of container {}: {}", id, e))
+        .ok()
+}
+
+/// The hooks of a container, with the spec they are told about, which is read once.
+struct Hooks {
+    hooks: Arc<dyn ContainerHooks>,
+    spec: Option<Spec>,
 }
 
 /// ContainerTemplate is a template struct to implement Container,
 /// most of the methods can be delegated to either init process or exec process.
 /// that's why we provides a ContainerTemplate struct,
 /// library users only need to implements Process for their own.
+///
+/// It is built by [ContainerTemplate::new], and [ContainerTemplate::with_hooks]
+/// if the container has [ContainerHooks].
 pub struct ContainerTemplate<T, E, P> {
     /// container id
     pub id: String,
@@ -80,6 +216,29 @@ pub struct ContainerTemplate<T, E, P> {
     pub process_factory: P,
     /// exec processes of this container
     pub processes: HashMap<String, E>,
+    /// hooks called when the container is started, exited and deleted
+    hooks: Option<Hooks>,
+}
+
+impl<T, E, P> ContainerTemplate<T, E, P> {
+    pub fn new(id: &str, bundle: &str, init: T, process_factory: P) -> Self {
+        Self {
+            id: id.to_string(),
+            bundle: bundle.to_string(),
+            init,
+            process_factory,
+            processes: HashMap::new(),
+            hooks: None,
+        }
+    }
+
+    /// Call the hooks around the lifecycle of the container,
+    /// the spec they are told about is read from the bundle here.
+    pub async fn with_hooks(mut self, hooks: Arc<dyn ContainerHooks>) -> Self {
+        let spec = load_spec(&self.id, &self.bundle).await;
+        self.hooks = Some(Hooks { hooks, spec });
+        self
+    }
 }
 
 #[async_trait]
@@ -89,10 +248,18 @@ where
     E: Process + Send + Sync,
     P: ProcessFactory<E> + Send + Sync,
 {
-    async fn start(&mut self, exec_id: Option<&str>) -> Result<i32> {
+    async fn start(&mut self, exec_id: Option<&str>, deadline: Deadline) -> Result<i32> {
+        let hooks = self.hooks().filter(|_| exec_id.is_none());
+        if let Some(hooks) = &hooks {
+            hooks.pre_start(&self.hook_context().await).await?;
+        }
         let process = self.get_mut_process(exec_id)?;
-        process.start().await?;
-        Ok(process.pid().await)
+        process.start(deadline).await?;
+        let pid = process.pid().await;
+        if let Some(hooks) = &hooks {
+            hooks.post_start(&self.hook_context().await).await;
+        }
+        Ok(pid)
     }
 
     async fn state(&self, exec_id: Option<&str>) -> Result<StateResponse> {
@@ -103,9 +270,15 @@ where
         Ok(resp)
     }
 
-    async fn kill(&mut self, exec_id: Option<&str>, signal: u32, all: bool) -> Result<()> {
+    async fn kill(
+        &mut self,
+        exec_id: Option<&str>,
+        signal: u32,
+        all: bool,
+        deadline: Deadline,
+    ) -> Result<()> {
         let process = self.get_mut_process(exec_id)?;
-        process.kill(signal, all).await
+        process.kill(signal, all, deadline).await
     }
 
     async fn wait_channel(&mut self, exec_id: Option<&str>) -> Result<Receiver<()>> {
@@ -128,11 +301,15 @@ where
     async fn delete(
         &mut self,
         exec_id_opt: Option<&str>,
+        deadline: Deadline,
     ) -> Result<(i32, i32, Option<OffsetDateTime>)> {
         let (pid, code, exited_at) = self.get_exit_info(exec_id_opt).await?;
+        if let (Some(hooks), None) = (self.hooks(), exec_id_opt) {
+            hooks.pre_delete(&self.hook_context().await).await;
+        }
         let process = self.get_mut_process(exec_id_opt);
         match process {
-            Ok(p) => p.delete().await?,
+            Ok(p) => p.delete(deadline).await?,
             Err(Error::NotFoundError(_)) => return Ok((pid, code, exited_at)),
             Err(e) => return Err(e),
         }
@@ -142,9 +319,9 @@ where
         Ok((pid, code, exited_at))
     }
 
-    async fn exec(&mut self, req: ExecProcessRequest) -> Result<()> {
+    async fn exec(&mut self, req: ExecProcessRequest, deadline: Deadline) -> Result<()> {
         let exec_id = req.exec_id.to_string();
-        let exec_process = self.process_factory.create(&req).await?;
+        let exec_process = self.process_factory.create(&req, deadline).await?;
         self.processes.insert(exec_id, exec_process);
         Ok(())
     }
@@ -163,27 +340,35 @@ where
     }
 
     #[cfg(target_os = "linux")]
-    async fn update(&mut self, resources: &LinuxResources) -> Result<()> {
-        self.init.update(resources).await
+    async fn update(&mut self, resources: &LinuxResources, deadline: Deadline) -> Result<()> {
+        self.init.update(resources, deadline).await
     }
 
     #[cfg(not(target_os = "linux"))]
-    async fn update(&mut self, _resources: &LinuxResources) -> Result<()> {
+    async fn update(&mut self, _resources: &LinuxResources, _deadline: Deadline) -> Result<()> {
         Err(Error::Unimplemented("update".to_string()))
     }
 
     #[cfg(target_os = "linux")]
-    async fn stats(&self) -> Result<Metrics> {
-        self.init.stats().await
+    async fn stats(&self, deadline: Deadline) -> Result<Metrics> {
+        self.init.stats(deadline).await
     }
 
     #[cfg(not(target_os = "linux"))]
-    async fn stats(&self) -> Result<Metrics> {
+    async fn stats(&self, _deadline: Deadline) -> Result<Metrics> {
         Err(Error::Unimplemented("stats".to_string()))
     }
 
-    async fn all_processes(&self) -> Result<Vec<ProcessInfo>> {
-        self.init.ps().await
+    async fn all_processes(&self, deadline: Deadline) -> Result<Vec<ProcessInfo>> {
+        self.init.ps(deadline).await
+    }
+
+    async fn process_pids(&self) -> Vec<(Option<String>, i32)> {
+        let mut pids = vec![(None, self.init.pid().await)];
+        for (exec_id, p) in self.processes.iter() {
+            pids.push((Some(exec_id.to_string()), p.pid().await));
+        }
+        pids
     }
 }
 
@@ -192,6 +377,35 @@ where
     T: Process + Send + Sync,
     E: Process + Send + Sync,
 {
+    pub async fn hook_context(&self) -> HookContext {
+        HookContext {
+            id: self.id.to_string(),
+            bundle: self.bundle.to_string(),
+            spec: self.hooks.as_ref().and_then(|h| h.spec.clone()),
+            pid: self.init.pid().await,
+            exited: self.init.exited_at().await.is_some(),
+        }
+    }
+
+    fn hooks(&self) -> Option<Arc<dyn ContainerHooks>> {
+        self.hooks.as_ref().map(|h| h.hooks.clone())
+    }
+
+    /// Call the `post_create` hook, once the container is created by the factory.
+    pub async fn post_create(&self) -> Result<()> {
+        match self.hooks() {
+            Some(hooks) => hooks.post_create(&self.hook_context().await).await,
+            None => Ok(()),
+        }
+    }
+
+    /// Call the `post_exit` hook, once the exit of the init process is set.
+    pub async fn post_exit(&self, exit_code: i32) {
+        if let Some(hooks) = self.hooks() {
+            hooks.post_exit(&self.hook_context().await, exit_code).await;
+        }
+    }
+
     pub fn get_process(&self, exec_id: Option<&str>) -> Result<&(dyn Process + Send + Sync)> {
         match exec_id {
             Some(exec_id) => {
@@ -219,3 +433,62 @@ where
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use oci_spec::runtime::{LinuxBuilder, LinuxNamespaceBuilder, SpecBuilder};
+
+    use super::*;
+
+    #[test]
+    fn test_namespace_path() {
+        let namespaces = vec![
+            LinuxNamespaceBuilder::default()
+                .typ(LinuxNamespaceType::Network)
+                .path("/var/run/netns/test")
+                .build()
+                .unwrap(),
+            LinuxNamespaceBuilder::default()
+                .typ(LinuxNamespaceType::Pid)
+                .build()
+                .unwrap(),
+        ];
+        let linux = LinuxBuilder::default()
+            .namespaces(namespaces)
+            .build()
+            .unwrap();
+        let spec = SpecBuilder::default().linux(linux).build().unwrap();
+        let mut ctx = HookContext {
+            id: "test".to_string(),
+            bundle: "/bundle".to_string(),
+            spec: Some(spec),
+            pid: 0,
+            exited: false,
+        };
+
+        // before the init process is created
+        assert_eq!(
+            ctx.namespace_path(LinuxNamespaceType::Network),
+            Some(PathBuf::from("/var/run/netns/test"))
+        );
+        assert_eq!(ctx.namespace_path(LinuxNamespaceType::Pid), None);
+
+        ctx.pid = 100;
+        assert_eq!(
+            ctx.namespace_path(LinuxNamespaceType::Network),
+            Some(PathBuf::from("/proc/100/ns/net"))
+        );
+        assert_eq!(
+            ctx.namespace_path(LinuxNamespaceType::Pid),
+            Some(PathBuf::from("/proc/100/ns/pid"))
+        );
+
+        // the namespaces of the exited init process are gone
+        ctx.exited = true;
+        assert_eq!(
+            ctx.namespace_path(LinuxNamespaceType::Network),
+            Some(PathBuf::from("/var/run/netns/test"))
+        );
+        assert_eq!(ctx.namespace_path(LinuxNamespaceType::Pid), None);
+    }
+}
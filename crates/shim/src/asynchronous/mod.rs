@@ -14,6 +14,7 @@
    limitations under the License.
 */
 
+use std::future::Future;
 use std::os::unix::fs::FileTypeExt;
 use std::os::unix::io::AsRawFd;
 use std::os::unix::net::UnixListener;
@@ -35,24 +36,33 @@ use tokio::sync::Notify;
 
 use containerd_shim_protos::api::DeleteResponse;
 use containerd_shim_protos::protobuf::Message;
-use containerd_shim_protos::shim_async::{create_task, Client, Task};
+use containerd_shim_protos::shim_async::{
+    create_sandbox, create_task, create_task_v3, Client, Sandbox, Task,
+};
 use containerd_shim_protos::ttrpc::r#async::Server;
 
 use crate::asynchronous::monitor::monitor_notify_by_pid;
-use crate::asynchronous::publisher::RemotePublisher;
+use crate::asynchronous::publisher::{BinaryPublisher, EventPublisher, RemotePublisher};
+use crate::asynchronous::task_v3::TaskV3Adapter;
 use crate::error::Error;
 use crate::error::Result;
+use crate::publish::EXIT_FLUSH_TIMEOUT;
 use crate::util::{asyncify, read_file_to_str, write_str_to_file};
 use crate::{
-    args, logger, parse_sockaddr, reap, socket_address, Config, StartOpts, SOCKET_FD, TTRPC_ADDRESS,
+    args, bootstrap_output, logger, parse_sockaddr, reap, remove_legacy_sockets, socket_address,
+    Config, StartOpts, SOCKET_FD, SOCKET_ROOT, TTRPC_ADDRESS,
 };
 
+pub mod client;
 pub mod console;
 pub mod container;
+pub mod layer;
 pub mod monitor;
 pub mod processes;
 pub mod publisher;
+pub mod sync_task;
 pub mod task;
+pub mod task_v3;
 pub mod util;
 
 /// Asynchronous Main shim interface that must be implemented by all async shims.
@@ -88,7 +98,13 @@ pub trait Shim {
     async fn wait(&mut self);
 
     /// Create the task service object asynchronously.
-    async fn create_task_service(&self, publisher: RemotePublisher) -> Self::T;
+    async fn create_task_service(&self, publisher: Box<dyn EventPublisher>) -> Self::T;
+
+    /// Create the sandbox service object asynchronously, which is registered beside
+    /// the task service if the shim owns the lifecycle of the sandbox, e.g. a pod VM.
+    async fn create_sandbox_service(&self) -> Option<Box<dyn Sandbox + Send + Sync>> {
+        None
+    }
 }
 
 /// Async Shim entry point that must be invoked from tokio `main`.
@@ -102,6 +118,25 @@ where
     }
 }
 
+/// Async Shim entry point for a blocking `main`, the shim is run by a tokio runtime of its own.
+///
+/// Unlike [run], the error of the shim is returned, for `main` to report it and exit.
+pub fn run_blocking<T>(runtime_id: &str, opts: Option<Config>) -> Result<()>
+where
+    T: Shim + Send + Sync + 'static,
+{
+    block_on(bootstrap::<T>(runtime_id, opts))?
+}
+
+/// Run the future to completion on a multi-thread tokio runtime of its own.
+fn block_on<F: Future>(fut: F) -> Result<F::Output> {
+    let runtime = tokio::runtime::Builder::new_multi_thread()
+        .enable_all()
+        .build()
+        .map_err(io_error!(e, "create tokio runtime"))?;
+    Ok(runtime.block_on(fut))
+}
+
 async fn bootstrap<T>(runtime_id: &str, opts: Option<Config>) -> Result<()>
 where
     T: Shim + Send + Sync + 'static,
@@ -110,7 +145,7 @@ where
     let os_args: Vec<_> = env::args_os().collect();
     let flags = args::parse(&os_args[1..])?;
 
-    let ttrpc_address = env::var(TTRPC_ADDRESS)?;
+    let ttrpc_address = env::var(TTRPC_ADDRESS).unwrap_or_default();
     // Create shim instance
     let mut config = opts.unwrap_or_default();
 
@@ -135,9 +170,10 @@ where
             };
 
             let address = shim.start_shim(args).await?;
+            let output = bootstrap_output(&address, &config)?;
             let mut stdout = tokio::io::stdout();
             stdout
-                .write_all(address.as_bytes())
+                .write_all(output.as_bytes())
                 .await
                 .map_err(io_error!(e, "write stdout"))?;
             // containerd occasionally read an empty string without flushing the stdout
@@ -149,6 +185,10 @@ where
                 handle_signals(signals).await;
             });
             let response = shim.delete_shim().await?;
+            // shims started by a previous release listened on the legacy addresses
+            asyncify(|| remove_legacy_sockets(Path::new(SOCKET_ROOT)))
+                .await
+                .unwrap_or_else(|e| warn!("failed to remove legacy sockets: {}", e));
             let resp_bytes = response.write_to_bytes()?;
             tokio::io::stdout()
                 .write_all(resp_bytes.as_slice())
@@ -162,10 +202,18 @@ where
                 logger::init(flags.debug)?;
             }
 
-            let publisher = RemotePublisher::new(&ttrpc_address).await?;
-            let task = shim.create_task_service(publisher).await;
-            let task_service = create_task(Arc::new(Box::new(task)));
-            let mut server = Server::new().register_service(task_service);
+            let (publisher, remote) = new_publisher(&ttrpc_address, &flags, &config).await?;
+            let task: Box<dyn Task + Send + Sync> =
+                Box::new(shim.create_task_service(publisher).await);
+            let task = Arc::new(task);
+            // Serve the task API v3 by the same task service for newer containerd releases.
+            let task_v3 = TaskV3Adapter::new(task.clone());
+            let mut server = Server::new()
+                .register_service(create_task(task))
+                .register_service(create_task_v3(Arc::new(Box::new(task_v3))));
+            if let Some(sandbox) = shim.create_sandbox_service().await {
+                server = server.register_service(create_sandbox(Arc::new(sandbox)));
+            }
             server = server.add_listener(SOCKET_FD)?;
             server = server.set_domain_unix();
             server.start().await?;
@@ -178,6 +226,13 @@ where
 
             info!("Shutting down shim instance");
             server.shutdown().await.unwrap_or_default();
+            if let Some(remote) = remote {
+                remote
+                    .flush(EXIT_FLUSH_TIMEOUT)
+                    .await
+                    .unwrap_or_else(|e| warn!("failed to flush events: {}", e));
+            }
+            config.publisher.remove_spill_file();
 
             // NOTE: If the shim server is down(like oom killer), the address
             // socket might be leaking.
@@ -276,6 +331,9 @@ pub async fn spawn(opts: StartOpts, grouping: &str, vars: Vec<(&str, &str)>) ->
             parent_fd: listener.as_raw_fd(),
             child_fd: SOCKET_FD,
         }])?;
+    if !opts.publish_binary.is_empty() {
+        command.args(&["-publish-binary", &opts.publish_binary]);
+    }
     if opts.debug {
         command.arg("-debug");
     }
@@ -288,6 +346,35 @@ pub async fn spawn(opts: StartOpts, grouping: &str, vars: Vec<(&str, &str)>) ->
     Ok(address)
 }
 
+/// Connect to the TTRPC address of containerd to publish events, or fall back to
+/// run the publish binary if containerd doesn't serve events over TTRPC.
+/// The remote publisher is also returned to flush its queue before the shim exits.
+async fn new_publisher(
+    ttrpc_address: &str,
+    flags: &args::Flags,
+    config: &Config,
+) -> Result<(Box<dyn EventPublisher>, Option<RemotePublisher>)> {
+    if !ttrpc_address.is_empty() {
+        match RemotePublisher::with_config(ttrpc_address, config.publisher.clone()).await {
+            Ok(p) => return Ok((Box::new(p.clone()), Some(p))),
+            Err(e) if flags.publish_binary.is_empty() => return Err(e),
+            Err(e) => warn!(
+                "failed to connect to {}, publish events by {}: {}",
+                ttrpc_address, flags.publish_binary, e
+            ),
+        }
+    }
+    if flags.publish_binary.is_empty() {
+        return Err(Error::InvalidArgument(format!(
+            "neither {} nor publish binary is given",
+            TTRPC_ADDRESS
+        )));
+    }
+    let publisher =
+        BinaryPublisher::new(&flags.publish_binary, &flags.address).with_reaper(!config.no_reaper);
+    Ok((Box::new(publisher), None))
+}
+
 fn setup_signals_tokio(config: &Config) -> Signals {
     if config.no_reaper {
         Signals::new(&[SIGTERM, SIGINT, SIGPIPE]).expect("new signal failed")
@@ -316,11 +403,7 @@ async fn handle_signals(signals: Signals) {
                 .await;
 
                 if let Ok((res_pid, status)) = result {
-                    monitor_notify_by_pid(res_pid, status)
-                        .await
-                        .unwrap_or_else(|e| {
-                            error!("failed to send pid exit event {}", e);
-                        })
+                    notify_exit(res_pid, status).await;
                 } else {
                     break;
                 }
@@ -330,6 +413,17 @@ async fn handle_signals(signals: Signals) {
     }
 }
 
+/// Notify the exit of a reaped process to the async monitor, which owns the exits in an async
+/// shim. A sync task served by the [SyncTaskAdapter](sync_task::SyncTaskAdapter) gets them
+/// forwarded by the adapter.
+async fn notify_exit(pid: pid_t, status: c_int) {
+    monitor_notify_by_pid(pid, status)
+        .await
+        .unwrap_or_else(|e| {
+            error!("failed to send pid exit event {}", e);
+        });
+}
+
 async fn remove_socket_silently(address: &str) {
     remove_socket(address)
         .await
@@ -378,8 +472,46 @@ async fn wait_socket_working(address: &str, interval_in_ms: u64, count: u32) ->
 #[cfg(test)]
 mod tests {
     use std::sync::Arc;
+    use std::time::Duration;
+
+    use crate::asynchronous::monitor::{monitor_subscribe, monitor_unsubscribe};
+    use crate::asynchronous::{block_on, notify_exit, start_listener, ExitSignal};
+    use crate::monitor::{ExitEvent, Subject, Topic};
+
+    #[test]
+    fn test_block_on() {
+        // a blocking main has no runtime, the one of block_on drives the tasks, blocking
+        // tasks and timers of the shim
+        let n = block_on(async {
+            let spawned = tokio::spawn(async { 1 }).await.unwrap();
+            let blocking = tokio::task::spawn_blocking(|| 2).await.unwrap();
+            tokio::time::sleep(Duration::from_millis(1)).await;
+            spawned + blocking
+        })
+        .unwrap();
+        assert_eq!(n, 3);
+    }
 
-    use crate::asynchronous::{start_listener, ExitSignal};
+    fn is_exit_of(e: &ExitEvent, pid: i32) -> bool {
+        matches!(e.subject, Subject::Pid(p) if p == pid)
+    }
+
+    #[tokio::test]
+    async fn test_notify_exit() {
+        let pid = i32::MAX - 1;
+        let mut s = monitor_subscribe(Topic::Pid).await.unwrap();
+
+        notify_exit(pid, 3).await;
+
+        loop {
+            let e = s.rx.recv().await.unwrap();
+            if is_exit_of(&e, pid) {
+                assert_eq!(e.exit_code, 3);
+                break;
+            }
+        }
+        monitor_unsubscribe(s.id).await.unwrap();
+    }
 
     #[tokio::test]
     async fn test_exit_signal() {
@@ -27,34 +27,46 @@ use containerd_shim_protos::cgroups::metrics::Metrics;
 use containerd_shim_protos::protobuf::well_known_types::Timestamp;
 
 use crate::io::Stdio;
-use crate::util::asyncify;
+use crate::util::{asyncify, Deadline};
 use crate::{ioctl_set_winsz, Console};
 
 #[async_trait]
 pub trait Process {
-    async fn start(&mut self) -> crate::Result<()>;
+    async fn start(&mut self, deadline: Deadline) -> crate::Result<()>;
     async fn set_exited(&mut self, exit_code: i32);
     async fn pid(&self) -> i32;
     async fn state(&self) -> crate::Result<StateResponse>;
-    async fn kill(&mut self, signal: u32, all: bool) -> crate::Result<()>;
-    async fn delete(&mut self) -> crate::Result<()>;
+    async fn kill(&mut self, signal: u32, all: bool, deadline: Deadline) -> crate::Result<()>;
+    async fn delete(&mut self, deadline: Deadline) -> crate::Result<()>;
     async fn wait_channel(&mut self) -> crate::Result<Receiver<()>>;
     async fn exit_code(&self) -> i32;
     async fn exited_at(&self) -> Option<OffsetDateTime>;
     async fn resize_pty(&mut self, height: u32, width: u32) -> crate::Result<()>;
-    async fn update(&mut self, resources: &LinuxResources) -> crate::Result<()>;
-    async fn stats(&self) -> crate::Result<Metrics>;
-    async fn ps(&self) -> crate::Result<Vec<ProcessInfo>>;
+    async fn update(&mut self, resources: &LinuxResources, deadline: Deadline)
+        -> crate::Result<()>;
+    async fn stats(&self, deadline: Deadline) -> crate::Result<Metrics>;
+    async fn ps(&self, deadline: Deadline) -> crate::Result<Vec<ProcessInfo>>;
 }
 
 #[async_trait]
 pub trait ProcessLifecycle<P: Process> {
-    async fn start(&self, p: &mut P) -> crate::Result<()>;
-    async fn kill(&self, p: &mut P, signal: u32, all: bool) -> crate::Result<()>;
-    async fn delete(&self, p: &mut P) -> crate::Result<()>;
-    async fn update(&self, p: &mut P, resources: &LinuxResources) -> crate::Result<()>;
-    async fn stats(&self, p: &P) -> crate::Result<Metrics>;
-    async fn ps(&self, p: &P) -> crate::Result<Vec<ProcessInfo>>;
+    async fn start(&self, p: &mut P, deadline: Deadline) -> crate::Result<()>;
+    async fn kill(
+        &self,
+        p: &mut P,
+        signal: u32,
+        all: bool,
+        deadline: Deadline,
+    ) -> crate::Result<()>;
+    async fn delete(&self, p: &mut P, deadline: Deadline) -> crate::Result<()>;
+    async fn update(
+        &self,
+        p: &mut P,
+        resources: &LinuxResources,
+        deadline: Deadline,
+    ) -> crate::Result<()>;
+    async fn stats(&self, p: &P, deadline: Deadline) -> crate::Result<Metrics>;
+    async fn ps(&self, p: &P, deadline: Deadline) -> crate::Result<Vec<ProcessInfo>>;
 }
 
 pub struct ProcessTemplate<S> {
@@ -90,8 +102,8 @@ impl<S> Process for ProcessTemplate<S>
 where
     S: ProcessLifecycle<Self> + Sync + Send,
 {
-    async fn start(&mut self) -> crate::Result<()> {
-        self.lifecycle.clone().start(self).await?;
+    async fn start(&mut self, deadline: Deadline) -> crate::Result<()> {
+        self.lifecycle.clone().start(self, deadline).await?;
         Ok(())
     }
 
@@ -126,12 +138,15 @@ where
         Ok(resp)
     }
 
-    async fn kill(&mut self, signal: u32, all: bool) -> crate::Result<()> {
-        self.lifecycle.clone().kill(self, signal, all).await
+    async fn kill(&mut self, signal: u32, all: bool, deadline: Deadline) -> crate::Result<()> {
+        self.lifecycle
+            .clone()
+            .kill(self, signal, all, deadline)
+            .await
     }
 
-    async fn delete(&mut self) -> crate::Result<()> {
-        self.lifecycle.clone().delete(self).await
+    async fn delete(&mut self, deadline: Deadline) -> crate::Result<()> {
+        self.lifecycle.clone().delete(self, deadline).await
     }
 
     async fn wait_channel(&mut self) -> crate::Result<Receiver<()>> {
@@ -167,15 +182,22 @@ where
         Ok(())
     }
 
-    async fn update(&mut self, resources: &LinuxResources) -> crate::Result<()> {
-        self.lifecycle.clone().update(self, resources).await
+    async fn update(
+        &mut self,
+        resources: &LinuxResources,
+        deadline: Deadline,
+    ) -> crate::Result<()> {
+        self.lifecycle
+            .clone()
+            .update(self, resources, deadline)
+            .await
     }
 
-    async fn stats(&self) -> crate::Result<Metrics> {
-        self.lifecycle.stats(self).await
+    async fn stats(&self, deadline: Deadline) -> crate::Result<Metrics> {
+        self.lifecycle.stats(self, deadline).await
     }
 
-    async fn ps(&self) -> crate::Result<Vec<ProcessInfo>> {
-        self.lifecycle.ps(self).await
+    async fn ps(&self, deadline: Deadline) -> crate::Result<Vec<ProcessInfo>> {
+        self.lifecycle.ps(self, deadline).await
     }
 }
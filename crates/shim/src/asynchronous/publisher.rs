@@ -15,8 +15,17 @@
 */
 
 use std::os::unix::io::RawFd;
+use std::process::Stdio;
+use std::sync::Arc;
+#[cfg(any(test, feature = "testing"))]
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
 
 use async_trait::async_trait;
+use log::{debug, error, warn};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::process::{Child, Command};
+use tokio::sync::mpsc::{channel, Receiver, Sender};
 
 use containerd_shim_protos::api::Empty;
 use containerd_shim_protos::protobuf::Message;
@@ -26,13 +35,42 @@ use containerd_shim_protos::ttrpc;
 use containerd_shim_protos::ttrpc::context::Context;
 use containerd_shim_protos::ttrpc::r#async::TtrpcContext;
 
-use crate::error::Result;
+use crate::asynchronous::monitor::{monitor_subscribe, monitor_unsubscribe};
+use crate::error::{Error, Result};
+use crate::monitor::{claim_exit, ExitEvent, Subject, Topic};
+#[cfg(any(test, feature = "testing"))]
+use crate::publish::PublishedEvent;
+use crate::publish::{
+    forward_context, is_rejected, publish_binary_args, Backoff, EventQueue, PublisherConfig,
+    PublisherMetrics, FLUSH_INTERVAL,
+};
 use crate::util::asyncify;
 use crate::util::{connect, convert_to_any, timestamp};
 
+/// EventPublisher publishes events from the shim back to containerd asynchronously.
+#[async_trait]
+pub trait EventPublisher: Send + Sync {
+    /// Publish a new event.
+    ///
+    /// Event object can be anything that Protobuf able serialize (e.g. implement `Message` trait).
+    async fn publish(
+        &self,
+        ctx: Context,
+        topic: &str,
+        namespace: &str,
+        event: Box<dyn Message>,
+    ) -> Result<()>;
+}
+
 /// Async Remote publisher connects to containerd's TTRPC endpoint to publish events from shim.
+///
+/// Events are queued and forwarded in order by a background task,
+/// which reconnects to containerd if the connection is broken.
+/// A clone shares the queue, the shim keeps one to flush the queue before it exits.
+#[derive(Clone)]
 pub struct RemotePublisher {
-    client: EventsClient,
+    queue: Arc<EventQueue>,
+    notifier: Sender<()>,
 }
 
 impl RemotePublisher {
@@ -40,11 +78,27 @@ impl RemotePublisher {
     ///
     /// containerd uses `/run/containerd/containerd.sock.ttrpc` by default
     pub async fn new(address: impl AsRef<str>) -> Result<RemotePublisher> {
-        let client = Self::connect(address).await?;
+        Self::with_config(address, PublisherConfig::default()).await
+    }
 
-        Ok(RemotePublisher {
-            client: EventsClient::new(client),
-        })
+    /// Connect to containerd's TTRPC endpoint asynchronously,
+    /// with the event queue and reconnection configured by `config`.
+    pub async fn with_config(
+        address: impl AsRef<str>,
+        config: PublisherConfig,
+    ) -> Result<RemotePublisher> {
+        let client = Self::connect(&address).await?;
+        let queue = Arc::new(EventQueue::new(&config)?);
+        let (notifier, rx) = channel(1);
+        let worker = Worker {
+            address: address.as_ref().to_string(),
+            client: Some(EventsClient::new(client)),
+            queue: queue.clone(),
+            backoff: Backoff::new(&config),
+        };
+        tokio::spawn(worker.run(rx));
+
+        Ok(RemotePublisher { queue, notifier })
     }
 
     async fn connect(address: impl AsRef<str>) -> Result<Client> {
@@ -62,6 +116,8 @@ impl RemotePublisher {
     /// Publish a new event.
     ///
     /// Event object can be anything that Protobuf able serialize (e.g. implement `Message` trait).
+    /// The event is queued to be forwarded, an error is returned if it is dropped
+    /// because the queue is full.
     pub async fn publish(
         &self,
         ctx: Context,
@@ -78,12 +134,50 @@ impl RemotePublisher {
         let mut req = events::ForwardRequest::new();
         req.set_envelope(envelope);
 
-        self.client.forward(ctx, &req).await?;
+        self.enqueue(ctx, req).await
+    }
 
+    /// Counters of the published, dropped and delayed events.
+    pub fn metrics(&self) -> PublisherMetrics {
+        self.queue.metrics()
+    }
+
+    /// Wait until all queued events are forwarded, or the timeout expires.
+    pub async fn flush(&self, timeout: Duration) -> Result<()> {
+        let deadline = Instant::now() + timeout;
+        loop {
+            let pending = with_queue(&self.queue, |q| Ok(q.len())).await?;
+            if pending == 0 {
+                return Ok(());
+            }
+            if Instant::now() >= deadline {
+                return Err(other!("{} events are not forwarded", pending));
+            }
+            tokio::time::sleep(FLUSH_INTERVAL).await;
+        }
+    }
+
+    async fn enqueue(&self, ctx: Context, req: events::ForwardRequest) -> Result<()> {
+        with_queue(&self.queue, move |q| q.push(ctx, req)).await?;
+        // the worker is already notified if the channel is full
+        self.notifier.try_send(()).unwrap_or_default();
         Ok(())
     }
 }
 
+#[async_trait]
+impl EventPublisher for RemotePublisher {
+    async fn publish(
+        &self,
+        ctx: Context,
+        topic: &str,
+        namespace: &str,
+        event: Box<dyn Message>,
+    ) -> Result<()> {
+        RemotePublisher::publish(self, ctx, topic, namespace, event).await
+    }
+}
+
 #[async_trait]
 impl Events for RemotePublisher {
     async fn forward(
@@ -91,12 +185,271 @@ impl Events for RemotePublisher {
         _ctx: &TtrpcContext,
         req: events::ForwardRequest,
     ) -> ttrpc::Result<Empty> {
-        self.client.forward(Context::default(), &req).await
+        self.enqueue(Context::default(), req).await?;
+        Ok(Empty::new())
+    }
+}
+
+/// Access the queue on a blocking thread, as it reads and writes the spill file
+/// under the lock of the queue.
+async fn with_queue<F, T>(queue: &Arc<EventQueue>, f: F) -> Result<T>
+where
+    F: FnOnce(&EventQueue) -> Result<T> + Send + 'static,
+    T: Send + 'static,
+{
+    let queue = queue.clone();
+    asyncify(move || f(&queue)).await
+}
+
+struct Worker {
+    address: String,
+    client: Option<EventsClient>,
+    queue: Arc<EventQueue>,
+    backoff: Backoff,
+}
+
+impl Worker {
+    async fn run(mut self, mut rx: Receiver<()>) {
+        // set when forwarding failed, until the queue is drained
+        let mut stalled = false;
+        loop {
+            let front = with_queue(&self.queue, |q| Ok(q.front()))
+                .await
+                .unwrap_or_else(|e| {
+                    error!("failed to get the queued event: {}", e);
+                    None
+                });
+            let (ctx, req) = match front {
+                Some(e) => e,
+                None => {
+                    stalled = false;
+                    // exit when the publisher is dropped
+                    if rx.recv().await.is_none() {
+                        let empty = with_queue(&self.queue, |q| Ok(q.is_empty())).await;
+                        if empty.unwrap_or(true) {
+                            return;
+                        }
+                    }
+                    continue;
+                }
+            };
+
+            if self.client.is_none() {
+                match RemotePublisher::connect(&self.address).await {
+                    Ok(c) => {
+                        debug!("reconnected to {}", self.address);
+                        self.queue.counters.reconnected();
+                        self.client = Some(EventsClient::new(c));
+                    }
+                    Err(e) => {
+                        let delay = self.backoff.next_delay();
+                        warn!(
+                            "failed to reconnect to {}, retry in {:?}: {}",
+                            self.address, delay, e
+                        );
+                        tokio::time::sleep(delay).await;
+                        continue;
+                    }
+                }
+            }
+            let client = self.client.as_ref().unwrap();
+
+            let topic = req.get_envelope().get_topic().to_string();
+            match client.forward(forward_context(ctx), &req).await {
+                Ok(_) => {
+                    self.pop_front().await;
+                    self.queue.counters.published(stalled);
+                    self.backoff.reset();
+                }
+                Err(e) if is_rejected(&e) => {
+                    warn!("event {} is rejected, drop it: {}", topic, e);
+                    self.pop_front().await;
+                    self.queue.counters.dropped();
+                }
+                Err(e) => {
+                    let delay = self.backoff.next_delay();
+                    warn!(
+                        "failed to forward event {}, retry in {:?}: {}",
+                        topic, delay, e
+                    );
+                    stalled = true;
+                    self.client = None;
+                    tokio::time::sleep(delay).await;
+                }
+            }
+        }
+    }
+}
+
+impl Worker {
+    async fn pop_front(&self) {
+        with_queue(&self.queue, |q| {
+            q.pop_front();
+            Ok(())
+        })
+        .await
+        .unwrap_or_else(|e| error!("failed to remove the forwarded event: {}", e));
+    }
+}
+
+/// Binary publisher runs the publish binary given by containerd, which is containerd itself,
+/// once for each event, for the containerd that doesn't serve events over TTRPC to shims.
+pub struct BinaryPublisher {
+    binary: String,
+    address: String,
+    reaper: bool,
+}
+
+impl BinaryPublisher {
+    /// `binary` is the publish binary and `address` is the GRPC address of containerd.
+    pub fn new(binary: impl AsRef<str>, address: impl AsRef<str>) -> Self {
+        Self {
+            binary: binary.as_ref().to_string(),
+            address: address.as_ref().to_string(),
+            reaper: true,
+        }
+    }
+
+    /// Whether the exit of the publish binary is collected by the reaper of the shim,
+    /// it should be unset if the reaper is disabled by `Config::no_reaper`,
+    /// then the publisher waits for the binary itself.
+    pub fn with_reaper(mut self, reaper: bool) -> Self {
+        self.reaper = reaper;
+        self
+    }
+
+    async fn run(&self, topic: &str, namespace: &str, data: &[u8]) -> Result<(i32, String)> {
+        if !self.reaper {
+            let mut child = self.spawn(topic, namespace)?;
+            let stderr = self.communicate(&mut child, data).await?;
+            let status = child
+                .wait()
+                .await
+                .map_err(io_error!(e, "wait for {}", self.binary))?;
+            return Ok((status.code().unwrap_or(-1), stderr));
+        }
+
+        // the exit of the child is collected by the reaper of the shim
+        let mut subscription = monitor_subscribe(Topic::Pid).await?;
+        let sid = subscription.id;
+        let res: Result<(i32, String)> = async {
+            let mut child = self.spawn(topic, namespace)?;
+            let pid = child
+                .id()
+                .ok_or_else(|| other!("{} exited too early", self.binary))?
+                as i32;
+            claim_exit(pid);
+            let stderr = self.communicate(&mut child, data).await?;
+
+            while let Some(e) = subscription.rx.recv().await {
+                if let ExitEvent {
+                    subject: Subject::Pid(epid),
+                    exit_code,
+                } = e
+                {
+                    if epid == pid {
+                        return Ok((exit_code, stderr));
+                    }
+                }
+            }
+            Err(other!("failed to wait for exit of {}", self.binary))
+        }
+        .await;
+        monitor_unsubscribe(sid).await.unwrap_or_default();
+        res
+    }
+
+    fn spawn(&self, topic: &str, namespace: &str) -> Result<Child> {
+        Command::new(&self.binary)
+            .args(&publish_binary_args(&self.address, topic, namespace))
+            .stdin(Stdio::piped())
+            .stdout(Stdio::null())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(io_error!(e, "spawn {}", self.binary))
+    }
+
+    /// Write the event to the stdin of the binary, and read its stderr until it exits.
+    async fn communicate(&self, child: &mut Child, data: &[u8]) -> Result<String> {
+        if let Some(mut stdin) = child.stdin.take() {
+            stdin
+                .write_all(data)
+                .await
+                .map_err(io_error!(e, "write event to {}", self.binary))?;
+        }
+        let mut stderr = String::new();
+        if let Some(mut r) = child.stderr.take() {
+            r.read_to_string(&mut stderr).await.unwrap_or_default();
+        }
+        Ok(stderr)
+    }
+}
+
+#[async_trait]
+impl EventPublisher for BinaryPublisher {
+    async fn publish(
+        &self,
+        _ctx: Context,
+        topic: &str,
+        namespace: &str,
+        event: Box<dyn Message>,
+    ) -> Result<()> {
+        let data = convert_to_any(event)?.write_to_bytes()?;
+        let (exit_code, stderr) = self.run(topic, namespace, &data).await?;
+        if exit_code != 0 {
+            return Err(other!(
+                "failed to publish event {}, exit code {}: {}",
+                topic,
+                exit_code,
+                stderr.trim()
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// In-memory publisher records the published events, it is meant for tests.
+#[cfg(any(test, feature = "testing"))]
+#[derive(Default)]
+pub struct MemoryPublisher {
+    events: Mutex<Vec<PublishedEvent>>,
+}
+
+#[cfg(any(test, feature = "testing"))]
+impl MemoryPublisher {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Events published so far, in order.
+    pub fn events(&self) -> Vec<PublishedEvent> {
+        self.events.lock().unwrap().clone()
+    }
+}
+
+#[cfg(any(test, feature = "testing"))]
+#[async_trait]
+impl EventPublisher for MemoryPublisher {
+    async fn publish(
+        &self,
+        _ctx: Context,
+        topic: &str,
+        namespace: &str,
+        event: Box<dyn Message>,
+    ) -> Result<()> {
+        let event = convert_to_any(event)?;
+        self.events.lock().unwrap().push(PublishedEvent {
+            topic: topic.to_string(),
+            namespace: namespace.to_string(),
+            event,
+        });
+        Ok(())
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use std::os::unix::fs::PermissionsExt;
     use std::os::unix::io::AsRawFd;
     use std::os::unix::net::UnixListener;
     use std::sync::Arc;
@@ -175,4 +528,94 @@ mod tests {
         barrier.wait().await;
         server_thread.await.unwrap();
     }
+
+    #[tokio::test]
+    async fn test_flush_before_exit() {
+        let tmpdir = tempfile::tempdir().unwrap();
+        let path = format!("{}/socket", tmpdir.as_ref().to_str().unwrap());
+        let listener = UnixListener::bind(&path).unwrap();
+        let (tx, mut rx) = channel(8);
+        let t = Arc::new(Box::new(FakeServer { tx }) as Box<dyn Events + Send + Sync>);
+        let mut server = Server::new()
+            .set_domain_unix()
+            .add_listener(listener.as_raw_fd())
+            .unwrap()
+            .register_service(create_events(t));
+        std::mem::forget(listener);
+        server.start().await.unwrap();
+
+        // the shim keeps a clone to flush the events published by the task service
+        let remote = RemotePublisher::new(&path).await.unwrap();
+        let publisher: Box<dyn EventPublisher> = Box::new(remote.clone());
+        for _ in 0..3 {
+            let mut msg = TaskOOM::new();
+            msg.set_container_id("test".to_string());
+            publisher
+                .publish(Context::default(), "/tasks/oom", "ns1", Box::new(msg))
+                .await
+                .unwrap();
+        }
+        drop(publisher);
+        remote.flush(Duration::from_secs(5)).await.unwrap();
+        assert_eq!(remote.metrics().published, 3);
+        drop(remote);
+
+        for _ in 0..3 {
+            assert_eq!(rx.try_recv().unwrap(), 0);
+        }
+        server.shutdown().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_binary_publisher_without_reaper() {
+        let tmpdir = tempfile::tempdir().unwrap();
+        let binary = tmpdir.path().join("publish");
+        std::fs::write(
+            &binary,
+            "#!/bin/sh\ncat > /dev/null\n[ \"$5\" = /tasks/oom ]\n",
+        )
+        .unwrap();
+        std::fs::set_permissions(&binary, std::fs::Permissions::from_mode(0o755)).unwrap();
+
+        // nothing collects the exit of the binary for the publisher
+        let publisher = BinaryPublisher::new(binary.to_str().unwrap(), "/run/containerd.sock")
+            .with_reaper(false);
+        let mut msg = TaskOOM::new();
+        msg.set_container_id("test".to_string());
+        publisher
+            .publish(
+                Context::default(),
+                "/tasks/oom",
+                "ns1",
+                Box::new(msg.clone()),
+            )
+            .await
+            .unwrap();
+        publisher
+            .publish(Context::default(), "/tasks/exit", "ns1", Box::new(msg))
+            .await
+            .unwrap_err();
+    }
+
+    #[tokio::test]
+    async fn test_memory_publisher() {
+        let publisher = MemoryPublisher::new();
+        let mut msg = TaskOOM::new();
+        msg.set_container_id("test".to_string());
+        EventPublisher::publish(
+            &publisher,
+            Context::default(),
+            "/tasks/oom",
+            "ns1",
+            Box::new(msg.clone()),
+        )
+        .await
+        .unwrap();
+
+        let events = publisher.events();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].topic, "/tasks/oom");
+        assert_eq!(events[0].namespace, "ns1");
+        assert_eq!(events[0].event, convert_to_any(Box::new(msg)).unwrap());
+    }
 }
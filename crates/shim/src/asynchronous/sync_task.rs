@@ -0,0 +1,357 @@
+/*
+   Copyright The containerd Authors.
+
+   Licensed under the Apache License, Version 2.0 (the "License");
+   you may not use this file except in compliance with the License.
+   You may obtain a copy of the License at
+
+       http://www.apache.org/licenses/LICENSE-2.0
+
+   Unless required by applicable law or agreed to in writing, software
+   distributed under the License is distributed on an "AS IS" BASIS,
+   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+   See the License for the specific language governing permissions and
+   limitations under the License.
+*/
+
+//! Serves a synchronous [Task](crate::protos::shim_sync::Task) by the async bootstrap, so that a shim
+//! can move to async one step at a time.
+//!
+//! The sync task runs on the blocking threads of tokio, its exit signal is waited the same way.
+//! The reaper of the async bootstrap notifies the async monitor, the adapter forwards the exits
+//! to the sync monitor, which the sync task subscribes to:
+//!
+//! ```ignore
+//! #[async_trait]
+//! impl Shim for Service {
+//!     type T = SyncTaskAdapter<TaskService<Factory, Container>>;
+//!
+//!     async fn wait(&mut self) {
+//!         let exit = self.exit.clone();
+//!         tokio::task::spawn_blocking(move || exit.wait()).await.unwrap_or_default();
+//!     }
+//!
+//!     async fn create_task_service(&self, publisher: Box<dyn EventPublisher>) -> Self::T {
+//!         let (tx, rx) = std::sync::mpsc::channel();
+//!         forward(publisher, rx);
+//!         SyncTaskAdapter::new(TaskService::new(&self.namespace, self.exit.clone(), tx))
+//!     }
+//! }
+//! ```
+
+use std::sync::mpsc::channel;
+use std::sync::{Arc, Once};
+
+use async_trait::async_trait;
+use log::warn;
+
+use crate::api::*;
+use crate::asynchronous::monitor::monitor_subscribe;
+use crate::error::Error;
+use crate::monitor::{Subject, Topic};
+use crate::protos::shim_async::Task;
+use crate::protos::shim_sync::Task as SyncTask;
+use crate::protos::ttrpc::{self, r#async::TtrpcContext};
+use crate::synchronous::monitor as sync_monitor;
+
+static FORWARD_EXITS: Once = Once::new();
+
+/// An async [Task] calling a sync one on the blocking threads of tokio.
+pub struct SyncTaskAdapter<T> {
+    task: Arc<T>,
+}
+
+impl<T> SyncTaskAdapter<T> {
+    /// Create the adapter, it must be called within the tokio runtime of the shim,
+    /// which forwards the exits to the sync monitor from then on.
+    pub fn new(task: T) -> Self {
+        FORWARD_EXITS.call_once(|| {
+            tokio::spawn(forward_exits());
+        });
+        Self {
+            task: Arc::new(task),
+        }
+    }
+
+    pub fn inner(&self) -> &T {
+        &self.task
+    }
+}
+
+/// Forward the exits notified to the async monitor to the sync one.
+async fn forward_exits() {
+    let mut s = match monitor_subscribe(Topic::All).await {
+        Ok(s) => s,
+        Err(e) => {
+            warn!("failed to forward exits to the sync task: {}", e);
+            return;
+        }
+    };
+    while let Some(e) = s.rx.recv().await {
+        let res = match &e.subject {
+            Subject::Pid(pid) => sync_monitor::monitor_notify_by_pid(*pid, e.exit_code),
+            Subject::Exec(id, exec_id) => {
+                sync_monitor::monitor_notify_by_exec(id, exec_id, e.exit_code)
+            }
+        };
+        res.unwrap_or_else(|err| warn!("failed to forward {}: {}", e, err));
+    }
+}
+
+/// The context of the request for the sync task, whose response is returned
+/// instead of being sent by the context.
+fn sync_context(ctx: &TtrpcContext) -> ttrpc::TtrpcContext {
+    ttrpc::TtrpcContext {
+        fd: ctx.fd,
+        mh: ctx.mh.clone(),
+        res_tx: channel().0,
+        metadata: ctx.metadata.clone(),
+        timeout_nano: ctx.timeout_nano,
+    }
+}
+
+macro_rules! adapt {
+    ($($name:ident($req:ty) -> $resp:ty),* $(,)?) => {
+        #[async_trait]
+        impl<T> Task for SyncTaskAdapter<T>
+        where
+            T: SyncTask + Send + Sync + 'static,
+        {
+            $(
+                async fn $name(&self, ctx: &TtrpcContext, req: $req) -> ttrpc::Result<$resp> {
+                    let task = self.task.clone();
+                    let ctx = sync_context(ctx);
+                    tokio::task::spawn_blocking(move || task.$name(&ctx, req))
+                        .await
+                        .map_err(|e| {
+                            Error::Other(format!("{} of sync task failed: {}", stringify!($name), e))
+                        })?
+                }
+            )*
+        }
+    };
+}
+
+adapt! {
+    state(StateRequest) -> StateResponse,
+    create(CreateTaskRequest) -> CreateTaskResponse,
+    start(StartRequest) -> StartResponse,
+    delete(DeleteRequest) -> DeleteResponse,
+    pids(PidsRequest) -> PidsResponse,
+    pause(PauseRequest) -> Empty,
+    resume(ResumeRequest) -> Empty,
+    checkpoint(CheckpointTaskRequest) -> Empty,
+    kill(KillRequest) -> Empty,
+    exec(ExecProcessRequest) -> Empty,
+    resize_pty(ResizePtyRequest) -> Empty,
+    close_io(CloseIORequest) -> Empty,
+    update(UpdateTaskRequest) -> Empty,
+    wait(WaitRequest) -> WaitResponse,
+    stats(StatsRequest) -> StatsResponse,
+    connect(ConnectRequest) -> ConnectResponse,
+    shutdown(ShutdownRequest) -> Empty,
+}
+
+#[cfg(test)]
+mod tests {
+    use std::os::unix::io::AsRawFd;
+    use std::os::unix::net::UnixListener;
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    use containerd_shim_protos::api::{ProcessInfo, Status};
+    use containerd_shim_protos::cgroups::metrics::Metrics;
+    use containerd_shim_protos::shim_async::{create_task, Client, TaskClient};
+    use containerd_shim_protos::ttrpc::context::Context;
+    use containerd_shim_protos::ttrpc::r#async::Server;
+    use oci_spec::runtime::LinuxResources;
+
+    use super::*;
+    use crate::asynchronous::monitor::monitor_notify_by_pid;
+    use crate::io::Stdio;
+    use crate::synchronous::container::{ContainerFactory, ContainerTemplate, ProcessFactory};
+    use crate::synchronous::processes::{Process, ProcessLifecycle, ProcessTemplate};
+    use crate::synchronous::task::TaskService;
+    use crate::synchronous::ExitSignal;
+    use crate::util::Deadline;
+
+    struct FakeLifecycle;
+
+    impl ProcessLifecycle<FakeProcess> for FakeLifecycle {
+        fn start(&self, p: &mut FakeProcess, _deadline: Deadline) -> crate::Result<()> {
+            p.pid = 1000;
+            p.state = Status::RUNNING;
+            Ok(())
+        }
+
+        fn kill(
+            &self,
+            p: &mut FakeProcess,
+            signal: u32,
+            _all: bool,
+            _deadline: Deadline,
+        ) -> crate::Result<()> {
+            p.set_exited(128 + signal as i32);
+            Ok(())
+        }
+
+        fn delete(&self, _p: &mut FakeProcess, _deadline: Deadline) -> crate::Result<()> {
+            Ok(())
+        }
+
+        fn update(
+            &self,
+            _p: &mut FakeProcess,
+            _resources: &LinuxResources,
+            _deadline: Deadline,
+        ) -> crate::Result<()> {
+            Ok(())
+        }
+
+        fn stats(&self, _p: &FakeProcess, _deadline: Deadline) -> crate::Result<Metrics> {
+            Ok(Metrics::new())
+        }
+
+        fn ps(&self, _p: &FakeProcess, _deadline: Deadline) -> crate::Result<Vec<ProcessInfo>> {
+            Ok(vec![])
+        }
+    }
+
+    type FakeProcess = ProcessTemplate<FakeLifecycle>;
+    type FakeContainer = ContainerTemplate<FakeProcess, FakeProcess, FakeFactory>;
+
+    #[derive(Default)]
+    struct FakeFactory;
+
+    fn fake_process(id: &str) -> FakeProcess {
+        let stdio = Stdio::new("", "", "", false);
+        ProcessTemplate::new(id, stdio, FakeLifecycle)
+    }
+
+    impl ContainerFactory<FakeContainer> for FakeFactory {
+        fn create(
+            &self,
+            _ns: &str,
+            req: &CreateTaskRequest,
+            _deadline: Deadline,
+        ) -> crate::Result<FakeContainer> {
+            Ok(ContainerTemplate {
+                id: req.id.to_string(),
+                bundle: req.bundle.to_string(),
+                init: fake_process(&req.id),
+                process_factory: FakeFactory,
+                processes: Default::default(),
+            })
+        }
+
+        fn cleanup(&self, _ns: &str, _c: &FakeContainer) -> crate::Result<()> {
+            Ok(())
+        }
+    }
+
+    impl ProcessFactory<FakeProcess> for FakeFactory {
+        fn create(
+            &self,
+            req: &ExecProcessRequest,
+            _deadline: Deadline,
+        ) -> crate::Result<FakeProcess> {
+            Ok(fake_process(&req.exec_id))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_sync_task_adapter() {
+        let tmpdir = tempfile::tempdir().unwrap();
+        let path = format!("{}/socket", tmpdir.as_ref().to_str().unwrap());
+        let listener = UnixListener::bind(&path).unwrap();
+
+        // the events of the task are not forwarded, but the receiver is kept to accept them
+        let (tx, _rx) = std::sync::mpsc::channel();
+        let task: TaskService<FakeFactory, FakeContainer> =
+            TaskService::new("test", Arc::new(ExitSignal::default()), tx);
+        let t = Arc::new(Box::new(SyncTaskAdapter::new(task)) as Box<dyn Task + Send + Sync>);
+        let mut server = Server::new()
+            .set_domain_unix()
+            .add_listener(listener.as_raw_fd())
+            .unwrap()
+            .register_service(create_task(t));
+        std::mem::forget(listener);
+        server.start().await.unwrap();
+
+        let client = TaskClient::new(Client::connect(&format!("unix://{}", path)).unwrap());
+        let req = CreateTaskRequest {
+            id: "test".to_string(),
+            bundle: tmpdir.as_ref().to_str().unwrap().to_string(),
+            ..Default::default()
+        };
+        client.create(Context::default(), &req).await.unwrap();
+        let req = StartRequest {
+            id: "test".to_string(),
+            ..Default::default()
+        };
+        let resp = client.start(Context::default(), &req).await.unwrap();
+        assert_eq!(resp.pid, 1000);
+
+        let req = StateRequest {
+            id: "test".to_string(),
+            ..Default::default()
+        };
+        let resp = client.state(Context::default(), &req).await.unwrap();
+        assert_eq!(resp.status, Status::RUNNING);
+        assert_eq!(resp.pid, 1000);
+
+        // the wait blocks a thread of tokio until the kill from another request
+        let waiter = client.clone();
+        let wait = tokio::spawn(async move {
+            let req = WaitRequest {
+                id: "test".to_string(),
+                ..Default::default()
+            };
+            waiter.wait(Context::default(), &req).await
+        });
+        tokio::time::sleep(Duration::from_millis(100)).await;
+
+        let req = KillRequest {
+            id: "test".to_string(),
+            signal: 9,
+            ..Default::default()
+        };
+        client.kill(Context::default(), &req).await.unwrap();
+        let resp = tokio::time::timeout(Duration::from_secs(5), wait)
+            .await
+            .unwrap()
+            .unwrap()
+            .unwrap();
+        assert_eq!(resp.exit_status, 137);
+
+        server.shutdown().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_forward_exits() {
+        let (tx, _rx) = std::sync::mpsc::channel();
+        let task: TaskService<FakeFactory, FakeContainer> =
+            TaskService::new("test", Arc::new(ExitSignal::default()), tx);
+        let _adapter = SyncTaskAdapter::new(task);
+        let s = sync_monitor::monitor_subscribe(Topic::Pid).unwrap();
+
+        // the forwarder subscribes to the async monitor in the background
+        let pid = i32::MAX - 2;
+        let exit_code = tokio::time::timeout(Duration::from_secs(5), async {
+            loop {
+                monitor_notify_by_pid(pid, 3).await.unwrap();
+                tokio::time::sleep(Duration::from_millis(10)).await;
+                let code = s.rx.try_iter().find_map(|e| match e.subject {
+                    Subject::Pid(p) if p == pid => Some(e.exit_code),
+                    _ => None,
+                });
+                if let Some(code) = code {
+                    return code;
+                }
+            }
+        })
+        .await
+        .unwrap();
+        assert_eq!(exit_code, 3);
+    }
+}
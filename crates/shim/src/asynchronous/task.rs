@@ -21,7 +21,7 @@ use async_trait::async_trait;
 use log::{debug, info, warn};
 use oci_spec::runtime::LinuxResources;
 use tokio::sync::mpsc::Sender;
-use tokio::sync::{MappedMutexGuard, Mutex, MutexGuard};
+use tokio::sync::{Mutex, OwnedMutexGuard};
 
 use containerd_shim_protos::api::{
     CloseIORequest, ConnectRequest, ConnectResponse, DeleteResponse, PidsRequest, PidsResponse,
@@ -32,7 +32,6 @@ use containerd_shim_protos::events::task::{
 };
 use containerd_shim_protos::protobuf::{Message, SingularPtrField};
 use containerd_shim_protos::shim_async::Task;
-use containerd_shim_protos::ttrpc;
 use containerd_shim_protos::ttrpc::r#async::TtrpcContext;
 
 use crate::api::{
@@ -41,9 +40,13 @@ use crate::api::{
     Status, WaitRequest, WaitResponse,
 };
 use crate::asynchronous::container::{Container, ContainerFactory};
+use crate::asynchronous::monitor::monitor_notify_by_pid;
 use crate::asynchronous::ExitSignal;
+use crate::bookkeeping::{Reservations, Starting};
+use crate::error::{Error, Result};
 use crate::event::Event;
-use crate::util::{convert_to_any, convert_to_timestamp, AsOption};
+use crate::monitor::ProcessIndex;
+use crate::util::{convert_to_any, convert_to_timestamp, AsOption, Deadline};
 use crate::TtrpcResult;
 
 type EventSender = Sender<(String, Box<dyn Message>)>;
@@ -51,11 +54,21 @@ type EventSender = Sender<(String, Box<dyn Message>)>;
 /// TaskService is a Task template struct, it is considered a helper struct,
 /// which has already implemented `Task` trait, so that users can make it the type `T`
 /// parameter of `Service`, and implements their own `ContainerFactory` and `Container`.
+///
+/// Each container has a lock of its own, the lock of the map is only held to look up,
+/// add or remove a container, so a long operation on one container doesn't block the others.
+///
+/// Every request is bounded by its [Deadline], the wait for the lock of the container and
+/// for an exit as well as what the container does are given up once it passes.
 pub struct TaskService<F, C> {
     pub factory: F,
-    pub containers: Arc<Mutex<HashMap<String, C>>>,
+    pub containers: Arc<Mutex<HashMap<String, Arc<Mutex<C>>>>>,
+    /// Processes of the containers by pid, to dispatch their exits.
+    pub processes: Arc<ProcessIndex>,
     pub namespace: String,
     pub exit: Arc<ExitSignal>,
+    /// Ids of the containers being created.
+    creating: Reservations,
     pub tx: EventSender,
 }
 
@@ -67,24 +80,28 @@ where
         Self {
             factory: Default::default(),
             containers: Arc::new(Mutex::new(Default::default())),
+            processes: Default::default(),
             namespace: ns.to_string(),
             exit,
+            creating: Default::default(),
             tx,
         }
     }
 }
 
 impl<F, C> TaskService<F, C> {
-    pub async fn get_container(&self, id: &str) -> TtrpcResult<MappedMutexGuard<'_, C>> {
-        let mut containers = self.containers.lock().await;
-        containers.get_mut(id).ok_or_else(|| {
-            ttrpc::Error::RpcStatus(ttrpc::get_status(
-                ttrpc::Code::NOT_FOUND,
-                format!("can not find container by id {}", id),
-            ))
-        })?;
-        let container = MutexGuard::map(containers, |m| m.get_mut(id).unwrap());
-        Ok(container)
+    /// Lock the container until the deadline, the map of containers is not locked while
+    /// waiting for it.
+    pub async fn get_container(
+        &self,
+        id: &str,
+        deadline: Deadline,
+    ) -> TtrpcResult<OwnedMutexGuard<C>> {
+        let container = self.containers.lock().await.get(id).cloned();
+        let container = container
+            .ok_or_else(|| Error::NotFoundError(format!("can not find container by id {}", id)))?;
+        let lock = async { Ok(container.lock_owned().await) };
+        Ok(deadline.run("lock container", lock).await?)
     }
 
     pub async fn send_event(&self, event: impl Event) {
@@ -94,6 +111,54 @@ impl<F, C> TaskService<F, C> {
             .await
             .unwrap_or_else(|e| warn!("send {} to publisher: {}", topic, e));
     }
+
+    /// Replay the exits of processes which exited before their pids were indexed.
+    async fn replay_exits(&self, exits: Vec<(i32, i32)>) {
+        for (pid, code) in exits {
+            debug!("replay the early exit of process {}", pid);
+            monitor_notify_by_pid(pid, code)
+                .await
+                .unwrap_or_else(|e| warn!("failed to replay exit of process {}: {}", pid, e));
+        }
+    }
+}
+
+impl<F, C> TaskService<F, C>
+where
+    F: ContainerFactory<C>,
+    C: Container + Sync,
+{
+    /// Rebuild the containers saved by a previous instance of the shim,
+    /// it should be called before the task service begins to serve requests.
+    pub async fn restore(&self) -> Result<()> {
+        // hold the lock while restoring, so that exits of the restored processes
+        // are not handled before they are added, and stash the exits that arrive
+        // before their pids are indexed.
+        let mut containers = self.containers.lock().await;
+        let mut starting = Starting::new(&self.processes);
+        let restored = self.factory.restore(&*self.namespace).await?;
+        for c in restored {
+            let id = c.id().await;
+            info!("restored container {}", id);
+            for (exec_id, pid) in c.process_pids().await {
+                if pid > 0 {
+                    starting.index(pid, &id, exec_id.as_deref());
+                }
+            }
+            containers.insert(id, Arc::new(Mutex::new(c)));
+        }
+        let early_exits = starting.finish();
+        drop(containers);
+        self.replay_exits(early_exits).await;
+        Ok(())
+    }
+
+    async fn save(&self, container: &C) {
+        self.factory
+            .save(&*self.namespace, container)
+            .await
+            .unwrap_or_else(|e| warn!("failed to save state of container: {}", e));
+    }
 }
 
 #[async_trait]
@@ -102,8 +167,8 @@ where
     F: ContainerFactory<C> + Sync + Send,
     C: Container + Sync + Send + 'static,
 {
-    async fn state(&self, _ctx: &TtrpcContext, req: StateRequest) -> TtrpcResult<StateResponse> {
-        let container = self.get_container(req.get_id()).await?;
+    async fn state(&self, ctx: &TtrpcContext, req: StateRequest) -> TtrpcResult<StateResponse> {
+        let container = self.get_container(req.get_id(), ctx.into()).await?;
         let exec_id = req.get_exec_id().as_option();
         let resp = container.state(exec_id).await?;
         Ok(resp)
@@ -111,23 +176,34 @@ where
 
     async fn create(
         &self,
-        _ctx: &TtrpcContext,
+        ctx: &TtrpcContext,
         req: CreateTaskRequest,
     ) -> TtrpcResult<CreateTaskResponse> {
         info!("Create request for {:?}", &req);
-        // Note: Get containers here is for getting the lock,
-        // to make sure no other threads manipulate the containers metadata;
-        let mut containers = self.containers.lock().await;
-
         let ns = self.namespace.as_str();
         let id = req.id.as_str();
+        // reserve the id, the reservation is released once the container is added,
+        // or if the create fails
+        let _reservation = {
+            let containers = self.containers.lock().await;
+            self.creating.reserve(id, containers.contains_key(id))?
+        };
 
-        let container = self.factory.create(ns, &req).await?;
+        // The container is created without the lock of the map,
+        // which may take long to run the runtime, mount the rootfs and set up the IO.
+        let mut starting = Starting::new(&self.processes);
+        let container = self.factory.create(ns, &req, ctx.into()).await?;
         let mut resp = CreateTaskResponse::new();
         let pid = container.pid().await as u32;
         resp.pid = pid;
+        self.save(&container).await;
 
-        containers.insert(id.to_string(), container);
+        let early_exits = {
+            let mut containers = self.containers.lock().await;
+            containers.insert(id.to_string(), Arc::new(Mutex::new(container)));
+            starting.index(pid as i32, id, None);
+            starting.finish()
+        };
 
         self.send_event(TaskCreate {
             container_id: req.id.to_string(),
@@ -146,14 +222,23 @@ where
             ..Default::default()
         })
         .await;
+        self.replay_exits(early_exits).await;
         info!("Create request for {} returns pid {}", id, resp.pid);
         Ok(resp)
     }
 
-    async fn start(&self, _ctx: &TtrpcContext, req: StartRequest) -> TtrpcResult<StartResponse> {
+    async fn start(&self, ctx: &TtrpcContext, req: StartRequest) -> TtrpcResult<StartResponse> {
         info!("Start request for {:?}", &req);
-        let mut container = self.get_container(req.get_id()).await?;
-        let pid = container.start(req.exec_id.as_str().as_option()).await?;
+        let deadline = Deadline::from(ctx);
+        let mut container = self.get_container(req.get_id(), deadline).await?;
+        let exec_id = req.exec_id.as_str().as_option();
+        let mut starting = Starting::new(&self.processes);
+        let pid = container.start(exec_id, deadline).await?;
+        starting.index(pid, req.get_id(), exec_id);
+        let early_exits = starting.finish();
+        self.save(&container).await;
+        // the exit handler needs the container to replay the exit
+        drop(container);
 
         let mut resp = StartResponse::new();
         resp.pid = pid as u32;
@@ -175,26 +260,26 @@ where
             .await;
         };
 
+        self.replay_exits(early_exits).await;
         info!("Start request for {:?} returns pid {}", req, resp.get_pid());
         Ok(resp)
     }
 
-    async fn delete(&self, _ctx: &TtrpcContext, req: DeleteRequest) -> TtrpcResult<DeleteResponse> {
+    async fn delete(&self, ctx: &TtrpcContext, req: DeleteRequest) -> TtrpcResult<DeleteResponse> {
         info!("Delete request for {:?}", &req);
-        let mut containers = self.containers.lock().await;
-        let container = containers.get_mut(req.get_id()).ok_or_else(|| {
-            ttrpc::Error::RpcStatus(ttrpc::get_status(
-                ttrpc::Code::NOT_FOUND,
-                format!("can not find container by id {}", req.get_id()),
-            ))
-        })?;
+        let deadline = Deadline::from(ctx);
+        let mut container = self.get_container(req.get_id(), deadline).await?;
         let id = container.id().await;
         let exec_id_opt = req.get_exec_id().as_option();
-        let (pid, exit_status, exited_at) = container.delete(exec_id_opt).await?;
-        self.factory.cleanup(&*self.namespace, container).await?;
+        let (pid, exit_status, exited_at) = container.delete(exec_id_opt, deadline).await?;
+        self.processes.remove(&id, exec_id_opt);
         if req.get_exec_id().is_empty() {
-            containers.remove(req.get_id());
+            self.factory.cleanup(&*self.namespace, &container).await?;
+            self.containers.lock().await.remove(req.get_id());
+        } else {
+            self.save(&container).await;
         }
+        drop(container);
 
         let ts = convert_to_timestamp(exited_at);
         self.send_event(TaskDelete {
@@ -219,10 +304,11 @@ where
         Ok(resp)
     }
 
-    async fn pids(&self, _ctx: &TtrpcContext, req: PidsRequest) -> TtrpcResult<PidsResponse> {
+    async fn pids(&self, ctx: &TtrpcContext, req: PidsRequest) -> TtrpcResult<PidsResponse> {
         debug!("Pids request for {:?}", req);
-        let container = self.get_container(req.get_id()).await?;
-        let procs = container.all_processes().await?;
+        let deadline = Deadline::from(ctx);
+        let container = self.get_container(req.get_id(), deadline).await?;
+        let procs = container.all_processes(deadline).await?;
         debug!("Pids request for {:?} returns successfully", req);
         Ok(PidsResponse {
             processes: procs.into(),
@@ -230,21 +316,24 @@ where
         })
     }
 
-    async fn kill(&self, _ctx: &TtrpcContext, req: KillRequest) -> TtrpcResult<Empty> {
+    async fn kill(&self, ctx: &TtrpcContext, req: KillRequest) -> TtrpcResult<Empty> {
         info!("Kill request for {:?}", req);
-        let mut container = self.get_container(req.get_id()).await?;
+        let deadline = Deadline::from(ctx);
+        let mut container = self.get_container(req.get_id(), deadline).await?;
         container
-            .kill(req.get_exec_id().as_option(), req.signal, req.all)
+            .kill(req.get_exec_id().as_option(), req.signal, req.all, deadline)
             .await?;
         info!("Kill request for {:?} returns successfully", req);
         Ok(Empty::new())
     }
 
-    async fn exec(&self, _ctx: &TtrpcContext, req: ExecProcessRequest) -> TtrpcResult<Empty> {
+    async fn exec(&self, ctx: &TtrpcContext, req: ExecProcessRequest) -> TtrpcResult<Empty> {
         info!("Exec request for {:?}", req);
+        let deadline = Deadline::from(ctx);
         let exec_id = req.get_exec_id().to_string();
-        let mut container = self.get_container(req.get_id()).await?;
-        container.exec(req).await?;
+        let mut container = self.get_container(req.get_id(), deadline).await?;
+        container.exec(req, deadline).await?;
+        self.save(&container).await;
 
         self.send_event(TaskExecAdded {
             container_id: container.id().await,
@@ -256,12 +345,12 @@ where
         Ok(Empty::new())
     }
 
-    async fn resize_pty(&self, _ctx: &TtrpcContext, req: ResizePtyRequest) -> TtrpcResult<Empty> {
+    async fn resize_pty(&self, ctx: &TtrpcContext, req: ResizePtyRequest) -> TtrpcResult<Empty> {
         debug!(
             "Resize pty request for container {}, exec_id: {}",
             &req.id, &req.exec_id
         );
-        let mut container = self.get_container(req.get_id()).await?;
+        let mut container = self.get_container(req.get_id(), ctx.into()).await?;
         container
             .resize_pty(req.get_exec_id().as_option(), req.height, req.width)
             .await?;
@@ -273,25 +362,22 @@ where
         Ok(Empty::new())
     }
 
-    async fn update(&self, _ctx: &TtrpcContext, req: UpdateTaskRequest) -> TtrpcResult<Empty> {
+    async fn update(&self, ctx: &TtrpcContext, req: UpdateTaskRequest) -> TtrpcResult<Empty> {
         debug!("Update request for {:?}", req);
         let resources: LinuxResources = serde_json::from_slice(req.get_resources().get_value())
-            .map_err(|e| {
-                ttrpc::Error::RpcStatus(ttrpc::get_status(
-                    ttrpc::Code::INVALID_ARGUMENT,
-                    format!("failed to parse resource spec: {}", e),
-                ))
-            })?;
-        let mut container = self.get_container(req.get_id()).await?;
-        container.update(&resources).await?;
+            .map_err(|e| Error::InvalidArgument(format!("failed to parse resource spec: {}", e)))?;
+        let deadline = Deadline::from(ctx);
+        let mut container = self.get_container(req.get_id(), deadline).await?;
+        container.update(&resources, deadline).await?;
         Ok(Empty::new())
     }
 
-    async fn wait(&self, _ctx: &TtrpcContext, req: WaitRequest) -> TtrpcResult<WaitResponse> {
+    async fn wait(&self, ctx: &TtrpcContext, req: WaitRequest) -> TtrpcResult<WaitResponse> {
         info!("Wait request for {:?}", req);
+        let deadline = Deadline::from(ctx);
         let exec_id = req.exec_id.as_str().as_option();
         let wait_rx = {
-            let mut container = self.get_container(req.get_id()).await?;
+            let mut container = self.get_container(req.get_id(), deadline).await?;
             let state = container.state(exec_id).await?;
             if state.status != Status::RUNNING && state.status != Status::CREATED {
                 let mut resp = WaitResponse::new();
@@ -305,9 +391,13 @@ where
                 .await?
         };
 
-        wait_rx.await.unwrap_or_default();
+        let exited = async {
+            wait_rx.await.unwrap_or_default();
+            Ok(())
+        };
+        deadline.run("wait", exited).await?;
         // get lock again.
-        let container = self.get_container(req.get_id()).await?;
+        let container = self.get_container(req.get_id(), deadline).await?;
         let (_, code, exited_at) = container.get_exit_info(exec_id).await?;
         let mut resp = WaitResponse::new();
         resp.exit_status = code as u32;
@@ -317,10 +407,11 @@ where
         Ok(resp)
     }
 
-    async fn stats(&self, _ctx: &TtrpcContext, req: StatsRequest) -> TtrpcResult<StatsResponse> {
+    async fn stats(&self, ctx: &TtrpcContext, req: StatsRequest) -> TtrpcResult<StatsResponse> {
         debug!("Stats request for {:?}", req);
-        let container = self.get_container(req.get_id()).await?;
-        let stats = container.stats().await?;
+        let deadline = Deadline::from(ctx);
+        let container = self.get_container(req.get_id(), deadline).await?;
+        let stats = container.stats(deadline).await?;
 
         let mut resp = StatsResponse::new();
         resp.set_stats(convert_to_any(Box::new(stats))?);
@@ -329,11 +420,11 @@ where
 
     async fn connect(
         &self,
-        _ctx: &TtrpcContext,
+        ctx: &TtrpcContext,
         req: ConnectRequest,
     ) -> TtrpcResult<ConnectResponse> {
         info!("Connect request for {:?}", req);
-        let container = self.get_container(req.get_id()).await?;
+        let container = self.get_container(req.get_id(), ctx.into()).await?;
 
         Ok(ConnectResponse {
             shim_pid: std::process::id() as u32,
@@ -344,8 +435,7 @@ where
 
     async fn shutdown(&self, _ctx: &TtrpcContext, _req: ShutdownRequest) -> TtrpcResult<Empty> {
         debug!("Shutdown request");
-        let containers = self.containers.lock().await;
-        if containers.len() > 0 {
+        if !self.containers.lock().await.is_empty() {
             return Ok(Empty::new());
         }
         self.exit.signal();
@@ -14,6 +14,7 @@
    limitations under the License.
 */
 
+use std::future::Future;
 use std::path::Path;
 
 use libc::mode_t;
@@ -22,13 +23,16 @@ use oci_spec::runtime::Spec;
 use tokio::fs::OpenOptions;
 use tokio::io::{AsyncReadExt, AsyncWriteExt};
 use tokio::task::spawn_blocking;
+use tokio::time::Instant;
 
 use containerd_shim_protos::api::Mount;
 use containerd_shim_protos::shim::oci::Options;
+use containerd_shim_protos::ttrpc::r#async::TtrpcContext;
 
 use crate::error::Error;
 use crate::error::Result;
-use crate::util::{AsOption, JsonOptions, CONFIG_FILE_NAME, OPTIONS_FILE_NAME, RUNTIME_FILE_NAME};
+use crate::mount::MountInfo;
+use crate::util::{Deadline, JsonOptions, CONFIG_FILE_NAME, OPTIONS_FILE_NAME, RUNTIME_FILE_NAME};
 
 pub async fn asyncify<F, T>(f: F) -> Result<T>
 where
@@ -116,16 +120,20 @@ pub async fn write_runtime(bundle: impl AsRef<Path>, binary_name: &str) -> Resul
 }
 
 pub async fn mount_rootfs(m: &Mount, target: impl AsRef<Path>) -> Result<()> {
-    let mount_type = m.field_type.to_string();
-    let source = m.source.to_string();
-    let options = m.options.to_vec();
+    let m = crate::mount::Mount::from(m);
     let rootfs = target.as_ref().to_owned();
-    asyncify(move || -> Result<()> {
-        let mount_type = mount_type.as_option();
-        let source = source.as_option();
-        crate::mount::mount_rootfs(mount_type, source, options.as_slice(), &rootfs)
-    })
-    .await
+    asyncify(move || -> Result<()> { m.mount(&rootfs) }).await
+}
+
+pub async fn lookup_mount(mount_point: impl AsRef<Path>) -> Result<Option<MountInfo>> {
+    let mount_point = mount_point.as_ref().to_owned();
+    asyncify(move || -> Result<Option<MountInfo>> { crate::mount::lookup_mount(&mount_point) })
+        .await
+}
+
+pub async fn unmount_rootfs(target: impl AsRef<Path>) -> Result<()> {
+    let rootfs = target.as_ref().to_owned();
+    asyncify(move || -> Result<()> { crate::mount::unmount_rootfs(&rootfs, 0) }).await
 }
 
 pub async fn mkdir(path: impl AsRef<Path>, mode: mode_t) -> Result<()> {
@@ -140,9 +148,33 @@ pub async fn mkdir(path: impl AsRef<Path>, mode: mode_t) -> Result<()> {
     .await
 }
 
+impl From<&TtrpcContext> for Deadline {
+    fn from(ctx: &TtrpcContext) -> Self {
+        Self::after_nanos(ctx.timeout_nano)
+    }
+}
+
+impl Deadline {
+    /// Run the future until the deadline, it is dropped when the deadline passes,
+    /// which kills the runtime command it is waiting for.
+    /// The caller is responsible to roll back the work done before.
+    pub async fn run<F, T>(&self, what: &str, fut: F) -> Result<T>
+    where
+        F: Future<Output = Result<T>>,
+    {
+        match self.instant() {
+            Some(deadline) => tokio::time::timeout_at(Instant::from_std(deadline), fut)
+                .await
+                .map_err(|_| Error::DeadlineExceeded(what.to_string()))?,
+            None => fut.await,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::util::{read_file_to_str, write_str_to_file};
+    use crate::error::Error;
+    use crate::util::{read_file_to_str, write_str_to_file, Deadline};
 
     #[tokio::test]
     async fn test_read_write_str() {
@@ -153,4 +185,17 @@ mod tests {
         let read_str = read_file_to_str(&tmp_file).await.unwrap();
         assert_eq!(read_str, test_str);
     }
+
+    #[tokio::test]
+    async fn test_deadline_run() {
+        let deadline = Deadline::default();
+        assert_eq!(deadline.run("test", async { Ok(1) }).await.unwrap(), 1);
+
+        let deadline = Deadline::after_nanos(10_000_000);
+        let res = deadline
+            .run("test", futures::future::pending::<crate::Result<()>>())
+            .await;
+        assert!(matches!(res, Err(Error::DeadlineExceeded(_))));
+        assert!(deadline.is_exceeded());
+    }
 }
@@ -0,0 +1,134 @@
+/*
+   Copyright The containerd Authors.
+
+   Licensed under the Apache License, Version 2.0 (the "License");
+   you may not use this file except in compliance with the License.
+   You may obtain a copy of the License at
+
+       http://www.apache.org/licenses/LICENSE-2.0
+
+   Unless required by applicable law or agreed to in writing, software
+   distributed under the License is distributed on an "AS IS" BASIS,
+   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+   See the License for the specific language governing permissions and
+   limitations under the License.
+*/
+
+//! Bookkeeping of the containers being created and the processes being started,
+//! shared by the sync and async `TaskService`.
+
+use std::collections::HashSet;
+use std::mem;
+use std::sync::Mutex;
+
+use crate::error::{Error, Result};
+use crate::monitor::ProcessIndex;
+
+/// Ids of the containers being created, reserved so that a second create of the same id
+/// is rejected right away instead of racing with the first one on the same bundle.
+#[derive(Default)]
+pub(crate) struct Reservations(Mutex<HashSet<String>>);
+
+impl Reservations {
+    /// Reserve the id, fails with `AlreadyExists` if the container `exists` or is being
+    /// created. The id is released when the reservation is dropped.
+    pub(crate) fn reserve(&self, id: &str, exists: bool) -> Result<Reservation<'_>> {
+        if exists || !self.0.lock().unwrap().insert(id.to_string()) {
+            return Err(Error::AlreadyExists(format!("container {}", id)));
+        }
+        Ok(Reservation {
+            reservations: self,
+            id: id.to_string(),
+        })
+    }
+}
+
+pub(crate) struct Reservation<'a> {
+    reservations: &'a Reservations,
+    id: String,
+}
+
+impl Drop for Reservation<'_> {
+    fn drop(&mut self) {
+        self.reservations.0.lock().unwrap().remove(&self.id);
+    }
+}
+
+/// A start in flight, the exits of unknown pids are stashed by the [ProcessIndex]
+/// until it is finished, or dropped if the start fails.
+pub(crate) struct Starting<'a> {
+    processes: &'a ProcessIndex,
+    early_exits: Vec<(i32, i32)>,
+    finished: bool,
+}
+
+impl<'a> Starting<'a> {
+    pub(crate) fn new(processes: &'a ProcessIndex) -> Self {
+        processes.starting();
+        Self {
+            processes,
+            early_exits: vec![],
+            finished: false,
+        }
+    }
+
+    /// Index a started process, the start stays in flight for the next ones.
+    pub(crate) fn index(&mut self, pid: i32, id: &str, exec_id: Option<&str>) {
+        self.processes.starting();
+        if let Some(code) = self.processes.started(Some(pid), id, exec_id) {
+            self.early_exits.push((pid, code));
+        }
+    }
+
+    /// Returns the pids and exit codes of the indexed processes which exited before
+    /// being indexed, the caller is responsible to replay the exits.
+    pub(crate) fn finish(mut self) -> Vec<(i32, i32)> {
+        self.finished = true;
+        self.processes.started(None, "", None);
+        mem::take(&mut self.early_exits)
+    }
+}
+
+impl Drop for Starting<'_> {
+    fn drop(&mut self) {
+        if !self.finished {
+            self.processes.started(None, "", None);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reservations() {
+        let reservations = Reservations::default();
+        let r = reservations.reserve("c1", false).unwrap();
+        assert!(reservations.reserve("c1", false).is_err());
+        assert!(reservations.reserve("c2", true).is_err());
+        assert!(reservations.reserve("c2", false).is_ok());
+        drop(r);
+        assert!(reservations.reserve("c1", false).is_ok());
+    }
+
+    #[test]
+    fn test_starting() {
+        let processes = ProcessIndex::default();
+        let mut starting = Starting::new(&processes);
+        assert_eq!(processes.exited(10, 137), None);
+        assert_eq!(processes.exited(11, 0), None);
+        starting.index(10, "c1", None);
+        starting.index(12, "c1", Some("e1"));
+        assert_eq!(starting.finish(), vec![(10, 137)]);
+        assert_eq!(processes.exited(12, 0).unwrap().id, "c1");
+
+        // the exits are not stashed once a failed start is dropped
+        let starting = Starting::new(&processes);
+        drop(starting);
+        assert_eq!(processes.exited(13, 0), None);
+        let mut starting = Starting::new(&processes);
+        starting.index(13, "c2", None);
+        assert!(starting.finish().is_empty());
+    }
+}
@@ -51,7 +51,20 @@ pub fn set_cgroup_and_oom_score(pid: u32) -> Result<()> {
         let opts = Any::parse_from_bytes(&data)
             .and_then(|any| Options::parse_from_bytes(any.get_value()))?;
 
-        if !opts.shim_cgroup.is_empty() {
+        // a path not in systemd form is a cgroupfs path, even with the systemd cgroup driver
+        if is_systemd_cgroup_path(&opts.shim_cgroup) {
+            let path = systemd_cgroup_path(&opts.shim_cgroup)?;
+            // the shim can't ask systemd to start the unit, and a cgroup made by hand
+            // would be unknown to systemd, so only a unit that is running can be joined.
+            if !unit_cgroup_exists(&path) {
+                return Err(Error::InvalidArgument(format!(
+                    "systemd unit of shim cgroup {} is not running, the shim can't start \
+                     systemd units, start it beforehand or use a cgroupfs path",
+                    opts.shim_cgroup
+                )));
+            }
+            add_task_to_cgroup(path.as_str(), pid)?;
+        } else if !opts.shim_cgroup.is_empty() {
             add_task_to_cgroup(opts.shim_cgroup.as_str(), pid)?;
         }
     }
@@ -71,6 +84,96 @@ pub fn add_task_to_cgroup(path: &str, pid: u32) -> Result<()> {
         .map_err(other_error!(e, "add task to cgroup"))
 }
 
+/// Returns true if the cgroup path is in systemd `slice:prefix:name` form.
+pub fn is_systemd_cgroup_path(path: &str) -> bool {
+    !path.starts_with('/') && path.split(':').count() == 3
+}
+
+/// Resolve a systemd `slice:prefix:name` cgroup path to the cgroupfs path of its unit,
+/// the same way runc's systemd cgroup driver does.
+///
+/// For example `system.slice:cri-containerd:abc` resolves to
+/// `/system.slice/cri-containerd-abc.scope`, and `kubepods-besteffort.slice::pod1.slice`
+/// resolves to `/kubepods.slice/kubepods-besteffort.slice/pod1.slice`.
+/// Like runc, the prefix is joined by `-` even if it is empty, `system.slice::abc`
+/// resolves to `/system.slice/-abc.scope`.
+pub fn systemd_cgroup_path(path: &str) -> Result<String> {
+    let parts: Vec<&str> = path.split(':').collect();
+    if parts.len() != 3 {
+        return Err(Error::InvalidArgument(format!(
+            "expected cgroup path in slice:prefix:name form, got {}",
+            path
+        )));
+    }
+    let (slice, prefix, name) = (parts[0], parts[1], parts[2]);
+    if name.is_empty() {
+        return Err(Error::InvalidArgument(format!(
+            "systemd cgroup path {} has empty unit name",
+            path
+        )));
+    }
+
+    // runc puts units without a parent slice into system.slice.
+    let slice = if slice.is_empty() {
+        "system.slice"
+    } else {
+        slice
+    };
+    let slice = expand_slice(slice)?;
+
+    let unit = if name.ends_with(".slice") {
+        name.to_string()
+    } else {
+        format!("{}-{}.scope", prefix, name)
+    };
+
+    Ok(format!("{}/{}", slice.trim_end_matches('/'), unit))
+}
+
+/// Whether the cgroup of a systemd unit exists, in the hierarchy that systemd manages.
+fn unit_cgroup_exists(path: &str) -> bool {
+    let h = hierarchies::auto();
+    let root = if h.v2() {
+        h.root()
+    } else {
+        h.root().join("systemd")
+    };
+    root.join(path.trim_start_matches('/')).is_dir()
+}
+
+/// Expand a systemd slice name to its cgroupfs path,
+/// e.g. `a-b-c.slice` expands to `/a.slice/a-b.slice/a-b-c.slice`.
+fn expand_slice(slice: &str) -> Result<String> {
+    let name = slice
+        .strip_suffix(".slice")
+        .ok_or_else(|| Error::InvalidArgument(format!("invalid slice name {}", slice)))?;
+    if name.contains('/') {
+        return Err(Error::InvalidArgument(format!(
+            "invalid slice name {}",
+            slice
+        )));
+    }
+    // "-.slice" is the root slice
+    if name == "-" {
+        return Ok("/".to_string());
+    }
+
+    let mut path = String::new();
+    let mut prefix = String::new();
+    for component in name.split('-') {
+        if component.is_empty() {
+            return Err(Error::InvalidArgument(format!(
+                "invalid slice name {}",
+                slice
+            )));
+        }
+        path.push_str(&format!("/{}{}.slice", prefix, component));
+        prefix.push_str(component);
+        prefix.push('-');
+    }
+    Ok(path)
+}
+
 /// Sets the OOM score for the process to the parents OOM score + 1
 /// to ensure that they parent has a lower score than the shim
 pub fn adjust_oom_score(pid: u32) -> Result<()> {
@@ -225,7 +328,8 @@ mod tests {
     use cgroups_rs::{hierarchies, Cgroup, CgroupPid};
 
     use crate::cgroup::{
-        add_task_to_cgroup, adjust_oom_score, read_process_oom_score, OOM_SCORE_ADJ_MAX,
+        add_task_to_cgroup, adjust_oom_score, expand_slice, is_systemd_cgroup_path,
+        read_process_oom_score, systemd_cgroup_path, OOM_SCORE_ADJ_MAX,
     };
 
     #[test]
@@ -259,4 +363,54 @@ mod tests {
             assert_eq!(new, OOM_SCORE_ADJ_MAX)
         }
     }
+
+    #[test]
+    fn test_expand_slice() {
+        assert_eq!(expand_slice("-.slice").unwrap(), "/");
+        assert_eq!(expand_slice("system.slice").unwrap(), "/system.slice");
+        assert_eq!(
+            expand_slice("kubepods-besteffort-pod1.slice").unwrap(),
+            "/kubepods.slice/kubepods-besteffort.slice/kubepods-besteffort-pod1.slice"
+        );
+        assert!(expand_slice("system").is_err());
+        assert!(expand_slice("a--b.slice").is_err());
+        assert!(expand_slice("a/b.slice").is_err());
+    }
+
+    #[test]
+    fn test_systemd_cgroup_path() {
+        assert!(is_systemd_cgroup_path("system.slice:containerd:abc"));
+        assert!(!is_systemd_cgroup_path("/runc_shim_test_cgroup"));
+        assert!(!is_systemd_cgroup_path("runc_shim_test_cgroup"));
+
+        assert_eq!(
+            systemd_cgroup_path("system.slice:cri-containerd:abc").unwrap(),
+            "/system.slice/cri-containerd-abc.scope"
+        );
+        assert_eq!(
+            systemd_cgroup_path(":cri-containerd:abc").unwrap(),
+            "/system.slice/cri-containerd-abc.scope"
+        );
+        assert_eq!(
+            systemd_cgroup_path("kubepods-besteffort.slice::pod1.slice").unwrap(),
+            "/kubepods.slice/kubepods-besteffort.slice/pod1.slice"
+        );
+        assert_eq!(
+            systemd_cgroup_path("-.slice:prefix:name").unwrap(),
+            "/prefix-name.scope"
+        );
+        assert_eq!(
+            systemd_cgroup_path("system.slice::abc").unwrap(),
+            "/system.slice/-abc.scope"
+        );
+        assert!(systemd_cgroup_path("system.slice:prefix:").is_err());
+        assert!(systemd_cgroup_path("system.slice").is_err());
+    }
+
+    #[test]
+    fn test_unit_cgroup_exists() {
+        assert!(!unit_cgroup_exists(
+            "/runc_shim_test.slice/runc_shim_test-abc.scope"
+        ));
+    }
 }
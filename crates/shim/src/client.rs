@@ -0,0 +1,201 @@
+/*
+   Copyright The containerd Authors.
+
+   Licensed under the Apache License, Version 2.0 (the "License");
+   you may not use this file except in compliance with the License.
+   You may obtain a copy of the License at
+
+       http://www.apache.org/licenses/LICENSE-2.0
+
+   Unless required by applicable law or agreed to in writing, software
+   distributed under the License is distributed on an "AS IS" BASIS,
+   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+   See the License for the specific language governing permissions and
+   limitations under the License.
+*/
+
+//! A client to talk to a shim directly, without containerd.
+
+use std::path::Path;
+use std::time::Duration;
+
+use oci_spec::runtime::{Process, Spec};
+use time::OffsetDateTime;
+
+#[cfg(feature = "async")]
+pub use crate::asynchronous::client::*;
+use crate::error::{Error, Result};
+use crate::io::Stdio;
+use crate::protos::cgroups::metrics::Metrics;
+use crate::protos::protobuf::well_known_types::{Any, Timestamp};
+use crate::protos::protobuf::{Message, SingularPtrField};
+use crate::protos::shim::shim::{CreateTaskRequest, ExecProcessRequest};
+use crate::protos::ttrpc::context::Context;
+#[cfg(not(feature = "async"))]
+pub use crate::synchronous::client::*;
+use crate::util::CONFIG_FILE_NAME;
+
+/// Key of the ttrpc metadata that carries the namespace of the request.
+const NAMESPACE_KEY: &str = "containerd-namespace-ttrpc";
+
+/// Default time to wait for the shim socket to be connectable.
+pub const DEFAULT_CONNECT_TIMEOUT: Duration = Duration::from_secs(3);
+
+/// Type url of the process spec of an exec, which is encoded in JSON.
+const PROCESS_TYPE_URL: &str = "types.containerd.io/opencontainers/runtime-spec/1/Process";
+
+/// Interval between the attempts to connect the shim socket.
+pub(crate) const CONNECT_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Exit status of a process in the shim.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ExitStatus {
+    pub exit_status: u32,
+    /// `None` if the shim didn't report when the process exited.
+    pub exited_at: Option<OffsetDateTime>,
+}
+
+impl ExitStatus {
+    pub(crate) fn new(exit_status: u32, exited_at: Option<&Timestamp>) -> Self {
+        Self {
+            exit_status,
+            exited_at: exited_at.and_then(|ts| {
+                let nanos = ts.seconds as i128 * 1_000_000_000 + ts.nanos as i128;
+                OffsetDateTime::from_unix_timestamp_nanos(nanos).ok()
+            }),
+        }
+    }
+}
+
+/// Options of the requests sent by a [ShimClient].
+#[derive(Clone, Debug, Default)]
+pub(crate) struct CallOpts {
+    pub(crate) namespace: String,
+    pub(crate) timeout: Option<Duration>,
+}
+
+impl CallOpts {
+    pub(crate) fn context(&self) -> Context {
+        let mut ctx = Context::default();
+        if !self.namespace.is_empty() {
+            ctx.add(NAMESPACE_KEY.to_string(), self.namespace.clone());
+        }
+        if let Some(timeout) = self.timeout {
+            ctx.timeout_nano = timeout.as_nanos() as i64;
+        }
+        ctx
+    }
+}
+
+/// Make the address understood by ttrpc, a bare path is taken as a unix socket.
+pub(crate) fn ttrpc_address(address: &str) -> String {
+    if address.starts_with("unix://") || address.starts_with("vsock://") {
+        address.to_string()
+    } else {
+        format!("unix://{}", address)
+    }
+}
+
+/// Write the spec into the bundle, as the runtime reads it from there on create.
+pub(crate) fn write_spec(bundle: &str, spec: Option<&Spec>) -> Result<()> {
+    if let Some(spec) = spec {
+        spec.save(Path::new(bundle).join(CONFIG_FILE_NAME))
+            .map_err(other_error!(e, "save spec"))?;
+    }
+    Ok(())
+}
+
+pub(crate) fn create_request(id: &str, bundle: &str, stdio: &Stdio) -> CreateTaskRequest {
+    CreateTaskRequest {
+        id: id.to_string(),
+        bundle: bundle.to_string(),
+        terminal: stdio.terminal,
+        stdin: stdio.stdin.clone(),
+        stdout: stdio.stdout.clone(),
+        stderr: stdio.stderr.clone(),
+        ..Default::default()
+    }
+}
+
+pub(crate) fn exec_request(
+    id: &str,
+    exec_id: &str,
+    process: &Process,
+    stdio: &Stdio,
+) -> Result<ExecProcessRequest> {
+    let mut spec = Any::new();
+    spec.set_type_url(PROCESS_TYPE_URL.to_string());
+    spec.set_value(serde_json::to_vec(process)?);
+    Ok(ExecProcessRequest {
+        id: id.to_string(),
+        exec_id: exec_id.to_string(),
+        terminal: stdio.terminal,
+        stdin: stdio.stdin.clone(),
+        stdout: stdio.stdout.clone(),
+        stderr: stdio.stderr.clone(),
+        spec: SingularPtrField::some(spec),
+        ..Default::default()
+    })
+}
+
+/// Decode the cgroups metrics carried by the stats of a task.
+pub(crate) fn decode_metrics(stats: &Any) -> Result<Metrics> {
+    let type_url = stats.get_type_url();
+    let name = type_url.rsplit('/').next().unwrap_or(type_url);
+    if name != Metrics::descriptor_static().full_name() {
+        return Err(other!("unexpected type of stats: {}", type_url));
+    }
+    Ok(Metrics::parse_from_bytes(stats.get_value())?)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::util::convert_to_any;
+
+    use super::*;
+
+    #[test]
+    fn test_ttrpc_address() {
+        assert_eq!(ttrpc_address("/run/shim.sock"), "unix:///run/shim.sock");
+        assert_eq!(
+            ttrpc_address("unix:///run/shim.sock"),
+            "unix:///run/shim.sock"
+        );
+        assert_eq!(ttrpc_address("vsock://3:1024"), "vsock://3:1024");
+    }
+
+    #[test]
+    fn test_context() {
+        let opts = CallOpts {
+            namespace: "k8s.io".to_string(),
+            timeout: Some(Duration::from_secs(2)),
+        };
+        let ctx = opts.context();
+        assert_eq!(ctx.metadata[NAMESPACE_KEY], vec!["k8s.io".to_string()]);
+        assert_eq!(ctx.timeout_nano, 2_000_000_000);
+
+        let ctx = CallOpts::default().context();
+        assert!(ctx.metadata.is_empty());
+        assert_eq!(ctx.timeout_nano, 0);
+    }
+
+    #[test]
+    fn test_decode_metrics() {
+        let mut metrics = Metrics::new();
+        metrics.mut_memory().set_usage(Default::default());
+        let any = convert_to_any(Box::new(metrics.clone())).unwrap();
+        assert_eq!(decode_metrics(&any).unwrap(), metrics);
+
+        let any = convert_to_any(Box::new(Timestamp::new())).unwrap();
+        assert!(decode_metrics(&any).is_err());
+    }
+
+    #[test]
+    fn test_exit_status() {
+        let mut ts = Timestamp::new();
+        ts.set_seconds(1_640_995_200);
+        let status = ExitStatus::new(137, Some(&ts));
+        assert_eq!(status.exited_at.unwrap().unix_timestamp(), 1_640_995_200);
+        assert_eq!(ExitStatus::new(0, None).exited_at, None);
+    }
+}
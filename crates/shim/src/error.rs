@@ -63,6 +63,15 @@ pub enum Error {
     #[error("Failed pre condition: {0}")]
     FailedPreconditionError(String),
 
+    #[error("Already exists: {0}")]
+    AlreadyExists(String),
+
+    #[error("Unavailable: {0}")]
+    Unavailable(String),
+
+    #[error("Deadline exceeded: {0}")]
+    DeadlineExceeded(String),
+
     #[error("{context} error: {err}")]
     MountError {
         context: String,
@@ -86,20 +95,84 @@ pub enum Error {
     Unimplemented(String),
 }
 
+/// Classes of errors defined by containerd's `errdefs` package,
+/// which containerd recognizes by the ttrpc status codes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ErrorKind {
+    Unknown,
+    InvalidArgument,
+    NotFound,
+    AlreadyExists,
+    FailedPrecondition,
+    Unavailable,
+    DeadlineExceeded,
+    NotImplemented,
+}
+
+impl ErrorKind {
+    /// The ttrpc status code of the errors of the kind.
+    pub fn code(&self) -> ttrpc::Code {
+        match self {
+            ErrorKind::Unknown => ttrpc::Code::UNKNOWN,
+            ErrorKind::InvalidArgument => ttrpc::Code::INVALID_ARGUMENT,
+            ErrorKind::NotFound => ttrpc::Code::NOT_FOUND,
+            ErrorKind::AlreadyExists => ttrpc::Code::ALREADY_EXISTS,
+            ErrorKind::FailedPrecondition => ttrpc::Code::FAILED_PRECONDITION,
+            ErrorKind::Unavailable => ttrpc::Code::UNAVAILABLE,
+            ErrorKind::DeadlineExceeded => ttrpc::Code::DEADLINE_EXCEEDED,
+            ErrorKind::NotImplemented => ttrpc::Code::UNIMPLEMENTED,
+        }
+    }
+
+    fn from_code(code: ttrpc::Code) -> Self {
+        match code {
+            ttrpc::Code::INVALID_ARGUMENT => ErrorKind::InvalidArgument,
+            ttrpc::Code::NOT_FOUND => ErrorKind::NotFound,
+            ttrpc::Code::ALREADY_EXISTS => ErrorKind::AlreadyExists,
+            ttrpc::Code::FAILED_PRECONDITION => ErrorKind::FailedPrecondition,
+            ttrpc::Code::UNAVAILABLE => ErrorKind::Unavailable,
+            ttrpc::Code::DEADLINE_EXCEEDED => ErrorKind::DeadlineExceeded,
+            ttrpc::Code::UNIMPLEMENTED => ErrorKind::NotImplemented,
+            _ => ErrorKind::Unknown,
+        }
+    }
+}
+
+impl Error {
+    /// Classify the error like containerd's `errdefs`.
+    ///
+    /// Only the typed variants are classified. A raw io, nix or mount error of the shim
+    /// itself is unknown whatever its errno, as containerd takes a not found on delete or
+    /// kill as already gone; a call site wraps the error in a typed variant on purpose.
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            Error::InvalidArgument(_) => ErrorKind::InvalidArgument,
+            Error::NotFoundError(_) => ErrorKind::NotFound,
+            Error::FailedPreconditionError(_) => ErrorKind::FailedPrecondition,
+            Error::AlreadyExists(_) => ErrorKind::AlreadyExists,
+            Error::Unavailable(_) => ErrorKind::Unavailable,
+            Error::DeadlineExceeded(_) => ErrorKind::DeadlineExceeded,
+            Error::Unimplemented(_) => ErrorKind::NotImplemented,
+            Error::Ttrpc(ttrpc::Error::RpcStatus(s)) => ErrorKind::from_code(s.get_code()),
+            _ => ErrorKind::Unknown,
+        }
+    }
+}
+
 impl From<Error> for ttrpc::Error {
     fn from(e: Error) -> Self {
+        let kind = e.kind();
         match e {
-            Error::InvalidArgument(ref s) => {
-                ttrpc::Error::RpcStatus(ttrpc::get_status(ttrpc::Code::INVALID_ARGUMENT, s))
-            }
-            Error::NotFoundError(ref s) => {
-                ttrpc::Error::RpcStatus(ttrpc::get_status(ttrpc::Code::NOT_FOUND, s))
-            }
-            Error::FailedPreconditionError(ref s) => {
-                ttrpc::Error::RpcStatus(ttrpc::get_status(ttrpc::Code::FAILED_PRECONDITION, s))
-            }
             Error::Ttrpc(e) => e,
-            _ => ttrpc::Error::Others(e.to_string()),
+            Error::InvalidArgument(s)
+            | Error::NotFoundError(s)
+            | Error::FailedPreconditionError(s)
+            | Error::AlreadyExists(s)
+            | Error::Unavailable(s)
+            | Error::DeadlineExceeded(s) => {
+                ttrpc::Error::RpcStatus(ttrpc::get_status(kind.code(), s))
+            }
+            e => ttrpc::Error::RpcStatus(ttrpc::get_status(kind.code(), e.to_string())),
         }
     }
 }
@@ -137,3 +210,59 @@ macro_rules! other_error {
         |$e| Error::Other($s.to_string() + &": ".to_string() + &$e.to_string())
     };
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn code(e: Error) -> ttrpc::Code {
+        match ttrpc::Error::from(e) {
+            ttrpc::Error::RpcStatus(s) => s.get_code(),
+            e => panic!("unexpected error {}", e),
+        }
+    }
+
+    #[test]
+    fn test_error_code() {
+        let e = Error::NotFoundError("process already finished".to_string());
+        assert_eq!(e.kind(), ErrorKind::NotFound);
+        match ttrpc::Error::from(e) {
+            ttrpc::Error::RpcStatus(s) => {
+                assert_eq!(s.get_code(), ttrpc::Code::NOT_FOUND);
+                assert_eq!(s.get_message(), "process already finished");
+            }
+            e => panic!("unexpected error {}", e),
+        }
+
+        let e = Error::AlreadyExists("container test".to_string());
+        assert_eq!(code(e), ttrpc::Code::ALREADY_EXISTS);
+        let e = Error::Unavailable("shim is shutting down".to_string());
+        assert_eq!(code(e), ttrpc::Code::UNAVAILABLE);
+        let e = Error::DeadlineExceeded("start".to_string());
+        assert_eq!(code(e), ttrpc::Code::DEADLINE_EXCEEDED);
+        let e = Error::Unimplemented("stats".to_string());
+        assert_eq!(code(e), ttrpc::Code::UNIMPLEMENTED);
+        assert_eq!(code(other!("unknown")), ttrpc::Code::UNKNOWN);
+
+        let status = ttrpc::get_status(ttrpc::Code::UNAVAILABLE, "closed");
+        let e = Error::Ttrpc(ttrpc::Error::RpcStatus(status));
+        assert_eq!(e.kind(), ErrorKind::Unavailable);
+    }
+
+    #[test]
+    fn test_internal_error_unknown() {
+        // a missing file of the shim is not a missing container or process
+        let e = Error::IoError {
+            context: "open bundle".to_string(),
+            err: std::io::Error::from_raw_os_error(libc::ENOENT),
+        };
+        assert_eq!(e.kind(), ErrorKind::Unknown);
+        assert_eq!(code(e), ttrpc::Code::UNKNOWN);
+        assert_eq!(code(Error::Nix(nix::Error::ESRCH)), ttrpc::Code::UNKNOWN);
+        let e = Error::MountError {
+            context: "unmount rootfs".to_string(),
+            err: nix::Error::EINVAL,
+        };
+        assert_eq!(code(e), ttrpc::Code::UNKNOWN);
+    }
+}
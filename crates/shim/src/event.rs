@@ -1,66 +1,147 @@
+use std::collections::HashMap;
+
+use lazy_static::lazy_static;
+
+use containerd_shim_protos::events::container::*;
+use containerd_shim_protos::events::content::*;
+use containerd_shim_protos::events::image::*;
+use containerd_shim_protos::events::namespace::*;
+use containerd_shim_protos::events::snapshot::*;
 use containerd_shim_protos::events::task::*;
-use containerd_shim_protos::protobuf::Message;
+use containerd_shim_protos::protobuf::well_known_types::Any;
+use containerd_shim_protos::protobuf::{Message, ProtobufResult};
+use containerd_shim_protos::shim::events::Envelope;
+use containerd_shim_protos::topics::*;
+
+use crate::error::Result;
 
 pub trait Event: Message {
     fn topic(&self) -> String;
 }
 
-impl Event for TaskCreate {
-    fn topic(&self) -> String {
-        "/tasks/create".to_string()
-    }
-}
+type Decoder = fn(&[u8]) -> ProtobufResult<TypedEvent>;
 
-impl Event for TaskStart {
-    fn topic(&self) -> String {
-        "/tasks/start".to_string()
-    }
-}
+macro_rules! events {
+    ($($name:ident => $topic:expr),* $(,)?) => {
+        $(
+            impl Event for $name {
+                fn topic(&self) -> String {
+                    $topic.to_string()
+                }
+            }
+        )*
 
-impl Event for TaskExecAdded {
-    fn topic(&self) -> String {
-        "/tasks/exec-added".to_string()
-    }
-}
+        /// An event decoded by the type url of the `Any` that carries it.
+        #[derive(Clone, Debug, PartialEq)]
+        pub enum TypedEvent {
+            $($name($name),)*
+            /// An event of the type that is not registered.
+            Unknown(Any),
+        }
 
-impl Event for TaskExecStarted {
-    fn topic(&self) -> String {
-        "/tasks/exec-started".to_string()
-    }
-}
+        impl TypedEvent {
+            /// Topic of the event, `None` if the type of the event is unknown.
+            pub fn topic(&self) -> Option<String> {
+                match self {
+                    $(TypedEvent::$name(e) => Some(e.topic()),)*
+                    TypedEvent::Unknown(_) => None,
+                }
+            }
+        }
 
-impl Event for TaskPaused {
-    fn topic(&self) -> String {
-        "/tasks/paused".to_string()
-    }
+        lazy_static! {
+            /// Decoders of the events, by the full name of their message types.
+            static ref REGISTRY: HashMap<&'static str, Decoder> = {
+                let mut registry: HashMap<&'static str, Decoder> = HashMap::new();
+                $(
+                    registry.insert(<$name as Message>::descriptor_static().full_name(), |b| {
+                        $name::parse_from_bytes(b).map(TypedEvent::$name)
+                    });
+                )*
+                registry
+            };
+        }
+    };
 }
 
-impl Event for TaskResumed {
-    fn topic(&self) -> String {
-        "/tasks/resumed".to_string()
-    }
+events! {
+    TaskCreate => TASK_CREATE_EVENT_TOPIC,
+    TaskStart => TASK_START_EVENT_TOPIC,
+    TaskExecAdded => TASK_EXEC_ADDED_EVENT_TOPIC,
+    TaskExecStarted => TASK_EXEC_STARTED_EVENT_TOPIC,
+    TaskPaused => TASK_PAUSED_EVENT_TOPIC,
+    TaskResumed => TASK_RESUMED_EVENT_TOPIC,
+    TaskExit => TASK_EXIT_EVENT_TOPIC,
+    TaskDelete => TASK_DELETE_EVENT_TOPIC,
+    TaskOOM => TASK_OOM_EVENT_TOPIC,
+    TaskCheckpointed => TASK_CHECKPOINTED_EVENT_TOPIC,
+    ContainerCreate => CONTAINER_CREATE_EVENT_TOPIC,
+    ContainerUpdate => CONTAINER_UPDATE_EVENT_TOPIC,
+    ContainerDelete => CONTAINER_DELETE_EVENT_TOPIC,
+    ContentDelete => CONTENT_DELETE_EVENT_TOPIC,
+    ImageCreate => IMAGE_CREATE_EVENT_TOPIC,
+    ImageUpdate => IMAGE_UPDATE_EVENT_TOPIC,
+    ImageDelete => IMAGE_DELETE_EVENT_TOPIC,
+    NamespaceCreate => NAMESPACE_CREATE_EVENT_TOPIC,
+    NamespaceUpdate => NAMESPACE_UPDATE_EVENT_TOPIC,
+    NamespaceDelete => NAMESPACE_DELETE_EVENT_TOPIC,
+    SnapshotPrepare => SNAPSHOT_PREPARE_EVENT_TOPIC,
+    SnapshotCommit => SNAPSHOT_COMMIT_EVENT_TOPIC,
+    SnapshotRemove => SNAPSHOT_REMOVE_EVENT_TOPIC,
 }
 
-impl Event for TaskExit {
-    fn topic(&self) -> String {
-        "/tasks/exit".to_string()
-    }
+/// Decode the event carried by the envelope.
+pub fn decode(envelope: &Envelope) -> Result<TypedEvent> {
+    decode_any(envelope.get_event())
 }
 
-impl Event for TaskDelete {
-    fn topic(&self) -> String {
-        "/tasks/delete".to_string()
+/// Decode the event by its type url, which is the full name of the message type,
+/// optionally prefixed by a host like `types.googleapis.com/`.
+///
+/// An event of an unregistered type is returned as `TypedEvent::Unknown`.
+pub fn decode_any(any: &Any) -> Result<TypedEvent> {
+    let type_url = any.get_type_url();
+    let name = type_url.rsplit('/').next().unwrap_or(type_url);
+    match REGISTRY.get(name) {
+        Some(decoder) => Ok(decoder(any.get_value())?),
+        None => Ok(TypedEvent::Unknown(any.clone())),
     }
 }
 
-impl Event for TaskOOM {
-    fn topic(&self) -> String {
-        "/tasks/oom".to_string()
-    }
-}
+#[cfg(test)]
+mod tests {
+    use crate::util::convert_to_any;
+
+    use super::*;
+
+    #[test]
+    fn test_decode() {
+        let mut exit = TaskExit::new();
+        exit.set_container_id("test".to_string());
+        exit.set_exit_status(137);
+        let mut envelope = Envelope::new();
+        envelope.set_topic(exit.topic());
+        envelope.set_event(convert_to_any(Box::new(exit.clone())).unwrap());
+
+        let event = decode(&envelope).unwrap();
+        assert_eq!(event.topic().as_deref(), Some(TASK_EXIT_EVENT_TOPIC));
+        assert_eq!(event, TypedEvent::TaskExit(exit));
+
+        let mut delete = ImageDelete::new();
+        delete.set_name("docker.io/library/busybox:latest".to_string());
+        let mut any = convert_to_any(Box::new(delete.clone())).unwrap();
+        any.set_type_url(format!("types.googleapis.com/{}", any.get_type_url()));
+        assert_eq!(decode_any(&any).unwrap(), TypedEvent::ImageDelete(delete));
+
+        let mut any = Any::new();
+        any.set_type_url("containerd.events.Unknown".to_string());
+        any.set_value(vec![1, 2, 3]);
+        let event = decode_any(&any).unwrap();
+        assert_eq!(event.topic(), None);
+        assert_eq!(event, TypedEvent::Unknown(any));
 
-impl Event for TaskCheckpointed {
-    fn topic(&self) -> String {
-        "/tasks/checkpointed".to_string()
+        let mut any = convert_to_any(Box::new(TaskOOM::new())).unwrap();
+        any.set_value(vec![0xff]);
+        assert!(decode_any(&any).is_err());
     }
 }
@@ -14,7 +14,9 @@
    limitations under the License.
 */
 
-#[derive(Clone, Debug)]
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct Stdio {
     pub stdin: String,
     pub stdout: String,
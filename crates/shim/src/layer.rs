@@ -0,0 +1,370 @@
+/*
+   Copyright The containerd Authors.
+
+   Licensed under the Apache License, Version 2.0 (the "License");
+   you may not use this file except in compliance with the License.
+   You may obtain a copy of the License at
+
+       http://www.apache.org/licenses/LICENSE-2.0
+
+   Unless required by applicable law or agreed to in writing, software
+   distributed under the License is distributed on an "AS IS" BASIS,
+   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+   See the License for the specific language governing permissions and
+   limitations under the License.
+*/
+
+//! Layers add behaviour to any [Task](crate::Task), such as logging, metrics and
+//! admission of the requests, without changing the task itself.
+//!
+//! A task is wrapped into a [Layered] task in [Shim::create_task_service](crate::Shim),
+//! with the layers stacked on it:
+//!
+//! ```ignore
+//! fn create_task_service(&self, publisher: RemotePublisher) -> Self::T {
+//!     let task = TaskService::new(&self.namespace, self.exit.clone(), tx);
+//!     Layered::new(task)
+//!         .layer(LogLayer)
+//!         .layer(AdmissionLayer::new(deny_privileged_exec))
+//!         .layer(ExecRateLimit::new(10, Duration::from_secs(1)))
+//! }
+//! ```
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use log::{debug, info};
+use oci_spec::runtime::{Capability, Process};
+
+#[cfg(feature = "async")]
+pub use crate::asynchronous::layer::*;
+use crate::error::{Error, Result};
+use crate::protos::protobuf::Message;
+use crate::protos::shim::shim::{CreateTaskRequest, ExecProcessRequest};
+use crate::protos::ttrpc;
+#[cfg(not(feature = "async"))]
+pub use crate::synchronous::layer::*;
+use crate::TtrpcResult;
+
+/// A layer sees every request to a [Layered] task, and the response to it.
+///
+/// The methods are named like those of the task, `exec` or `resize_pty` for example,
+/// and the messages can be downcast to the requests and responses with `as_any`.
+pub trait TaskLayer: Send + Sync {
+    /// Called before the request is passed on, an error rejects the request.
+    fn on_request(&self, _method: &str, _req: &dyn Message) -> Result<()> {
+        Ok(())
+    }
+
+    /// Called once the request is handled, or rejected by a layer stacked on this one.
+    fn on_response(
+        &self,
+        _method: &str,
+        _resp: std::result::Result<&dyn Message, &ttrpc::Error>,
+        _elapsed: Duration,
+    ) {
+    }
+}
+
+/// The layers of a [Layered] task, in the order they were added.
+#[derive(Clone, Default)]
+pub(crate) struct Layers(Vec<Arc<dyn TaskLayer>>);
+
+impl Layers {
+    pub(crate) fn push(&mut self, layer: impl TaskLayer + 'static) {
+        self.0.push(Arc::new(layer));
+    }
+
+    /// Pass the request to the layers in order,
+    /// returns how many of them accepted it and the rejection if any.
+    pub(crate) fn enter(&self, method: &str, req: &dyn Message) -> (usize, TtrpcResult<()>) {
+        for (i, layer) in self.0.iter().enumerate() {
+            if let Err(e) = layer.on_request(method, req) {
+                return (i, Err(e.into()));
+            }
+        }
+        (self.0.len(), Ok(()))
+    }
+
+    /// Pass the response to the layers which accepted the request, in reverse order.
+    pub(crate) fn leave<T: Message>(
+        &self,
+        entered: usize,
+        method: &str,
+        resp: &TtrpcResult<T>,
+        elapsed: Duration,
+    ) {
+        for layer in self.0[..entered].iter().rev() {
+            let resp = resp.as_ref().map(|r| r as &dyn Message);
+            layer.on_response(method, resp, elapsed);
+        }
+    }
+}
+
+/// Logs the requests and the responses, the process spec of an exec and the options
+/// of a create are redacted, as they may carry secrets in the environment variables.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct LogLayer;
+
+impl TaskLayer for LogLayer {
+    fn on_request(&self, method: &str, req: &dyn Message) -> Result<()> {
+        info!("{} request: {}", method, redact(req));
+        Ok(())
+    }
+
+    fn on_response(
+        &self,
+        method: &str,
+        resp: std::result::Result<&dyn Message, &ttrpc::Error>,
+        elapsed: Duration,
+    ) {
+        match resp {
+            Ok(resp) => debug!("{} returns in {:?}: {:?}", method, elapsed, resp),
+            Err(e) => info!("{} fails in {:?}: {}", method, elapsed, e),
+        }
+    }
+}
+
+fn redact(req: &dyn Message) -> String {
+    if let Some(req) = req.as_any().downcast_ref::<ExecProcessRequest>() {
+        let mut req = req.clone();
+        if req.has_spec() {
+            req.clear_spec();
+            return format!("{:?} spec: <redacted>", req);
+        }
+        return format!("{:?}", req);
+    }
+    if let Some(req) = req.as_any().downcast_ref::<CreateTaskRequest>() {
+        let mut req = req.clone();
+        if req.has_options() {
+            req.clear_options();
+            return format!("{:?} options: <redacted>", req);
+        }
+        return format!("{:?}", req);
+    }
+    format!("{:?}", req)
+}
+
+/// Latency of the requests of a method.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct RpcStats {
+    pub count: u64,
+    pub errors: u64,
+    pub total: Duration,
+    pub max: Duration,
+}
+
+/// Collects the latency of the requests by method,
+/// a clone of the layer reads the stats collected by the layer in the task.
+#[derive(Clone, Default)]
+pub struct MetricsLayer {
+    stats: Arc<Mutex<HashMap<String, RpcStats>>>,
+}
+
+impl MetricsLayer {
+    pub fn stats(&self) -> HashMap<String, RpcStats> {
+        self.stats.lock().unwrap().clone()
+    }
+}
+
+impl TaskLayer for MetricsLayer {
+    fn on_response(
+        &self,
+        method: &str,
+        resp: std::result::Result<&dyn Message, &ttrpc::Error>,
+        elapsed: Duration,
+    ) {
+        let mut stats = self.stats.lock().unwrap();
+        let s = stats.entry(method.to_string()).or_default();
+        s.count += 1;
+        if resp.is_err() {
+            s.errors += 1;
+        }
+        s.total += elapsed;
+        s.max = s.max.max(elapsed);
+    }
+}
+
+/// Admits the requests by a check, which rejects a request with an error.
+pub struct AdmissionLayer<F> {
+    check: F,
+}
+
+impl<F> AdmissionLayer<F>
+where
+    F: Fn(&str, &dyn Message) -> Result<()> + Send + Sync,
+{
+    pub fn new(check: F) -> Self {
+        Self { check }
+    }
+}
+
+impl<F> TaskLayer for AdmissionLayer<F>
+where
+    F: Fn(&str, &dyn Message) -> Result<()> + Send + Sync,
+{
+    fn on_request(&self, method: &str, req: &dyn Message) -> Result<()> {
+        (self.check)(method, req)
+    }
+}
+
+/// A check of [AdmissionLayer] rejecting the execs with `CAP_SYS_ADMIN`.
+pub fn deny_privileged_exec(_method: &str, req: &dyn Message) -> Result<()> {
+    let req = match req.as_any().downcast_ref::<ExecProcessRequest>() {
+        Some(req) => req,
+        None => return Ok(()),
+    };
+    let process: Process = serde_json::from_slice(req.get_spec().get_value())
+        .map_err(|e| Error::InvalidArgument(format!("failed to parse exec spec: {}", e)))?;
+    let privileged = process.capabilities().as_ref().map_or(false, |caps| {
+        [
+            caps.bounding(),
+            caps.effective(),
+            caps.permitted(),
+            caps.inheritable(),
+            caps.ambient(),
+        ]
+        .iter()
+        .any(|set| {
+            set.as_ref()
+                .map_or(false, |set| set.contains(&Capability::SysAdmin))
+        })
+    });
+    if privileged {
+        return Err(Error::InvalidArgument(format!(
+            "privileged exec {} is not allowed",
+            req.get_exec_id()
+        )));
+    }
+    Ok(())
+}
+
+/// Limits the rate of exec by a token bucket, which holds up to `burst` execs
+/// and is refilled at `burst` per `period`. The execs over the limit are rejected
+/// as unavailable, which tells the client to retry later.
+pub struct ExecRateLimit {
+    burst: f64,
+    period: Duration,
+    bucket: Mutex<(f64, Instant)>,
+}
+
+impl ExecRateLimit {
+    pub fn new(burst: u32, period: Duration) -> Self {
+        Self {
+            burst: burst as f64,
+            period,
+            bucket: Mutex::new((burst as f64, Instant::now())),
+        }
+    }
+}
+
+impl TaskLayer for ExecRateLimit {
+    fn on_request(&self, method: &str, _req: &dyn Message) -> Result<()> {
+        if method != "exec" {
+            return Ok(());
+        }
+        let mut bucket = self.bucket.lock().unwrap();
+        let (tokens, last) = &mut *bucket;
+        let now = Instant::now();
+        let refill = now.duration_since(*last).as_secs_f64() / self.period.as_secs_f64();
+        *tokens = (*tokens + refill * self.burst).min(self.burst);
+        *last = now;
+        if *tokens < 1.0 {
+            return Err(Error::Unavailable("exec rate limit exceeded".to_string()));
+        }
+        *tokens -= 1.0;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use oci_spec::runtime::{LinuxCapabilitiesBuilder, ProcessBuilder};
+
+    use crate::protos::protobuf::well_known_types::Any;
+    use crate::protos::protobuf::SingularPtrField;
+    use crate::protos::shim::shim::StateResponse;
+
+    use super::*;
+
+    fn exec_request(process: &Process) -> ExecProcessRequest {
+        let mut spec = Any::new();
+        spec.set_value(serde_json::to_vec(process).unwrap());
+        ExecProcessRequest {
+            exec_id: "exec1".to_string(),
+            spec: SingularPtrField::some(spec),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_layers() {
+        let metrics = MetricsLayer::default();
+        let mut layers = Layers::default();
+        layers.push(metrics.clone());
+        layers.push(ExecRateLimit::new(1, Duration::from_secs(3600)));
+
+        let req = ExecProcessRequest::new();
+        let (entered, res) = layers.enter("exec", &req);
+        assert_eq!(entered, 2);
+        assert!(res.is_ok());
+        let resp: TtrpcResult<StateResponse> = Ok(StateResponse::new());
+        layers.leave(entered, "exec", &resp, Duration::from_millis(5));
+
+        // the second exec is rejected by the rate limit, below the metrics
+        let (entered, res) = layers.enter("exec", &req);
+        assert_eq!(entered, 1);
+        match res {
+            Err(ttrpc::Error::RpcStatus(s)) => assert_eq!(s.get_code(), ttrpc::Code::UNAVAILABLE),
+            _ => panic!("exec should be rejected"),
+        }
+        let resp: TtrpcResult<StateResponse> = res.map(|_| StateResponse::new());
+        layers.leave(entered, "exec", &resp, Duration::from_millis(1));
+
+        let stats = metrics.stats();
+        assert_eq!(
+            stats["exec"],
+            RpcStats {
+                count: 2,
+                errors: 1,
+                total: Duration::from_millis(6),
+                max: Duration::from_millis(5),
+            }
+        );
+        assert!(layers.enter("state", &req).1.is_ok());
+    }
+
+    #[test]
+    fn test_deny_privileged_exec() {
+        let process = ProcessBuilder::default()
+            .args(vec!["sh".to_string()])
+            .build()
+            .unwrap();
+        assert!(deny_privileged_exec("exec", &exec_request(&process)).is_ok());
+
+        let caps = LinuxCapabilitiesBuilder::default()
+            .effective(vec![Capability::SysAdmin].into_iter().collect())
+            .build()
+            .unwrap();
+        let process = ProcessBuilder::default()
+            .args(vec!["sh".to_string()])
+            .capabilities(caps)
+            .build()
+            .unwrap();
+        let err = deny_privileged_exec("exec", &exec_request(&process)).unwrap_err();
+        assert!(matches!(err, Error::InvalidArgument(_)));
+        assert!(deny_privileged_exec("state", &StateResponse::new()).is_ok());
+    }
+
+    #[test]
+    fn test_redact() {
+        let process = ProcessBuilder::default()
+            .env(vec!["PASSWORD=secret".to_string()])
+            .build()
+            .unwrap();
+        let log = redact(&exec_request(&process));
+        assert!(!log.contains("secret"));
+        assert!(log.contains("exec1"));
+    }
+}
@@ -32,14 +32,18 @@
 //! ```
 //!
 
-use std::collections::hash_map::DefaultHasher;
+use std::env;
 use std::fs::File;
-use std::hash::Hasher;
+use std::io::ErrorKind;
+use std::os::unix::fs::FileTypeExt;
 use std::os::unix::io::RawFd;
-use std::os::unix::net::UnixListener;
-use std::path::{Path, PathBuf};
+use std::os::unix::net::{UnixListener, UnixStream};
+use std::path::Path;
 
+use log::warn;
 use nix::ioctl_write_ptr_bad;
+use serde::Serialize;
+use sha2::{Digest, Sha256};
 
 pub use containerd_shim_protos as protos;
 pub use protos::shim::shim::DeleteResponse;
@@ -57,23 +61,33 @@ pub mod error;
 mod args;
 #[cfg(feature = "async")]
 pub mod asynchronous;
+mod bookkeeping;
 pub mod cgroup;
+pub mod client;
 pub mod event;
 pub mod io;
+pub mod layer;
 mod logger;
 pub mod monitor;
 pub mod mount;
+pub mod publish;
 mod reap;
-#[cfg(not(feature = "async"))]
+/// The synchronous API, which is also built with the `async` feature, so that a shim
+/// can serve a sync [Task](protos::shim_sync::Task) by the async server while it moves to async.
 pub mod synchronous;
+#[cfg(feature = "testing")]
+#[cfg_attr(docsrs, doc(cfg(feature = "testing")))]
+pub mod testing;
 pub mod util;
 
 /// Generated request/response structures.
 pub mod api {
     pub use super::protos::api::Status;
+    pub use super::protos::sandbox::sandbox::*;
     pub use super::protos::shim::oci::Options;
     pub use super::protos::shim::shim::*;
     pub use super::protos::types::empty::Empty;
+    pub use super::protos::types::platform::Platform;
 }
 
 macro_rules! cfg_not_async {
@@ -99,8 +113,12 @@ macro_rules! cfg_async {
 cfg_not_async! {
     pub use crate::synchronous::*;
     pub use crate::synchronous::console;
+    pub use crate::synchronous::container;
+    pub use crate::synchronous::processes;
+    pub use crate::synchronous::task;
     pub use crate::synchronous::publisher;
     pub use protos::shim::shim_ttrpc::Task;
+    pub use protos::shim_sync::Sandbox;
     pub use protos::ttrpc::TtrpcContext;
 }
 
@@ -112,6 +130,7 @@ cfg_async! {
     pub use crate::asynchronous::task;
     pub use crate::asynchronous::publisher;
     pub use protos::shim_async::Task;
+    pub use protos::shim_async::Sandbox;
     pub use protos::ttrpc::r#async::TtrpcContext;
 }
 
@@ -128,6 +147,35 @@ pub struct Config {
     pub no_reaper: bool,
     /// Disables setting the shim as a child subreaper.
     pub no_sub_reaper: bool,
+    /// Configures the queue and reconnection of the event publisher.
+    pub publisher: publish::PublisherConfig,
+    /// Advertises the task API v3 to containerd on start, by writing the bootstrap params in JSON
+    /// instead of the plain socket address. containerd before 2.0 only accepts the address.
+    pub advertise_task_v3: bool,
+}
+
+/// Bootstrap params written by `start` for containerd to connect to the shim.
+#[derive(Debug, Serialize)]
+struct BootstrapParams<'a> {
+    /// Version of the task API served by the shim.
+    version: u32,
+    /// Address of the shim socket.
+    address: &'a str,
+    /// Protocol of the shim socket.
+    protocol: &'a str,
+}
+
+/// Make the output of `start` from the address of the shim socket.
+fn bootstrap_output(address: &str, config: &Config) -> Result<String> {
+    if !config.advertise_task_v3 {
+        return Ok(address.to_string());
+    }
+    let params = BootstrapParams {
+        version: 3,
+        address,
+        protocol: "ttrpc",
+    };
+    Ok(serde_json::to_string(&params)?)
 }
 
 /// Startup options received from containerd to start new shim instance.
@@ -161,20 +209,113 @@ pub const SOCKET_ROOT: &str = "/run/containerd";
 pub const SOCKET_ROOT: &str = "/var/run/containerd";
 
 /// Make socket path from containerd socket path, namespace and id.
+///
+/// The path is the same as the one of a Go shim, named by the hex sha256 of
+/// `socket_path/namespace/id` under `SOCKET_ROOT/s`, so shims of both languages find
+/// each other's sockets. A rootless shim puts it under `$XDG_RUNTIME_DIR/containerd/s`.
 pub fn socket_address(socket_path: &str, namespace: &str, id: &str) -> String {
-    let path = PathBuf::from(socket_path)
-        .join(namespace)
-        .join(id)
-        .display()
-        .to_string();
-
-    let hash = {
-        let mut hasher = DefaultHasher::new();
-        hasher.write(path.as_bytes());
-        hasher.finish()
+    shim_socket_address(&socket_root(), socket_path, namespace, id)
+}
+
+fn shim_socket_address(root: &str, socket_path: &str, namespace: &str, id: &str) -> String {
+    let path = join_path(&[socket_path, namespace, id]);
+    format!("unix://{}/s/{:x}", root, Sha256::digest(path.as_bytes()))
+}
+
+fn socket_root() -> String {
+    match env::var("XDG_RUNTIME_DIR") {
+        Ok(dir) if !dir.is_empty() && !nix::unistd::geteuid().is_root() => {
+            format!("{}/containerd", dir)
+        }
+        _ => SOCKET_ROOT.to_string(),
+    }
+}
+
+/// Remove the sockets leaked by killed shims of a release before [socket_address] took the
+/// address of the Go shim. They listened on `SOCKET_ROOT/<hash>.sock`, named by a hash that
+/// depends on the toolchain which built them, so instead of computing the name, every socket
+/// of that form nobody listens on any more is removed.
+fn remove_legacy_sockets(root: &Path) -> Result<()> {
+    let entries = match std::fs::read_dir(root) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == ErrorKind::NotFound => return Ok(()),
+        Err(e) => return Err(io_error!(e, "read dir {}", root.display())(e)),
     };
+    for entry in entries {
+        let entry = entry.map_err(io_error!(e, "read dir {}", root.display()))?;
+        if !is_legacy_socket_name(&entry.file_name().to_string_lossy()) {
+            continue;
+        }
+        let address = format!("unix://{}", entry.path().display());
+        remove_stale_socket(&address)
+            .unwrap_or_else(|e| warn!("failed to remove legacy socket: {}", e));
+    }
+    Ok(())
+}
+
+/// The name of a legacy socket is the hex of a 64 bits hash, which is not zero padded.
+fn is_legacy_socket_name(name: &str) -> bool {
+    match name.strip_suffix(".sock") {
+        Some(hash) => {
+            !hash.is_empty()
+                && hash.len() <= 16
+                && hash
+                    .chars()
+                    .all(|c| c.is_ascii_digit() || ('a'..='f').contains(&c))
+        }
+        None => false,
+    }
+}
 
-    format!("unix://{}/{:x}.sock", SOCKET_ROOT, hash)
+fn remove_stale_socket(address: &str) -> Result<()> {
+    let path = parse_sockaddr(address);
+    match Path::new(path).metadata() {
+        Ok(md) if md.file_type().is_socket() => {}
+        _ => return Ok(()),
+    }
+    match UnixStream::connect(path) {
+        Err(e) if e.kind() == ErrorKind::ConnectionRefused => {
+            std::fs::remove_file(path).map_err(io_error!(e, "remove socket {}", address))
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Join the elements of a path like `filepath.Join` of Go, which cleans the path lexically,
+/// as the hash of the socket address is taken over the joined path.
+fn join_path(elems: &[&str]) -> String {
+    let path = elems
+        .iter()
+        .filter(|e| !e.is_empty())
+        .copied()
+        .collect::<Vec<_>>()
+        .join("/");
+    if path.is_empty() {
+        return path;
+    }
+    let rooted = path.starts_with('/');
+    let mut parts: Vec<&str> = vec![];
+    for part in path.split('/') {
+        match part {
+            "" | "." => {}
+            ".." => match parts.last() {
+                Some(&last) if last != ".." => {
+                    parts.pop();
+                }
+                _ if rooted => {}
+                _ => parts.push(".."),
+            },
+            part => parts.push(part),
+        }
+    }
+    let cleaned = parts.join("/");
+    if rooted {
+        format!("/{}", cleaned)
+    } else if cleaned.is_empty() {
+        ".".to_string()
+    } else {
+        cleaned
+    }
 }
 
 fn parse_sockaddr(addr: &str) -> &str {
@@ -204,7 +345,10 @@ pub struct Console {
 
 #[cfg(test)]
 mod tests {
-    use crate::start_listener;
+    use crate::{
+        bootstrap_output, is_legacy_socket_name, join_path, remove_legacy_sockets,
+        remove_stale_socket, shim_socket_address, start_listener, Config,
+    };
 
     #[test]
     fn test_start_listener() {
@@ -229,4 +373,121 @@ mod tests {
         let context = std::fs::read_to_string(&txt_file).unwrap();
         assert_eq!(context, "test");
     }
+
+    #[test]
+    fn test_socket_address() {
+        // the addresses are those computed by shim.SocketAddress of containerd in Go
+        assert_eq!(
+            shim_socket_address(
+                "/run/containerd",
+                "/run/containerd/containerd.sock.ttrpc",
+                "default",
+                "1234"
+            ),
+            "unix:///run/containerd/s/9cc5a099edced03d9bf3717795b89fd4d78a1122f4e259259fbf39df4f13e558"
+        );
+        let sandbox = "5a1c0a2f7c9e5b8e3d4f6a7b8c9d0e1f2a3b4c5d6e7f8a9b0c1d2e3f4a5b6c7d";
+        let address =
+            "unix:///run/containerd/s/15895311daa79a27cc01db40da79e911e2219c703f2858c274d5c2afb878247f";
+        assert_eq!(
+            shim_socket_address(
+                "/run/containerd",
+                "/run/containerd/containerd.sock",
+                "k8s.io",
+                sandbox
+            ),
+            address
+        );
+        // the path is cleaned before it is hashed
+        assert_eq!(
+            shim_socket_address(
+                "/run/containerd",
+                "/run/containerd//containerd.sock/",
+                "k8s.io",
+                sandbox
+            ),
+            address
+        );
+        assert_eq!(
+            shim_socket_address(
+                "/run/user/1000/containerd",
+                "/home/user/.local/share/containerd/containerd.sock",
+                "default",
+                "redis"
+            ),
+            "unix:///run/user/1000/containerd/s/67b008d6e9569f012717c7d08c75a04796a17d2bc9ca241636e175d0c86a1bcc"
+        );
+    }
+
+    #[test]
+    fn test_legacy_socket_name() {
+        assert!(is_legacy_socket_name("169d49074105e2a4.sock"));
+        assert!(is_legacy_socket_name("9d49074105e2a4.sock"));
+        assert!(!is_legacy_socket_name("containerd.sock"));
+        assert!(!is_legacy_socket_name("169D49074105E2A4.sock"));
+        assert!(!is_legacy_socket_name("169d49074105e2a4e.sock"));
+        assert!(!is_legacy_socket_name(".sock"));
+        assert!(!is_legacy_socket_name("169d49074105e2a4"));
+    }
+
+    #[test]
+    fn test_remove_legacy_sockets() {
+        let tmpdir = tempfile::tempdir().unwrap();
+        let address = |name: &str| format!("unix://{}", tmpdir.path().join(name).display());
+        let serving = start_listener(&address("a1.sock")).unwrap();
+        drop(start_listener(&address("b2.sock")).unwrap());
+        drop(start_listener(&address("containerd.sock")).unwrap());
+
+        remove_legacy_sockets(tmpdir.path()).unwrap();
+        assert!(tmpdir.path().join("a1.sock").exists());
+        assert!(!tmpdir.path().join("b2.sock").exists());
+        assert!(tmpdir.path().join("containerd.sock").exists());
+        drop(serving);
+
+        remove_legacy_sockets(&tmpdir.path().join("none")).unwrap();
+    }
+
+    #[test]
+    fn test_remove_stale_socket() {
+        let tmpdir = tempfile::tempdir().unwrap();
+        let path = tmpdir.path().join("socket");
+        let address = format!("unix://{}", path.display());
+
+        // the socket is kept as long as a shim listens on it
+        let listener = start_listener(&address).unwrap();
+        remove_stale_socket(&address).unwrap();
+        assert!(path.exists());
+
+        drop(listener);
+        remove_stale_socket(&address).unwrap();
+        assert!(!path.exists());
+        // nothing to remove
+        remove_stale_socket(&address).unwrap();
+    }
+
+    #[test]
+    fn test_join_path() {
+        assert_eq!(
+            join_path(&["/run/containerd/", "ns", "id"]),
+            "/run/containerd/ns/id"
+        );
+        assert_eq!(join_path(&["/a/./b/../c", "", "d"]), "/a/c/d");
+        assert_eq!(join_path(&["/..", "a"]), "/a");
+        assert_eq!(join_path(&["../a", "../../b"]), "../../b");
+        assert_eq!(join_path(&["a", ".."]), ".");
+        assert_eq!(join_path(&["", ""]), "");
+    }
+
+    #[test]
+    fn test_bootstrap_output() {
+        let address = "unix:///run/containerd/s/1234";
+        let mut config = Config::default();
+        assert_eq!(bootstrap_output(address, &config).unwrap(), address);
+
+        config.advertise_task_v3 = true;
+        assert_eq!(
+            bootstrap_output(address, &config).unwrap(),
+            r#"{"version":3,"address":"unix:///run/containerd/s/1234","protocol":"ttrpc"}"#
+        );
+    }
 }
@@ -13,13 +13,48 @@
    See the License for the specific language governing permissions and
    limitations under the License.
 */
+use std::collections::{HashMap, VecDeque};
 use std::fmt;
+use std::sync::Mutex;
+
+use lazy_static::lazy_static;
 
 #[cfg(feature = "async")]
 pub use crate::asynchronous::monitor::*;
 #[cfg(not(feature = "async"))]
 pub use crate::synchronous::monitor::*;
 
+/// Bound of the exits stashed by a [ProcessIndex], and of the pids claimed by [claim_exit],
+/// the oldest ones are dropped beyond it.
+const MAX_STASHED: usize = 1024;
+
+lazy_static! {
+    static ref CLAIMED: Mutex<VecDeque<i32>> = Mutex::new(VecDeque::new());
+}
+
+/// Claim the exit of a child of the shim which is waited by its spawner on a subscription
+/// of its own, such as a runc command or a mount helper, so that a [ProcessIndex] doesn't
+/// stash it as the early exit of a process being started. The claim is taken back once the
+/// index sees the exit.
+///
+/// The child may be reaped before it is claimed, its exit is stashed then until the starts
+/// in flight are finished.
+pub fn claim_exit(pid: i32) {
+    let mut claimed = CLAIMED.lock().unwrap();
+    if claimed.len() >= MAX_STASHED {
+        claimed.pop_front();
+    }
+    claimed.push_back(pid);
+}
+
+fn take_claim(pid: i32) -> bool {
+    let mut claimed = CLAIMED.lock().unwrap();
+    match claimed.iter().position(|&p| p == pid) {
+        Some(i) => claimed.remove(i).is_some(),
+        None => false,
+    }
+}
+
 #[derive(Clone, Eq, Hash, PartialEq)]
 pub enum Topic {
     Pid,
@@ -59,3 +94,143 @@ pub enum Subject {
     // if exec is empty, then the event is for the container
     Exec(String, String),
 }
+
+/// A process of a container, the exec id is `None` for the init process.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ProcessKey {
+    pub id: String,
+    pub exec_id: Option<String>,
+}
+
+/// Index of the processes of the containers by pid, so that an exit is
+/// dispatched to its process without scanning all the containers.
+///
+/// A process may exit before the starter gets its pid from the runtime. While a
+/// start is in flight, the exits of unknown pids which are not claimed by [claim_exit]
+/// are stashed, and handed back to the starter by [ProcessIndex::started] once the pid
+/// is known. The stash is bounded, the oldest exits are dropped first.
+#[derive(Default)]
+pub struct ProcessIndex {
+    inner: Mutex<IndexInner>,
+}
+
+#[derive(Default)]
+struct IndexInner {
+    processes: HashMap<i32, ProcessKey>,
+    starting: usize,
+    early_exits: VecDeque<(i32, i32)>,
+}
+
+impl ProcessIndex {
+    pub fn insert(&self, pid: i32, id: &str, exec_id: Option<&str>) {
+        let key = ProcessKey {
+            id: id.to_string(),
+            exec_id: exec_id.map(String::from),
+        };
+        self.inner.lock().unwrap().processes.insert(pid, key);
+    }
+
+    /// Stash the exits of unknown pids until the matching [ProcessIndex::started].
+    pub fn starting(&self) {
+        self.inner.lock().unwrap().starting += 1;
+    }
+
+    /// Index the started process, `pid` is `None` if it failed to start.
+    /// Returns the exit code if the process has exited before being indexed,
+    /// the caller is responsible to replay the exit.
+    pub fn started(&self, pid: Option<i32>, id: &str, exec_id: Option<&str>) -> Option<i32> {
+        let mut inner = self.inner.lock().unwrap();
+        inner.starting = inner.starting.saturating_sub(1);
+        let exit_code = pid.and_then(|pid| {
+            inner.processes.insert(
+                pid,
+                ProcessKey {
+                    id: id.to_string(),
+                    exec_id: exec_id.map(String::from),
+                },
+            );
+            let i = inner.early_exits.iter().position(|&(p, _)| p == pid)?;
+            inner.early_exits.remove(i).map(|(_, code)| code)
+        });
+        if inner.starting == 0 {
+            inner.early_exits.clear();
+        }
+        exit_code
+    }
+
+    /// Take the process of the exited pid out of the index,
+    /// returns `None` if the pid is not a process of the containers.
+    pub fn exited(&self, pid: i32, exit_code: i32) -> Option<ProcessKey> {
+        let mut inner = self.inner.lock().unwrap();
+        let key = inner.processes.remove(&pid);
+        let claimed = take_claim(pid);
+        if key.is_none() && !claimed && inner.starting > 0 {
+            if inner.early_exits.len() >= MAX_STASHED {
+                inner.early_exits.pop_front();
+            }
+            inner.early_exits.push_back((pid, exit_code));
+        }
+        key
+    }
+
+    /// Remove the exec process of the container, or all of its processes if `exec_id` is `None`.
+    pub fn remove(&self, id: &str, exec_id: Option<&str>) {
+        self.inner
+            .lock()
+            .unwrap()
+            .processes
+            .retain(|_, k| k.id != id || (exec_id.is_some() && k.exec_id.as_deref() != exec_id));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_process_index() {
+        let index = ProcessIndex::default();
+        index.insert(10, "c1", None);
+        index.insert(11, "c1", Some("e1"));
+        index.insert(20, "c2", None);
+
+        // exits of unknown pids are dropped when nothing is starting
+        assert_eq!(index.exited(99, 0), None);
+        let key = index.exited(11, 1).unwrap();
+        assert_eq!(key.id, "c1");
+        assert_eq!(key.exec_id.as_deref(), Some("e1"));
+        assert_eq!(index.exited(11, 1), None);
+
+        // the exit before the pid is indexed is handed back to the starter
+        index.starting();
+        index.starting();
+        assert_eq!(index.exited(12, 137), None);
+        assert_eq!(index.exited(13, 0), None);
+        assert_eq!(index.started(None, "c1", Some("e3")), None);
+        assert_eq!(index.started(Some(12), "c1", Some("e2")), Some(137));
+        // the stash is cleared once no start is in flight
+        index.starting();
+        assert_eq!(index.started(Some(13), "c2", Some("e1")), None);
+
+        // the exits claimed by their spawners are not stashed
+        claim_exit(14);
+        index.starting();
+        assert_eq!(index.exited(14, 1), None);
+        assert_eq!(index.started(Some(14), "c1", Some("e4")), None);
+
+        index.remove("c1", Some("e2"));
+        assert_eq!(index.exited(12, 0), None);
+        index.remove("c1", None);
+        assert_eq!(index.exited(10, 0), None);
+        assert_eq!(index.exited(20, 0).unwrap().id, "c2");
+
+        // the oldest exits are dropped beyond the bound
+        index.starting();
+        index.starting();
+        for pid in 1000..1001 + MAX_STASHED as i32 {
+            assert_eq!(index.exited(pid, 1), None);
+        }
+        assert_eq!(index.started(Some(1000), "c3", None), None);
+        assert_eq!(index.started(Some(1001), "c3", Some("e1")), Some(1));
+    }
+}
@@ -16,14 +16,36 @@
 #![allow(unused)]
 
 use std::collections::HashMap;
+use std::ffi::CString;
+use std::fs::{File, OpenOptions};
+use std::io::Read;
 use std::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, Not};
-use std::path::Path;
+use std::os::unix::ffi::OsStrExt;
+use std::os::unix::io::{AsRawFd, FromRawFd, RawFd};
+use std::path::{Path, PathBuf};
+use std::process::Stdio;
+use std::time::Duration;
 
 use lazy_static::lazy_static;
+use log::warn;
 #[cfg(target_os = "linux")]
-use nix::mount::{mount, MsFlags};
+use nix::errno::Errno;
+#[cfg(target_os = "linux")]
+use nix::mount::{mount, umount2, MntFlags, MsFlags};
+#[cfg(target_os = "linux")]
+use nix::sched::{clone, unshare, CloneFlags};
+#[cfg(target_os = "linux")]
+use nix::sys::signal::{kill, Signal};
+#[cfg(target_os = "linux")]
+use nix::unistd::Pid;
 
 use crate::error::{Error, Result};
+use crate::monitor::{claim_exit, ExitEvent, Subject, Topic};
+use crate::protos::api::Mount as ApiMount;
+use crate::protos::protobuf::RepeatedField;
+use crate::util::AsOption;
+
+const MOUNTINFO_PATH: &str = "/proc/self/mountinfo";
 
 #[cfg(target_os = "linux")]
 struct Flag {
@@ -223,6 +245,231 @@ lazy_static! {
     static ref MS_BIND_RO: MsFlags = MsFlags::MS_BIND.bitor(MsFlags::MS_RDONLY);
 }
 
+/// A filesystem mount, the counterpart of the `Mount` in the containerd api.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Mount {
+    /// Type of the filesystem, e.g. `overlay` or `fuse.sshfs`.
+    pub mount_type: String,
+    pub source: String,
+    pub target: String,
+    pub options: Vec<String>,
+}
+
+impl Mount {
+    /// Check that the options of the mount can be applied together,
+    /// of the options setting and clearing the same flag the last one wins, like mount(8).
+    pub fn validate(&self) -> Result<()> {
+        let has_option = |prefix: &str| self.options.iter().any(|o| o.starts_with(prefix));
+        let invalid = |msg: &str| {
+            Err(Error::InvalidArgument(format!(
+                "{} in mount {} of type {:?} with options {:?}",
+                msg, self.source, self.mount_type, self.options
+            )))
+        };
+
+        #[cfg(target_os = "linux")]
+        {
+            let mut set = MsFlags::empty();
+            for f in self
+                .options
+                .iter()
+                .filter_map(|o| MOUNT_FLAGS.get(o.as_str()))
+            {
+                if f.clear {
+                    set.bitand_assign(f.flags.not());
+                } else {
+                    set.bitor_assign(f.flags);
+                }
+            }
+            let bind = set.contains(MsFlags::MS_BIND);
+            if (bind || has_option("loop")) && self.source.is_empty() {
+                return invalid("no source");
+            }
+            if (has_option(UID_MAP_OPTION) || has_option(GID_MAP_OPTION)) && !bind {
+                return invalid("idmap without bind");
+            }
+        }
+        if has_option(UID_MAP_OPTION) != has_option(GID_MAP_OPTION) {
+            return invalid("X-containerd.uidmap and X-containerd.gidmap are not both set");
+        }
+        if self.mount_type == "overlay" && !has_option("lowerdir=") {
+            return invalid("no lowerdir");
+        }
+        Ok(())
+    }
+
+    /// Validate the mount and mount it to the target.
+    pub fn mount(&self, target: impl AsRef<Path>) -> Result<()> {
+        self.validate()?;
+        mount_rootfs(
+            self.mount_type.as_option(),
+            self.source.as_option(),
+            &self.options,
+            target,
+        )
+    }
+}
+
+impl From<&ApiMount> for Mount {
+    fn from(m: &ApiMount) -> Self {
+        Self {
+            mount_type: m.field_type.to_string(),
+            source: m.source.to_string(),
+            target: m.target.to_string(),
+            options: m.options.to_vec(),
+        }
+    }
+}
+
+impl From<Mount> for ApiMount {
+    fn from(m: Mount) -> Self {
+        Self {
+            field_type: m.mount_type,
+            source: m.source,
+            target: m.target,
+            options: RepeatedField::from_vec(m.options),
+            ..Default::default()
+        }
+    }
+}
+
+impl From<&MountInfo> for Mount {
+    /// The options are the per mount options, followed by the superblock ones.
+    fn from(info: &MountInfo) -> Self {
+        let mut options = info.options.clone();
+        for o in info.super_options.iter() {
+            // the read-only state of the superblock is not the one of this mount
+            if o != "ro" && o != "rw" && !options.contains(o) {
+                options.push(o.to_string());
+            }
+        }
+        Self {
+            mount_type: info.fs_type.to_string(),
+            source: info.source.to_string(),
+            target: info.mount_point.to_string(),
+            options,
+        }
+    }
+}
+
+/// A mount of the current mount namespace, as listed in /proc/self/mountinfo.
+///
+/// See proc(5) for the meaning of the fields.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MountInfo {
+    pub id: u32,
+    pub parent: u32,
+    pub major: u32,
+    pub minor: u32,
+    /// Pathname of the directory in the filesystem which forms the root of this mount.
+    pub root: String,
+    pub mount_point: String,
+    /// Per mount options.
+    pub options: Vec<String>,
+    /// Optional fields like `shared:N` or `master:N`.
+    pub optional: Vec<String>,
+    pub fs_type: String,
+    pub source: String,
+    /// Per superblock options.
+    pub super_options: Vec<String>,
+}
+
+impl MountInfo {
+    /// Returns the peer group id if the mount is shared.
+    pub fn shared(&self) -> Option<u32> {
+        self.optional_id("shared:")
+    }
+
+    /// Returns the peer group id of the master if the mount is a slave.
+    pub fn master(&self) -> Option<u32> {
+        self.optional_id("master:")
+    }
+
+    /// Returns the nearest dominant peer group id the slave mount receives propagation from.
+    pub fn propagate_from(&self) -> Option<u32> {
+        self.optional_id("propagate_from:")
+    }
+
+    pub fn unbindable(&self) -> bool {
+        self.optional.iter().any(|o| o == "unbindable")
+    }
+
+    pub fn private(&self) -> bool {
+        self.shared().is_none() && self.master().is_none() && !self.unbindable()
+    }
+
+    fn optional_id(&self, prefix: &str) -> Option<u32> {
+        self.optional
+            .iter()
+            .find_map(|o| o.strip_prefix(prefix))
+            .and_then(|id| id.parse().ok())
+    }
+}
+
+/// Parse the content of a mountinfo file, e.g. /proc/self/mountinfo.
+pub fn parse_mountinfo(content: &str) -> Result<Vec<MountInfo>> {
+    content
+        .lines()
+        .filter(|l| !l.trim().is_empty())
+        .map(parse_mountinfo_line)
+        .collect()
+}
+
+fn parse_mountinfo_line(line: &str) -> Result<MountInfo> {
+    let invalid = || other!("invalid mountinfo line {:?}", line);
+    // the optional fields are variable in number, and are terminated by a single hyphen
+    let (left, right) = line.split_once(" - ").ok_or_else(invalid)?;
+    let fields: Vec<&str> = left.split(' ').collect();
+    let super_fields: Vec<&str> = right.split(' ').collect();
+    if fields.len() < 6 || super_fields.len() < 3 {
+        return Err(invalid());
+    }
+    let (major, minor) = fields[2].split_once(':').ok_or_else(invalid)?;
+    let number = |s: &str| s.parse::<u32>().map_err(|_| invalid());
+    let split_options = |s: &str| s.split(',').map(unescape_octal).collect();
+    Ok(MountInfo {
+        id: number(fields[0])?,
+        parent: number(fields[1])?,
+        major: number(major)?,
+        minor: number(minor)?,
+        root: unescape_octal(fields[3]),
+        mount_point: unescape_octal(fields[4]),
+        options: split_options(fields[5]),
+        optional: fields[6..].iter().map(|f| f.to_string()).collect(),
+        fs_type: unescape_octal(super_fields[0]),
+        source: unescape_octal(super_fields[1]),
+        super_options: split_options(super_fields[2]),
+    })
+}
+
+/// Returns the mounts of the current mount namespace.
+pub fn get_mounts() -> Result<Vec<MountInfo>> {
+    let content =
+        std::fs::read_to_string(MOUNTINFO_PATH).map_err(io_error!(e, "read {}", MOUNTINFO_PATH))?;
+    parse_mountinfo(&content)
+}
+
+/// Returns the topmost mount on the mount point, or `None` if it is not a mount point.
+pub fn lookup_mount(mount_point: impl AsRef<Path>) -> Result<Option<MountInfo>> {
+    let mount_point = mount_point.as_ref();
+    Ok(get_mounts()?
+        .into_iter()
+        .filter(|m| Path::new(&m.mount_point) == mount_point)
+        .last())
+}
+
+/// Returns the mounts below the path, not including the mount on the path itself.
+pub fn get_child_mounts(path: impl AsRef<Path>) -> Result<Vec<MountInfo>> {
+    let path = path.as_ref();
+    Ok(get_mounts()?
+        .into_iter()
+        .filter(|m| {
+            let mp = Path::new(&m.mount_point);
+            mp != path && mp.starts_with(path)
+        })
+        .collect())
+}
+
 #[cfg(target_os = "linux")]
 pub fn mount_rootfs(
     fs_type: Option<&str>,
@@ -230,10 +477,23 @@ pub fn mount_rootfs(
     options: &[String],
     target: impl AsRef<Path>,
 ) -> Result<()> {
-    //TODO add helper to mount fuse
-    //TODO compactLowerdirOption for overlay
+    if let Some((helper, sub_type)) = fs_type.and_then(helper_binary) {
+        return mount_with_helper(&helper, sub_type, source, options, target.as_ref());
+    }
+
+    // avoid hitting the one page limit of the mount argument buffer,
+    // 512 is a buffer during the page size check.
+    let (chdir, options) =
+        if fs_type == Some("overlay") && options_size(options) >= page_size() - 512 {
+            compact_lowerdir_option(options)
+        } else {
+            (None, options.to_vec())
+        };
+
     let mut flags: MsFlags = MsFlags::from_bits(0).unwrap();
     let mut data = Vec::new();
+    let mut losetup = false;
+    let (mut uid_map, mut gid_map) = (None, None);
     options.iter().for_each(|x| {
         if let Some(f) = MOUNT_FLAGS.get(x.as_str()) {
             if f.clear {
@@ -241,11 +501,20 @@ pub fn mount_rootfs(
             } else {
                 flags.bitor_assign(f.flags)
             }
+        } else if x == "loop" {
+            losetup = true;
+        } else if let Some(m) = x.strip_prefix(UID_MAP_OPTION) {
+            uid_map = Some(m);
+        } else if let Some(m) = x.strip_prefix(GID_MAP_OPTION) {
+            gid_map = Some(m);
         } else {
             data.push(x.as_str())
         }
     });
     let opt = data.join(",");
+    if opt.len() > page_size() {
+        return Err(other!("mount options is too long"));
+    }
 
     let data = if !data.is_empty() {
         Some(opt.as_str())
@@ -253,16 +522,58 @@ pub fn mount_rootfs(
         None
     };
 
+    // the loop device is detached automatically once it is unmounted,
+    // so it only has to be held open until it is mounted.
+    let loop_dev = if losetup {
+        let backing_file =
+            source.ok_or_else(|| Error::InvalidArgument("loop mount without source".into()))?;
+        let params = LoopParams {
+            readonly: flags.contains(MsFlags::MS_RDONLY),
+            autoclear: true,
+        };
+        Some(setup_loop(backing_file, &params)?)
+    } else {
+        None
+    };
+    let source = loop_dev.as_ref().map(|(_, path)| path.as_str()).or(source);
+
+    let userns = match (uid_map, gid_map) {
+        (None, None) => None,
+        (Some(uid_map), Some(gid_map)) if flags.contains(MsFlags::MS_BIND) => {
+            Some(get_userns_fd(uid_map, gid_map)?)
+        }
+        (Some(_), Some(_)) => {
+            return Err(Error::InvalidArgument(
+                "idmapped mounts are only supported for bind mounts".into(),
+            ))
+        }
+        _ => {
+            return Err(Error::InvalidArgument(format!(
+                "both {} and {} are required for idmapped mounts",
+                UID_MAP_OPTION, GID_MAP_OPTION
+            )))
+        }
+    };
+
     // mount with non-propagation first, or remount with changed data
     let oflags = flags.bitand(PROPAGATION_TYPES.not());
     let zero: MsFlags = MsFlags::from_bits(0).unwrap();
     if flags.bitand(MsFlags::MS_REMOUNT).eq(&zero) || data != None {
-        mount(source, target.as_ref(), fs_type, oflags, data).map_err(mount_error!(
-            e,
-            "Mount {:?} to {}",
-            source,
-            target.as_ref().display()
-        ))?;
+        if let Some(userns) = userns.as_ref() {
+            let source =
+                source.ok_or_else(|| Error::InvalidArgument("bind mount without source".into()))?;
+            let recursive = flags.contains(MsFlags::MS_REC);
+            mount_idmapped(source, target.as_ref(), recursive, userns)?;
+        } else {
+            mount_at(
+                chdir.as_deref(),
+                source,
+                target.as_ref(),
+                fs_type,
+                oflags,
+                data,
+            )?;
+        }
     }
 
     // change the propagation type
@@ -290,6 +601,644 @@ pub fn mount_rootfs(
     Ok(())
 }
 
+/// Options of a bind mount that is idmapped with the given `container:host:size` mappings,
+/// several mappings are separated by commas. Like the other `X-containerd.` options they
+/// are handled by the shim, and not passed to the kernel.
+const UID_MAP_OPTION: &str = "X-containerd.uidmap=";
+const GID_MAP_OPTION: &str = "X-containerd.gidmap=";
+
+// loop device ioctls and flags, from linux/loop.h
+#[cfg(target_os = "linux")]
+const LOOP_CONTROL_PATH: &str = "/dev/loop-control";
+#[cfg(target_os = "linux")]
+const LOOP_SET_FD: libc::c_ulong = 0x4C00;
+#[cfg(target_os = "linux")]
+const LOOP_CLR_FD: libc::c_ulong = 0x4C01;
+#[cfg(target_os = "linux")]
+const LOOP_SET_STATUS64: libc::c_ulong = 0x4C04;
+#[cfg(target_os = "linux")]
+const LOOP_CONFIGURE: libc::c_ulong = 0x4C0A;
+#[cfg(target_os = "linux")]
+const LOOP_CTL_GET_FREE: libc::c_ulong = 0x4C82;
+#[cfg(target_os = "linux")]
+const LO_FLAGS_READ_ONLY: u32 = 1;
+#[cfg(target_os = "linux")]
+const LO_FLAGS_AUTOCLEAR: u32 = 4;
+
+// new mount api flags, from linux/mount.h
+#[cfg(target_os = "linux")]
+const OPEN_TREE_CLONE: libc::c_uint = 1;
+#[cfg(target_os = "linux")]
+const AT_RECURSIVE: libc::c_uint = 0x8000;
+#[cfg(target_os = "linux")]
+const MOVE_MOUNT_F_EMPTY_PATH: libc::c_uint = 0x04;
+#[cfg(target_os = "linux")]
+const MOUNT_ATTR_IDMAP: u64 = 0x0010_0000;
+
+#[cfg(target_os = "linux")]
+#[repr(C)]
+struct LoopInfo64 {
+    lo_device: u64,
+    lo_inode: u64,
+    lo_rdevice: u64,
+    lo_offset: u64,
+    lo_sizelimit: u64,
+    lo_number: u32,
+    lo_encrypt_type: u32,
+    lo_encrypt_key_size: u32,
+    lo_flags: u32,
+    lo_file_name: [u8; 64],
+    lo_crypt_name: [u8; 64],
+    lo_encrypt_key: [u8; 32],
+    lo_init: [u64; 2],
+}
+
+#[cfg(target_os = "linux")]
+#[repr(C)]
+struct LoopConfig {
+    fd: u32,
+    block_size: u32,
+    info: LoopInfo64,
+    reserved: [u64; 8],
+}
+
+#[cfg(target_os = "linux")]
+#[repr(C)]
+struct MountAttr {
+    attr_set: u64,
+    attr_clr: u64,
+    propagation: u64,
+    userns_fd: u64,
+}
+
+#[cfg(target_os = "linux")]
+struct LoopParams {
+    readonly: bool,
+    autoclear: bool,
+}
+
+/// Attach the backing file to a free loop device, returning the opened device and its path.
+#[cfg(target_os = "linux")]
+fn setup_loop(backing_file: &str, params: &LoopParams) -> Result<(File, String)> {
+    let back = OpenOptions::new()
+        .read(true)
+        .write(!params.readonly)
+        .open(backing_file)
+        .map_err(io_error!(e, "open backing file {}", backing_file))?;
+    for retry in 1..100 {
+        let num = get_free_loop_dev()?;
+        let loop_dev = format!("/dev/loop{}", num);
+        match setup_loop_dev(&back, &loop_dev, params) {
+            Ok(dev) => return Ok((dev, loop_dev)),
+            // the free loop device can be raced for, back off a bit and try another one
+            Err(Error::MountError {
+                err: Errno::EBUSY, ..
+            }) => std::thread::sleep(Duration::from_millis(retry)),
+            Err(e) => return Err(e),
+        }
+    }
+    Err(other!("timeout creating new loopback device"))
+}
+
+#[cfg(target_os = "linux")]
+fn get_free_loop_dev() -> Result<i32> {
+    let ctl = OpenOptions::new()
+        .read(true)
+        .write(true)
+        .open(LOOP_CONTROL_PATH)
+        .map_err(io_error!(e, "open {}", LOOP_CONTROL_PATH))?;
+    // Safe because the ioctl takes no argument.
+    let num = unsafe { libc::ioctl(ctl.as_raw_fd(), LOOP_CTL_GET_FREE as _) };
+    Errno::result(num).map_err(mount_error!(e, "get free loop device"))
+}
+
+#[cfg(target_os = "linux")]
+fn setup_loop_dev(back: &File, loop_dev: &str, params: &LoopParams) -> Result<File> {
+    let dev = OpenOptions::new()
+        .read(true)
+        .write(true)
+        .open(loop_dev)
+        .map_err(io_error!(e, "open loop device {}", loop_dev))?;
+
+    // Safe because LoopConfig is plain old data that is valid when zeroed.
+    let mut config: LoopConfig = unsafe { std::mem::zeroed() };
+    config.fd = back.as_raw_fd() as u32;
+    if params.readonly {
+        config.info.lo_flags |= LO_FLAGS_READ_ONLY;
+    }
+    if params.autoclear {
+        config.info.lo_flags |= LO_FLAGS_AUTOCLEAR;
+    }
+
+    // Safe because config outlives the ioctl calls, which don't keep a reference to it.
+    let res = match Errno::result(unsafe {
+        libc::ioctl(dev.as_raw_fd(), LOOP_CONFIGURE as _, &config)
+    }) {
+        // LOOP_CONFIGURE is only available since Linux 5.8, fall back to the old api.
+        // Older kernels fail the unknown ioctl with EINVAL or ENOTTY.
+        Err(Errno::EINVAL) | Err(Errno::ENOTTY) => unsafe {
+            Errno::result(libc::ioctl(
+                dev.as_raw_fd(),
+                LOOP_SET_FD as _,
+                back.as_raw_fd(),
+            ))
+            .and_then(|_| {
+                Errno::result(libc::ioctl(
+                    dev.as_raw_fd(),
+                    LOOP_SET_STATUS64 as _,
+                    &config.info,
+                ))
+                .map_err(|e| {
+                    libc::ioctl(dev.as_raw_fd(), LOOP_CLR_FD as _, 0);
+                    e
+                })
+            })
+        },
+        res => res,
+    };
+    res.map(|_| dev)
+        .map_err(mount_error!(e, "setup loop device {}", loop_dev))
+}
+
+/// Returns a user namespace with the given uid and gid mappings, it is kept alive by the fd.
+#[cfg(target_os = "linux")]
+fn get_userns_fd(uid_map: &str, gid_map: &str) -> Result<File> {
+    let uid_map = parse_id_mappings(uid_map)?;
+    let gid_map = parse_id_mappings(gid_map)?;
+
+    let (_, res) = wait_child(|| {
+        // a child that does nothing but sleeping in a new user namespace
+        let mut stack = vec![0u8; 64 * 1024];
+        let pid = clone(
+            Box::new(|| loop {
+                // Safe because pause() only waits for a signal.
+                unsafe { libc::pause() };
+            }),
+            &mut stack,
+            CloneFlags::CLONE_NEWUSER,
+            Some(libc::SIGCHLD),
+        )
+        .map_err(other_error!(e, "clone process in new user namespace"))?;
+
+        let res = write_id_mappings(pid, &uid_map, &gid_map).and_then(|_| {
+            File::open(format!("/proc/{}/ns/user", pid)).map_err(io_error!(
+                e,
+                "open user namespace of {}",
+                pid
+            ))
+        });
+        kill(pid, Signal::SIGKILL).unwrap_or_default();
+        Ok((pid.as_raw(), res))
+    })?;
+    res
+}
+
+#[cfg(target_os = "linux")]
+fn write_id_mappings(pid: Pid, uid_map: &str, gid_map: &str) -> Result<()> {
+    let uid_map_path = format!("/proc/{}/uid_map", pid);
+    std::fs::write(&uid_map_path, uid_map).map_err(io_error!(e, "write {}", uid_map_path))?;
+    let gid_map_path = format!("/proc/{}/gid_map", pid);
+    std::fs::write(&gid_map_path, gid_map).map_err(io_error!(e, "write {}", gid_map_path))
+}
+
+/// Convert `0:1000:65536,65536:2000:1` to the format of /proc/[pid]/uid_map.
+fn parse_id_mappings(mappings: &str) -> Result<String> {
+    let mut content = String::new();
+    for mapping in mappings.split(',') {
+        let ids = mapping
+            .split(':')
+            .map(|id| id.parse::<u32>())
+            .collect::<std::result::Result<Vec<u32>, _>>()
+            .ok()
+            .filter(|ids| ids.len() == 3)
+            .ok_or_else(|| Error::InvalidArgument(format!("invalid id mapping {}", mapping)))?;
+        content.push_str(&format!("{} {} {}\n", ids[0], ids[1], ids[2]));
+    }
+    Ok(content)
+}
+
+/// Bind mount the source to the target with its ids mapped by the user namespace.
+#[cfg(target_os = "linux")]
+fn mount_idmapped(source: &str, target: &Path, recursive: bool, userns: &File) -> Result<()> {
+    let src = CString::new(source).map_err(other_error!(e, "invalid mount source"))?;
+    let dst = CString::new(target.as_os_str().as_bytes())
+        .map_err(other_error!(e, "invalid mount target"))?;
+    let empty = CString::default();
+
+    let mut tree_flags = OPEN_TREE_CLONE | libc::O_CLOEXEC as libc::c_uint;
+    let mut attr_flags = libc::AT_EMPTY_PATH as libc::c_uint;
+    if recursive {
+        tree_flags |= AT_RECURSIVE;
+        attr_flags |= AT_RECURSIVE;
+    }
+    // Safe because the path is a valid C string.
+    let fd = unsafe {
+        libc::syscall(
+            libc::SYS_open_tree,
+            libc::AT_FDCWD,
+            src.as_ptr(),
+            tree_flags,
+        )
+    };
+    let fd = Errno::result(fd).map_err(mount_error!(e, "open tree {}", source))?;
+    // Safe because the fd has just been opened, and is closed when the tree is dropped.
+    let tree = unsafe { File::from_raw_fd(fd as RawFd) };
+
+    let attr = MountAttr {
+        attr_set: MOUNT_ATTR_IDMAP,
+        attr_clr: 0,
+        propagation: 0,
+        userns_fd: userns.as_raw_fd() as u64,
+    };
+    // Safe because attr outlives the syscall, which doesn't keep a reference to it.
+    let res = unsafe {
+        libc::syscall(
+            libc::SYS_mount_setattr,
+            tree.as_raw_fd(),
+            empty.as_ptr(),
+            attr_flags,
+            &attr as *const MountAttr,
+            std::mem::size_of::<MountAttr>(),
+        )
+    };
+    Errno::result(res).map_err(mount_error!(e, "set idmap of {}", source))?;
+
+    // Safe because the paths are valid C strings.
+    let res = unsafe {
+        libc::syscall(
+            libc::SYS_move_mount,
+            tree.as_raw_fd(),
+            empty.as_ptr(),
+            libc::AT_FDCWD,
+            dst.as_ptr(),
+            MOVE_MOUNT_F_EMPTY_PATH,
+        )
+    };
+    Errno::result(res).map(|_| ()).map_err(mount_error!(
+        e,
+        "Mount {} to {}",
+        source,
+        target.display()
+    ))
+}
+
+/// Directories searched for mount helpers when the shim has no PATH.
+#[cfg(target_os = "linux")]
+const DEFAULT_PATH: &str = "/usr/local/sbin:/usr/local/bin:/usr/sbin:/usr/bin:/sbin:/bin";
+
+/// Returns the mount helper found in PATH for the mount type, and the sub type to pass it.
+///
+/// As mount(8) does, a type with a sub type like `fuse.sshfs` is mounted by
+/// `mount.fuse.sshfs`, or by `mount.fuse` given the sub type, and any other type
+/// like `nfs` by `mount.nfs`. Types without a helper are mounted by the mount syscall.
+#[cfg(target_os = "linux")]
+fn helper_binary(fs_type: &str) -> Option<(PathBuf, Option<&str>)> {
+    let path = std::env::var_os("PATH").unwrap_or_else(|| DEFAULT_PATH.into());
+    let dirs: Vec<PathBuf> = std::env::split_paths(&path).collect();
+    helper_binary_in(fs_type, &dirs)
+}
+
+#[cfg(target_os = "linux")]
+fn helper_binary_in<'a>(fs_type: &'a str, dirs: &[PathBuf]) -> Option<(PathBuf, Option<&'a str>)> {
+    if fs_type.is_empty() {
+        return None;
+    }
+    let find = |name: &str| {
+        dirs.iter()
+            .map(|dir| dir.join(name))
+            .find(|path| is_executable(path))
+    };
+    if let Some(helper) = find(&format!("mount.{}", fs_type)) {
+        return Some((helper, None));
+    }
+    let (main_type, sub_type) = fs_type.split_once('.')?;
+    find(&format!("mount.{}", main_type)).map(|helper| (helper, Some(sub_type)))
+}
+
+#[cfg(target_os = "linux")]
+fn is_executable(path: &Path) -> bool {
+    use std::os::unix::fs::PermissionsExt;
+    std::fs::metadata(path)
+        .map(|m| m.is_file() && m.permissions().mode() & 0o111 != 0)
+        .unwrap_or(false)
+}
+
+/// Mount by running e.g. `mount.fuse3 <source> <target> -o <option>... -t <sub type>`.
+#[cfg(target_os = "linux")]
+fn mount_with_helper(
+    helper: &Path,
+    sub_type: Option<&str>,
+    source: Option<&str>,
+    options: &[String],
+    target: &Path,
+) -> Result<()> {
+    let mut args = vec![
+        source.unwrap_or_default().to_string(),
+        target.to_string_lossy().to_string(),
+    ];
+    for o in options {
+        args.push("-o".to_string());
+        args.push(o.to_string());
+    }
+    if let Some(sub_type) = sub_type {
+        args.push("-t".to_string());
+        args.push(sub_type.to_string());
+    }
+
+    let (exit_code, stderr) = wait_child(|| {
+        let mut child = std::process::Command::new(helper)
+            .args(&args)
+            .stdin(Stdio::null())
+            .stdout(Stdio::null())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(io_error!(
+                e,
+                "start mount helper [{} {:?}]",
+                helper.display(),
+                args
+            ))?;
+        let mut stderr = String::new();
+        if let Some(mut err) = child.stderr.take() {
+            err.read_to_string(&mut stderr).unwrap_or_default();
+        }
+        Ok((child.id() as i32, stderr))
+    })?;
+    if exit_code != 0 {
+        return Err(other!(
+            "mount helper [{} {:?}] failed with exit code {}: {}",
+            helper.display(),
+            args,
+            exit_code,
+            stderr
+        ));
+    }
+    Ok(())
+}
+
+/// Run `start`, which starts a child and returns its pid, and wait for the child to exit.
+/// Returns the exit code of the child and the result of `start`.
+///
+/// The shim reaps all of its children, so a child may be reaped before it is waited for.
+/// Its exit status is taken from the monitor instead, which the reaper notifies, and which
+/// is subscribed before the child is started so that its exit is not missed.
+#[cfg(all(target_os = "linux", not(feature = "async")))]
+fn wait_child<T>(start: impl FnOnce() -> Result<(i32, T)>) -> Result<(i32, T)> {
+    use crate::synchronous::monitor::monitor_subscribe;
+
+    let subscription = monitor_subscribe(Topic::Pid)?;
+    let (pid, res) = start()?;
+    claim_exit(pid);
+    subscription
+        .rx
+        .iter()
+        .find_map(|e| exit_code_of(e, pid))
+        .map(|code| (code, res))
+        .ok_or_else(|| other!("monitor closed before process {} exited", pid))
+}
+
+/// In an async shim the reaper notifies the async monitor, which is waited on by the runtime
+/// of the shim, as the mounts run on its blocking threads.
+#[cfg(all(target_os = "linux", feature = "async"))]
+fn wait_child<T>(start: impl FnOnce() -> Result<(i32, T)>) -> Result<(i32, T)> {
+    use crate::asynchronous::monitor::{monitor_subscribe, monitor_unsubscribe};
+
+    let runtime = tokio::runtime::Handle::try_current()
+        .map_err(other_error!(e, "wait for child out of the shim runtime"))?;
+    let mut subscription = runtime.block_on(monitor_subscribe(Topic::Pid))?;
+    let res = start().and_then(|(pid, res)| {
+        claim_exit(pid);
+        runtime.block_on(async {
+            while let Some(e) = subscription.rx.recv().await {
+                if let Some(code) = exit_code_of(e, pid) {
+                    return Ok((code, res));
+                }
+            }
+            Err(other!("monitor closed before process {} exited", pid))
+        })
+    });
+    runtime
+        .block_on(monitor_unsubscribe(subscription.id))
+        .unwrap_or_default();
+    res
+}
+
+#[cfg(target_os = "linux")]
+fn exit_code_of(e: ExitEvent, pid: i32) -> Option<i32> {
+    match e.subject {
+        Subject::Pid(p) if p == pid => Some(e.exit_code),
+        _ => None,
+    }
+}
+
+/// Mount in a helper thread that has its own working directory changed to `chdir`,
+/// so that relative paths in the mount data are resolved against it.
+#[cfg(target_os = "linux")]
+fn mount_at(
+    chdir: Option<&Path>,
+    source: Option<&str>,
+    target: &Path,
+    fs_type: Option<&str>,
+    flags: MsFlags,
+    data: Option<&str>,
+) -> Result<()> {
+    let dir = match chdir {
+        Some(dir) => dir.to_path_buf(),
+        None => {
+            return mount(source, target, fs_type, flags, data).map_err(mount_error!(
+                e,
+                "Mount {:?} to {}",
+                source,
+                target.display()
+            ))
+        }
+    };
+
+    // the target is resolved against the new working directory as well
+    let abs_target = if target.is_absolute() {
+        target.to_path_buf()
+    } else {
+        std::env::current_dir()
+            .map_err(io_error!(e, "get current dir"))?
+            .join(target)
+    };
+    let (src, fs, opts) = (
+        source.map(String::from),
+        fs_type.map(String::from),
+        data.map(String::from),
+    );
+    let res = std::thread::spawn(move || -> nix::Result<()> {
+        // the working directory is shared by the whole process unless CLONE_FS is unshared
+        unshare(CloneFlags::CLONE_FS)?;
+        nix::unistd::chdir(&dir)?;
+        mount(
+            src.as_deref(),
+            &abs_target,
+            fs.as_deref(),
+            flags,
+            opts.as_deref(),
+        )
+    })
+    .join()
+    .map_err(|_| other!("mount helper thread panicked"))?;
+    res.map_err(mount_error!(
+        e,
+        "Mount {:?} to {}",
+        source,
+        target.display()
+    ))
+}
+
+fn options_size(options: &[String]) -> usize {
+    options.iter().map(|o| o.len()).sum()
+}
+
+fn page_size() -> usize {
+    // Safe because sysconf() has no side effects and _SC_PAGESIZE is always valid.
+    unsafe { libc::sysconf(libc::_SC_PAGESIZE) as usize }
+}
+
+/// Replace the overlay `lowerdir` paths with ones relative to their common parent directory,
+/// returning that directory, which the mount has to be done from.
+fn compact_lowerdir_option(options: &[String]) -> (Option<PathBuf>, Vec<String>) {
+    let (idx, dirs) = match find_overlay_lowerdirs(options) {
+        Some((idx, dirs)) if dirs.len() > 1 => (idx, dirs),
+        // no need to compact if there is only one lowerdir
+        _ => return (None, options.to_vec()),
+    };
+
+    // the snapshot ids are digits, go back to the parent dir so that we don't end up in
+    // `snapshots/1` when the lowerdirs are `snapshots/1/fs` and `snapshots/10/fs`.
+    let common_dir = match longest_common_prefix(&dirs).rfind('/') {
+        Some(i) if i > 0 => &dirs[0][..i + 1],
+        _ => return (None, options.to_vec()),
+    };
+
+    let new_dirs: Vec<&str> = dirs.iter().map(|d| &d[common_dir.len()..]).collect();
+    let mut new_options = options.to_vec();
+    new_options.remove(idx);
+    new_options.push(format!("lowerdir={}", new_dirs.join(":")));
+    (Some(PathBuf::from(common_dir)), new_options)
+}
+
+fn find_overlay_lowerdirs(options: &[String]) -> Option<(usize, Vec<&str>)> {
+    options.iter().enumerate().find_map(|(i, o)| {
+        o.strip_prefix("lowerdir=")
+            .map(|dirs| (i, dirs.split(':').collect()))
+    })
+}
+
+fn longest_common_prefix<'a>(strs: &[&'a str]) -> &'a str {
+    let (min, max) = match (strs.iter().min(), strs.iter().max()) {
+        (Some(min), Some(max)) => (*min, *max),
+        _ => return "",
+    };
+    // the common prefix of the alphabetically first and last strings is shared by all of them
+    match min
+        .char_indices()
+        .zip(max.chars())
+        .find(|((_, a), b)| a != b)
+    {
+        Some(((i, _), _)) => &min[..i],
+        None => min,
+    }
+}
+
+/// Unmount the target repeatedly until it is no longer a mount point,
+/// so that stacked mounts on the same target are all removed.
+///
+/// A busy mount is detached lazily (`MNT_DETACH`) instead of failing.
+#[cfg(target_os = "linux")]
+pub fn unmount_all(target: impl AsRef<Path>, flags: i32) -> Result<()> {
+    let target = target.as_ref();
+    let flags = MntFlags::from_bits_truncate(flags);
+    loop {
+        match unmount(target, flags) {
+            Ok(()) => continue,
+            // EINVAL: not a mount point any more; ENOENT: the target does not exist.
+            Err(Errno::EINVAL) | Err(Errno::ENOENT) => return Ok(()),
+            res => res.map_err(mount_error!(e, "Unmount {}", target.display()))?,
+        }
+    }
+}
+
+/// Unmount the rootfs target and every mount beneath it, deepest mounts first.
+///
+/// Fails if any mount is still left beneath the target afterwards.
+#[cfg(target_os = "linux")]
+pub fn unmount_rootfs(target: impl AsRef<Path>, flags: i32) -> Result<()> {
+    let target = target.as_ref();
+    let mut mount_points = mount_points_under(target)?;
+    mount_points.sort_by_key(|p| std::cmp::Reverse(p.components().count()));
+    mount_points.dedup();
+    for mp in mount_points {
+        unmount_all(&mp, flags)?;
+    }
+    // the target may also be mounted over, even if it is absent from our mount table
+    unmount_all(target, flags)?;
+
+    let leaked = mount_points_under(target)?;
+    if !leaked.is_empty() {
+        return Err(other!(
+            "mounts {:?} are left after unmounting {}",
+            leaked,
+            target.display()
+        ));
+    }
+    Ok(())
+}
+
+/// Returns the mount points of the target itself and the mounts below it.
+fn mount_points_under(target: &Path) -> Result<Vec<PathBuf>> {
+    let mount_points = get_mounts()?
+        .into_iter()
+        .map(|m| PathBuf::from(m.mount_point))
+        .filter(|mp| mp.starts_with(target))
+        .collect();
+    Ok(mount_points)
+}
+
+#[cfg(target_os = "linux")]
+fn unmount(target: &Path, flags: MntFlags) -> nix::Result<()> {
+    match umount2(target, flags) {
+        Err(Errno::EBUSY) if !flags.contains(MntFlags::MNT_DETACH) => {
+            warn!("{} is busy, unmount it lazily", target.display());
+            umount2(target, flags.bitor(MntFlags::MNT_DETACH))
+        }
+        res => res,
+    }
+}
+
+/// Decode the octal escapes (e.g. `\040` for a space) the kernel uses in /proc mount tables.
+fn unescape_octal(s: &str) -> String {
+    let bytes = s.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'\\' && i + 3 < bytes.len() {
+            let code = std::str::from_utf8(&bytes[i + 1..i + 4]).unwrap_or_default();
+            if let Ok(c) = u8::from_str_radix(code, 8) {
+                out.push(c);
+                i += 4;
+                continue;
+            }
+        }
+        out.push(bytes[i]);
+        i += 1;
+    }
+    String::from_utf8_lossy(&out).to_string()
+}
+
+#[cfg(not(target_os = "linux"))]
+pub fn unmount_all(target: impl AsRef<Path>, flags: i32) -> Result<()> {
+    Err(Error::Unimplemented("unmount".to_string()))
+}
+
+#[cfg(not(target_os = "linux"))]
+pub fn unmount_rootfs(target: impl AsRef<Path>, flags: i32) -> Result<()> {
+    Err(Error::Unimplemented("unmount".to_string()))
+}
+
 #[cfg(not(target_os = "linux"))]
 pub fn mount_rootfs(
     fs_type: Option<&str>,
@@ -299,3 +1248,236 @@ pub fn mount_rootfs(
 ) -> Result<()> {
     Err(Error::Unimplemented("start".to_string()))
 }
+
+#[cfg(all(test, target_os = "linux"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_longest_common_prefix() {
+        assert_eq!(longest_common_prefix(&[]), "");
+        assert_eq!(longest_common_prefix(&["/a"]), "/a");
+        assert_eq!(longest_common_prefix(&["/a/b", "/a/c", "/a/bc"]), "/a/");
+        assert_eq!(longest_common_prefix(&["/a/b1", "/a/b10"]), "/a/b1");
+        assert_eq!(longest_common_prefix(&["/a", "/b"]), "/");
+    }
+
+    #[test]
+    fn test_compact_lowerdir_option() {
+        let cases = vec![
+            // no lowerdir or only one
+            (vec!["workdir=a"], None, vec!["workdir=a"]),
+            (
+                vec!["workdir=a", "lowerdir=b"],
+                None,
+                vec!["workdir=a", "lowerdir=b"],
+            ),
+            // >= 2 lowerdirs
+            (
+                vec!["lowerdir=/snapshots/1/fs:/snapshots/10/fs"],
+                Some("/snapshots/"),
+                vec!["lowerdir=1/fs:10/fs"],
+            ),
+            (
+                vec![
+                    "workdir=/snapshots/3/work",
+                    "lowerdir=/snapshots/1/fs:/snapshots/10/fs:/snapshots/2/fs",
+                    "upperdir=/snapshots/3/fs",
+                ],
+                Some("/snapshots/"),
+                vec![
+                    "workdir=/snapshots/3/work",
+                    "upperdir=/snapshots/3/fs",
+                    "lowerdir=1/fs:10/fs:2/fs",
+                ],
+            ),
+            // if the common dir is /
+            (
+                vec!["lowerdir=/snapshots/1/fs:/other_snapshots/1/fs"],
+                None,
+                vec!["lowerdir=/snapshots/1/fs:/other_snapshots/1/fs"],
+            ),
+            // if the common dir is .
+            (vec!["lowerdir=a:aaa"], None, vec!["lowerdir=a:aaa"]),
+        ];
+        for (opts, common_dir, new_opts) in cases {
+            let opts: Vec<String> = opts.into_iter().map(String::from).collect();
+            let (dir, compacted) = compact_lowerdir_option(&opts);
+            assert_eq!(dir, common_dir.map(PathBuf::from));
+            assert_eq!(compacted, new_opts);
+        }
+    }
+
+    #[test]
+    fn test_parse_id_mappings() {
+        assert_eq!(parse_id_mappings("0:1000:65536").unwrap(), "0 1000 65536\n");
+        assert_eq!(
+            parse_id_mappings("0:1000:1,1:2000:65535").unwrap(),
+            "0 1000 1\n1 2000 65535\n"
+        );
+        assert!(parse_id_mappings("").is_err());
+        assert!(parse_id_mappings("0:1000").is_err());
+        assert!(parse_id_mappings("0:1000:1:1").is_err());
+        assert!(parse_id_mappings("0:-1:1").is_err());
+    }
+
+    #[test]
+    fn test_helper_binary() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let tmpdir = tempfile::tempdir().unwrap();
+        let helper = |name: &str, mode: u32| {
+            let path = tmpdir.path().join(name);
+            std::fs::write(&path, "").unwrap();
+            std::fs::set_permissions(&path, std::fs::Permissions::from_mode(mode)).unwrap();
+            path
+        };
+        let fuse = helper("mount.fuse", 0o755);
+        let sshfs = helper("mount.fuse.sshfs", 0o755);
+        let nfs = helper("mount.nfs", 0o755);
+        helper("mount.ext4", 0o644);
+        let dirs = vec![tmpdir.path().join("missing"), tmpdir.path().to_path_buf()];
+
+        assert_eq!(helper_binary_in("fuse.sshfs", &dirs), Some((sshfs, None)));
+        assert_eq!(
+            helper_binary_in("fuse.fuse-overlayfs", &dirs),
+            Some((fuse.clone(), Some("fuse-overlayfs")))
+        );
+        assert_eq!(helper_binary_in("fuse", &dirs), Some((fuse, None)));
+        assert_eq!(helper_binary_in("nfs", &dirs), Some((nfs, None)));
+        // not executable
+        assert_eq!(helper_binary_in("ext4", &dirs), None);
+        assert_eq!(helper_binary_in("fuse3.fuse-overlayfs", &dirs), None);
+        assert_eq!(helper_binary_in("overlay", &dirs), None);
+        assert_eq!(helper_binary_in("", &dirs), None);
+    }
+
+    const MOUNTINFO: &str = r"22 1 8:1 / / rw,relatime shared:1 - ext4 /dev/sda1 rw,errors=remount-ro
+23 22 0:21 / /proc rw,nosuid,nodev,noexec,relatime shared:12 - proc proc rw
+120 22 0:47 / /run/containerd/io.containerd.runtime.v2.task/k8s/abc/rootfs rw,relatime master:1 propagate_from:2 - overlay overlay rw,lowerdir=/l1:/l2,upperdir=/u,workdir=/w
+121 120 0:48 / /run/containerd/io.containerd.runtime.v2.task/k8s/abc/rootfs/dev rw,nosuid unbindable - tmpfs tmpfs rw,mode=755
+122 120 0:49 /data /run/containerd/io.containerd.runtime.v2.task/k8s/abc/rootfs/mnt\040data ro,relatime - ext4 /dev/sdb1 rw
+";
+
+    #[test]
+    fn test_parse_mountinfo() {
+        let mounts = parse_mountinfo(MOUNTINFO).unwrap();
+        assert_eq!(mounts.len(), 5);
+        assert_eq!(
+            mounts[0],
+            MountInfo {
+                id: 22,
+                parent: 1,
+                major: 8,
+                minor: 1,
+                root: "/".to_string(),
+                mount_point: "/".to_string(),
+                options: vec!["rw".to_string(), "relatime".to_string()],
+                optional: vec!["shared:1".to_string()],
+                fs_type: "ext4".to_string(),
+                source: "/dev/sda1".to_string(),
+                super_options: vec!["rw".to_string(), "errors=remount-ro".to_string()],
+            }
+        );
+        assert_eq!(mounts[0].shared(), Some(1));
+        assert_eq!(mounts[2].shared(), None);
+        assert_eq!(mounts[2].master(), Some(1));
+        assert_eq!(mounts[2].propagate_from(), Some(2));
+        assert!(mounts[3].unbindable());
+        assert!(!mounts[3].private());
+        assert!(mounts[4].private());
+        assert_eq!(mounts[4].root, "/data");
+        assert!(mounts[4].mount_point.ends_with("/rootfs/mnt data"));
+
+        assert!(parse_mountinfo("22 1 8:1 / / rw,relatime shared:1").is_err());
+        assert!(parse_mountinfo("x 1 8:1 / / rw - ext4 /dev/sda1 rw").is_err());
+    }
+
+    #[test]
+    fn test_mount_from_mountinfo() {
+        let mounts = parse_mountinfo(MOUNTINFO).unwrap();
+        let m = Mount::from(&mounts[2]);
+        assert_eq!(m.mount_type, "overlay");
+        assert_eq!(m.source, "overlay");
+        assert_eq!(
+            m.options,
+            vec![
+                "rw",
+                "relatime",
+                "lowerdir=/l1:/l2",
+                "upperdir=/u",
+                "workdir=/w"
+            ]
+        );
+        m.validate().unwrap();
+        let m = Mount::from(&mounts[4]);
+        assert_eq!(m.options, vec!["ro", "relatime"]);
+    }
+
+    #[test]
+    fn test_mount_api_conversion() {
+        let mut api = ApiMount::new();
+        api.set_field_type("bind".to_string());
+        api.set_source("/src".to_string());
+        api.set_options(RepeatedField::from_vec(vec![
+            "rbind".to_string(),
+            "ro".to_string(),
+        ]));
+        let m = Mount::from(&api);
+        assert_eq!(m.mount_type, "bind");
+        assert_eq!(m.options, vec!["rbind", "ro"]);
+        assert_eq!(ApiMount::from(m), api);
+    }
+
+    #[test]
+    fn test_mount_validate() {
+        let mount = |mount_type: &str, source: &str, options: &[&str]| Mount {
+            mount_type: mount_type.to_string(),
+            source: source.to_string(),
+            options: options.iter().map(|o| o.to_string()).collect(),
+            ..Default::default()
+        };
+        mount("bind", "/src", &["rbind", "ro"]).validate().unwrap();
+        mount("ext4", "/img", &["loop", "ro"]).validate().unwrap();
+        mount(
+            "bind",
+            "/src",
+            &[
+                "bind",
+                "X-containerd.uidmap=0:1:1",
+                "X-containerd.gidmap=0:1:1",
+            ],
+        )
+        .validate()
+        .unwrap();
+        mount("bind", "/src", &["rbind", "ro", "rw"])
+            .validate()
+            .unwrap();
+        mount("bind", "/src", &["rbind", "nosuid", "suid"])
+            .validate()
+            .unwrap();
+        mount("bind", "", &["rbind"]).validate().unwrap_err();
+        mount("ext4", "", &["loop"]).validate().unwrap_err();
+        mount("bind", "/src", &["bind", "X-containerd.uidmap=0:1:1"])
+            .validate()
+            .unwrap_err();
+        mount(
+            "ext4",
+            "/dev/sda",
+            &["X-containerd.uidmap=0:1:1", "X-containerd.gidmap=0:1:1"],
+        )
+        .validate()
+        .unwrap_err();
+        mount("overlay", "overlay", &["upperdir=/u"])
+            .validate()
+            .unwrap_err();
+    }
+
+    #[test]
+    fn test_unescape_octal() {
+        assert_eq!(unescape_octal("/run/a\\040b"), "/run/a b");
+        assert_eq!(unescape_octal("/run/a\\134b"), "/run/a\\b");
+        assert_eq!(unescape_octal("/run/ab\\04"), "/run/ab\\04");
+        assert_eq!(unescape_octal("/run/ab"), "/run/ab");
+    }
+}
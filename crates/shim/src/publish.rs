@@ -0,0 +1,560 @@
+/*
+   Copyright The containerd Authors.
+
+   Licensed under the Apache License, Version 2.0 (the "License");
+   you may not use this file except in compliance with the License.
+   You may obtain a copy of the License at
+
+       http://www.apache.org/licenses/LICENSE-2.0
+
+   Unless required by applicable law or agreed to in writing, software
+   distributed under the License is distributed on an "AS IS" BASIS,
+   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+   See the License for the specific language governing permissions and
+   limitations under the License.
+*/
+
+//! Shared parts of the event publishers.
+//!
+//! Events of `RemotePublisher` are queued and forwarded in order by a background worker,
+//! which reconnects to containerd with exponential backoff if the connection breaks.
+
+use std::collections::VecDeque;
+use std::fs::{File, OpenOptions};
+use std::io::{BufReader, Read, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::time::Duration;
+
+use log::{error, warn};
+
+#[cfg(any(test, feature = "testing"))]
+use containerd_shim_protos::protobuf::well_known_types::Any;
+use containerd_shim_protos::protobuf::Message;
+use containerd_shim_protos::shim::events::ForwardRequest;
+use containerd_shim_protos::ttrpc;
+use containerd_shim_protos::ttrpc::context::Context;
+
+use crate::error::{Error, Result};
+
+/// Configuration of the publisher's event queue and reconnection.
+#[derive(Clone, Debug)]
+pub struct PublisherConfig {
+    /// Max number of events buffered in memory while containerd is unreachable.
+    pub queue_size: usize,
+    /// File to spill events to when the in-memory queue is full,
+    /// events are dropped instead if it is not set.
+    ///
+    /// It should be an absolute path owned by the shim, the events left in it are
+    /// reloaded by a shim restarted on the same file, and it is removed when the shim exits.
+    pub spill_file: Option<PathBuf>,
+    /// Delay before the first reconnection, it doubles after each failure.
+    pub initial_backoff: Duration,
+    /// Upper limit of the reconnection delay.
+    pub max_backoff: Duration,
+}
+
+impl Default for PublisherConfig {
+    fn default() -> Self {
+        Self {
+            queue_size: 1024,
+            spill_file: None,
+            initial_backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_secs(10),
+        }
+    }
+}
+
+impl PublisherConfig {
+    /// Remove the spill file when the shim exits, the events not forwarded by then are dropped.
+    pub(crate) fn remove_spill_file(&self) {
+        if let Some(path) = self.spill_file.as_ref() {
+            match std::fs::remove_file(path) {
+                Ok(()) => warn!("drop the events left in {}", path.display()),
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+                Err(e) => warn!("failed to remove spill file {}: {}", path.display(), e),
+            }
+        }
+    }
+}
+
+/// An event recorded by the in-memory publisher.
+#[cfg(any(test, feature = "testing"))]
+#[derive(Clone, Debug, PartialEq)]
+pub struct PublishedEvent {
+    pub topic: String,
+    pub namespace: String,
+    pub event: Any,
+}
+
+/// Arguments of the publish binary, which is containerd itself,
+/// the event is given to it on stdin as an encoded `Any`.
+pub(crate) fn publish_binary_args<'a>(
+    address: &'a str,
+    topic: &'a str,
+    namespace: &'a str,
+) -> [&'a str; 7] {
+    [
+        "--address",
+        address,
+        "publish",
+        "--topic",
+        topic,
+        "--namespace",
+        namespace,
+    ]
+}
+
+/// Counters of the events that went through a publisher.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct PublisherMetrics {
+    /// Events forwarded to containerd.
+    pub published: u64,
+    /// Events dropped because the queue was full, or containerd rejected them.
+    pub dropped: u64,
+    /// Events forwarded only after containerd was reconnected.
+    pub delayed: u64,
+    /// Times the publisher reconnected to containerd.
+    pub reconnects: u64,
+    /// Events waiting in the queue, including the spilled ones.
+    pub pending: u64,
+}
+
+#[derive(Default)]
+pub(crate) struct Counters {
+    published: AtomicU64,
+    dropped: AtomicU64,
+    delayed: AtomicU64,
+    reconnects: AtomicU64,
+}
+
+impl Counters {
+    pub(crate) fn published(&self, delayed: bool) {
+        self.published.fetch_add(1, Ordering::Relaxed);
+        if delayed {
+            self.delayed.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    pub(crate) fn dropped(&self) {
+        self.dropped.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn reconnected(&self) {
+        self.reconnects.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+/// Queue of the events waiting to be forwarded, bounded in memory and
+/// optionally spilled to a file, events are always dequeued in the order of enqueue.
+pub(crate) struct EventQueue {
+    capacity: usize,
+    inner: Mutex<Inner>,
+    pub(crate) counters: Counters,
+}
+
+struct Inner {
+    events: VecDeque<(Context, ForwardRequest)>,
+    spill: Option<Spill>,
+}
+
+impl EventQueue {
+    pub(crate) fn new(config: &PublisherConfig) -> Result<Self> {
+        let spill = match config.spill_file.as_ref() {
+            Some(path) => Some(Spill::open(path)?),
+            None => None,
+        };
+        Ok(Self {
+            capacity: config.queue_size.max(1),
+            inner: Mutex::new(Inner {
+                events: VecDeque::new(),
+                spill,
+            }),
+            counters: Counters::default(),
+        })
+    }
+
+    pub(crate) fn push(&self, ctx: Context, req: ForwardRequest) -> Result<()> {
+        let mut inner = self.inner.lock().unwrap();
+        let spilling = inner.spill.as_ref().map(|s| s.pending > 0).unwrap_or(false);
+        if !spilling && inner.events.len() < self.capacity {
+            inner.events.push_back((ctx, req));
+            return Ok(());
+        }
+        let res = match inner.spill.as_mut() {
+            Some(spill) => spill.append(&req),
+            None => Err(other!("event queue is full")),
+        };
+        if res.is_err() {
+            self.counters.dropped();
+        }
+        res.map_err(other_error!(
+            e,
+            format!("drop event {}", req.get_envelope().get_topic())
+        ))
+    }
+
+    /// Get a copy of the oldest event, which stays in the queue until `pop_front`.
+    pub(crate) fn front(&self) -> Option<(Context, ForwardRequest)> {
+        let mut inner = self.inner.lock().unwrap();
+        if inner.events.is_empty() {
+            let capacity = self.capacity;
+            if let Some(spill) = inner.spill.as_mut() {
+                match spill.take(capacity) {
+                    Ok(reqs) => {
+                        let reqs = reqs.into_iter().map(|r| (Context::default(), r));
+                        inner.events.extend(reqs);
+                    }
+                    Err(e) => error!("failed to read spilled events: {}", e),
+                }
+            }
+        }
+        inner.events.front().cloned()
+    }
+
+    pub(crate) fn pop_front(&self) {
+        self.inner.lock().unwrap().events.pop_front();
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub(crate) fn len(&self) -> u64 {
+        let inner = self.inner.lock().unwrap();
+        inner.events.len() as u64 + inner.spill.as_ref().map(|s| s.pending).unwrap_or(0)
+    }
+
+    pub(crate) fn metrics(&self) -> PublisherMetrics {
+        PublisherMetrics {
+            published: self.counters.published.load(Ordering::Relaxed),
+            dropped: self.counters.dropped.load(Ordering::Relaxed),
+            delayed: self.counters.delayed.load(Ordering::Relaxed),
+            reconnects: self.counters.reconnects.load(Ordering::Relaxed),
+            pending: self.len(),
+        }
+    }
+}
+
+/// File of spilled events, each one is a 4 bytes little endian length
+/// followed by the serialized `ForwardRequest`.
+struct Spill {
+    path: PathBuf,
+    /// offset of the first event that is not taken yet
+    offset: u64,
+    /// number of events after the offset
+    pending: u64,
+}
+
+impl Spill {
+    /// Open the spill file, events left by a previous shim are kept to be forwarded.
+    fn open(path: &Path) -> Result<Self> {
+        let mut spill = Self {
+            path: path.to_owned(),
+            offset: 0,
+            pending: 0,
+        };
+        let file = match File::open(path) {
+            Ok(f) => f,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(spill),
+            Err(e) => {
+                return Err(Error::IoError {
+                    context: format!("open {}", path.display()),
+                    err: e,
+                })
+            }
+        };
+        let mut reader = BufReader::new(file);
+        let mut end = 0;
+        while let Some(len) = read_record(&mut reader, false)? {
+            end += 4 + len as u64;
+            spill.pending += 1;
+        }
+        // cut off the event that was partially written
+        OpenOptions::new()
+            .write(true)
+            .open(path)
+            .and_then(|f| f.set_len(end))
+            .map_err(io_error!(e, "truncate {}", path.display()))?;
+        Ok(spill)
+    }
+
+    fn append(&mut self, req: &ForwardRequest) -> Result<()> {
+        let data = req.write_to_bytes()?;
+        let mut record = Vec::with_capacity(4 + data.len());
+        record.extend_from_slice(&(data.len() as u32).to_le_bytes());
+        record.extend_from_slice(&data);
+        OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .and_then(|mut f| f.write_all(&record))
+            .map_err(io_error!(e, "write {}", self.path.display()))?;
+        self.pending += 1;
+        Ok(())
+    }
+
+    fn take(&mut self, max: usize) -> Result<Vec<ForwardRequest>> {
+        let mut reqs = vec![];
+        if self.pending == 0 {
+            return Ok(reqs);
+        }
+        let mut file =
+            File::open(&self.path).map_err(io_error!(e, "open {}", self.path.display()))?;
+        file.seek(SeekFrom::Start(self.offset)).map_err(io_error!(
+            e,
+            "seek {}",
+            self.path.display()
+        ))?;
+        let mut reader = BufReader::new(file);
+        while reqs.len() < max && self.pending > 0 {
+            let data = match read_record(&mut reader, true)? {
+                Some(len) => len,
+                None => break,
+            };
+            self.offset += 4 + data as u64;
+            self.pending -= 1;
+            let mut buf = vec![0u8; data];
+            reader
+                .read_exact(&mut buf)
+                .map_err(io_error!(e, "read {}", self.path.display()))?;
+            match ForwardRequest::parse_from_bytes(&buf) {
+                Ok(r) => reqs.push(r),
+                Err(e) => warn!("drop corrupted spilled event: {}", e),
+            }
+        }
+        if self.pending == 0 {
+            self.offset = 0;
+            std::fs::remove_file(&self.path).map_err(io_error!(
+                e,
+                "remove {}",
+                self.path.display()
+            ))?;
+        }
+        Ok(reqs)
+    }
+}
+
+/// Read the length of the next record, and skip its data unless `keep_data` is set.
+/// Returns None at the end of file or if the record is incomplete.
+fn read_record<R: Read>(reader: &mut R, keep_data: bool) -> Result<Option<usize>> {
+    let mut len = [0u8; 4];
+    match reader.read_exact(&mut len) {
+        Ok(_) => {}
+        Err(e) if e.kind() == std::io::ErrorKind::UnexpectedEof => return Ok(None),
+        Err(e) => {
+            return Err(Error::IoError {
+                context: "read spilled event".to_string(),
+                err: e,
+            })
+        }
+    }
+    let len = u32::from_le_bytes(len) as usize;
+    if keep_data {
+        return Ok(Some(len));
+    }
+    let mut data = vec![0u8; len];
+    match reader.read_exact(&mut data) {
+        Ok(_) => Ok(Some(len)),
+        Err(e) if e.kind() == std::io::ErrorKind::UnexpectedEof => Ok(None),
+        Err(e) => Err(Error::IoError {
+            context: "read spilled event".to_string(),
+            err: e,
+        }),
+    }
+}
+
+/// Exponential backoff of reconnection.
+pub(crate) struct Backoff {
+    initial: Duration,
+    max: Duration,
+    current: Duration,
+}
+
+impl Backoff {
+    pub(crate) fn new(config: &PublisherConfig) -> Self {
+        Self {
+            initial: config.initial_backoff,
+            max: config.max_backoff,
+            current: config.initial_backoff,
+        }
+    }
+
+    pub(crate) fn next_delay(&mut self) -> Duration {
+        let delay = self.current;
+        self.current = std::cmp::min(self.current * 2, self.max);
+        delay
+    }
+
+    pub(crate) fn reset(&mut self) {
+        self.current = self.initial;
+    }
+}
+
+/// Interval of checking the queue when waiting for it to be flushed.
+pub(crate) const FLUSH_INTERVAL: Duration = Duration::from_millis(10);
+
+/// Time the shim waits for the queued events to be forwarded before it exits,
+/// the exit events of the tasks are published right before the shim is shut down.
+pub(crate) const EXIT_FLUSH_TIMEOUT: Duration = Duration::from_secs(3);
+
+/// Timeout of forwarding an event, so that a broken connection is detected
+/// even if the publisher is given no timeout.
+const FORWARD_TIMEOUT: Duration = Duration::from_secs(5);
+
+pub(crate) fn forward_context(ctx: Context) -> Context {
+    let mut ctx = ctx;
+    if ctx.timeout_nano == 0 {
+        ctx.timeout_nano = FORWARD_TIMEOUT.as_nanos() as i64;
+    }
+    ctx
+}
+
+/// Whether the event should be given up instead of retried after the error,
+/// it is the case if containerd received and rejected the event.
+pub(crate) fn is_rejected(err: &ttrpc::Error) -> bool {
+    match err {
+        ttrpc::Error::RpcStatus(s) => !matches!(
+            s.get_code(),
+            ttrpc::Code::DEADLINE_EXCEEDED | ttrpc::Code::UNAVAILABLE
+        ),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use containerd_shim_protos::shim::events::Envelope;
+
+    use super::*;
+
+    fn request(topic: &str) -> ForwardRequest {
+        let mut envelope = Envelope::new();
+        envelope.set_topic(topic.to_string());
+        let mut req = ForwardRequest::new();
+        req.set_envelope(envelope);
+        req
+    }
+
+    fn pop_topic(queue: &EventQueue) -> Option<String> {
+        let (_, req) = queue.front()?;
+        queue.pop_front();
+        Some(req.get_envelope().get_topic().to_string())
+    }
+
+    #[test]
+    fn test_queue_drop_when_full() {
+        let config = PublisherConfig {
+            queue_size: 2,
+            ..Default::default()
+        };
+        let queue = EventQueue::new(&config).unwrap();
+        queue.push(Context::default(), request("1")).unwrap();
+        queue.push(Context::default(), request("2")).unwrap();
+        assert!(queue.push(Context::default(), request("3")).is_err());
+        assert_eq!(queue.metrics().dropped, 1);
+        assert_eq!(queue.metrics().pending, 2);
+        assert_eq!(pop_topic(&queue).as_deref(), Some("1"));
+        assert_eq!(pop_topic(&queue).as_deref(), Some("2"));
+        assert_eq!(pop_topic(&queue), None);
+        assert!(queue.is_empty());
+    }
+
+    #[test]
+    fn test_queue_spill_in_order() {
+        let tmpdir = tempfile::tempdir().unwrap();
+        let path = tmpdir.path().join("events");
+        let config = PublisherConfig {
+            queue_size: 2,
+            spill_file: Some(path.clone()),
+            ..Default::default()
+        };
+        let queue = EventQueue::new(&config).unwrap();
+        for i in 0..5 {
+            queue
+                .push(Context::default(), request(&i.to_string()))
+                .unwrap();
+        }
+        assert_eq!(queue.metrics().pending, 5);
+        assert_eq!(pop_topic(&queue).as_deref(), Some("0"));
+        // room in memory, but events before it are still spilled
+        queue.push(Context::default(), request("5")).unwrap();
+        for i in 1..6 {
+            assert_eq!(pop_topic(&queue), Some(i.to_string()));
+        }
+        assert_eq!(pop_topic(&queue), None);
+        assert!(!path.exists());
+        assert_eq!(queue.metrics().dropped, 0);
+    }
+
+    #[test]
+    fn test_queue_reload_spill() {
+        let tmpdir = tempfile::tempdir().unwrap();
+        let path = tmpdir.path().join("events");
+        let config = PublisherConfig {
+            queue_size: 1,
+            spill_file: Some(path.clone()),
+            ..Default::default()
+        };
+        {
+            let queue = EventQueue::new(&config).unwrap();
+            for i in 0..3 {
+                queue
+                    .push(Context::default(), request(&i.to_string()))
+                    .unwrap();
+            }
+        }
+        // a partially written event
+        OpenOptions::new()
+            .append(true)
+            .open(&path)
+            .unwrap()
+            .write_all(&[10, 0, 0, 0, 1])
+            .unwrap();
+
+        let queue = EventQueue::new(&config).unwrap();
+        assert_eq!(queue.metrics().pending, 2);
+        queue.push(Context::default(), request("3")).unwrap();
+        for i in 1..4 {
+            assert_eq!(pop_topic(&queue), Some(i.to_string()));
+        }
+        assert_eq!(pop_topic(&queue), None);
+    }
+
+    #[test]
+    fn test_remove_spill_file() {
+        let tmpdir = tempfile::tempdir().unwrap();
+        let path = tmpdir.path().join("events");
+        let config = PublisherConfig {
+            queue_size: 1,
+            spill_file: Some(path.clone()),
+            ..Default::default()
+        };
+        let queue = EventQueue::new(&config).unwrap();
+        for i in 0..3 {
+            queue
+                .push(Context::default(), request(&i.to_string()))
+                .unwrap();
+        }
+        assert!(path.exists());
+        config.remove_spill_file();
+        assert!(!path.exists());
+        // nothing to remove
+        config.remove_spill_file();
+    }
+
+    #[test]
+    fn test_backoff() {
+        let config = PublisherConfig {
+            initial_backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_millis(500),
+            ..Default::default()
+        };
+        let mut backoff = Backoff::new(&config);
+        let delays: Vec<u128> = (0..5).map(|_| backoff.next_delay().as_millis()).collect();
+        assert_eq!(delays, vec![100, 200, 400, 500, 500]);
+        backoff.reset();
+        assert_eq!(backoff.next_delay().as_millis(), 100);
+    }
+}
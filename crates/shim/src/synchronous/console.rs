@@ -20,7 +20,8 @@ use std::path::{Path, PathBuf};
 use log::warn;
 use uuid::Uuid;
 
-use crate::util::{mkdir, xdg_runtime_dir};
+use crate::synchronous::util::mkdir;
+use crate::util::xdg_runtime_dir;
 use crate::Error;
 use crate::Result;
 
@@ -0,0 +1,269 @@
+/*
+   Copyright The containerd Authors.
+
+   Licensed under the Apache License, Version 2.0 (the "License");
+   you may not use this file except in compliance with the License.
+   You may obtain a copy of the License at
+
+       http://www.apache.org/licenses/LICENSE-2.0
+
+   Unless required by applicable law or agreed to in writing, software
+   distributed under the License is distributed on an "AS IS" BASIS,
+   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+   See the License for the specific language governing permissions and
+   limitations under the License.
+*/
+
+use std::collections::HashMap;
+use std::sync::mpsc::Receiver;
+
+use log::debug;
+use oci_spec::runtime::LinuxResources;
+use time::OffsetDateTime;
+
+use containerd_shim_protos::api::{
+    CreateTaskRequest, ExecProcessRequest, ProcessInfo, StateResponse,
+};
+use containerd_shim_protos::cgroups::metrics::Metrics;
+use containerd_shim_protos::shim::oci::ProcessDetails;
+
+use crate::error::Result;
+use crate::synchronous::processes::Process;
+use crate::util::{convert_to_any, Deadline};
+use crate::Error;
+
+/// A container of the [TaskService](crate::synchronous::task::TaskService).
+///
+/// The methods running the runtime take the [Deadline] of the request, see there
+/// for what is expected once it passes.
+pub trait Container {
+    /// Start the process, an exec that is half started at the deadline is rolled back,
+    /// while the init process is left for the client to delete.
+    fn start(&mut self, exec_id: Option<&str>, deadline: Deadline) -> Result<i32>;
+    fn state(&self, exec_id: Option<&str>) -> Result<StateResponse>;
+    fn kill(
+        &mut self,
+        exec_id: Option<&str>,
+        signal: u32,
+        all: bool,
+        deadline: Deadline,
+    ) -> Result<()>;
+    fn wait_channel(&mut self, exec_id: Option<&str>) -> Result<Receiver<()>>;
+    fn get_exit_info(&self, exec_id: Option<&str>) -> Result<(i32, i32, Option<OffsetDateTime>)>;
+    fn delete(
+        &mut self,
+        exec_id_opt: Option<&str>,
+        deadline: Deadline,
+    ) -> Result<(i32, i32, Option<OffsetDateTime>)>;
+    fn exec(&mut self, req: ExecProcessRequest, deadline: Deadline) -> Result<()>;
+    fn resize_pty(&mut self, exec_id: Option<&str>, height: u32, width: u32) -> Result<()>;
+    fn pid(&self) -> i32;
+    fn id(&self) -> String;
+    fn update(&mut self, resources: &LinuxResources, deadline: Deadline) -> Result<()>;
+    fn stats(&self, deadline: Deadline) -> Result<Metrics>;
+    fn all_processes(&self, deadline: Deadline) -> Result<Vec<ProcessInfo>>;
+
+    /// Pids of the processes of the container by exec id, which is `None` for the init process.
+    fn process_pids(&self) -> Vec<(Option<String>, i32)> {
+        vec![(None, self.pid())]
+    }
+}
+
+pub trait ContainerFactory<C> {
+    /// Create the container, the work done is rolled back if the deadline passes.
+    fn create(&self, ns: &str, req: &CreateTaskRequest, deadline: Deadline) -> Result<C>;
+    fn cleanup(&self, ns: &str, c: &C) -> Result<()>;
+
+    /// Persist the state of the container, so that it can be restored
+    /// by `restore` after the shim restarts.
+    fn save(&self, _ns: &str, _c: &C) -> Result<()> {
+        Ok(())
+    }
+
+    /// Rebuild the containers that were saved by a previous shim instance.
+    fn restore(&self, _ns: &str) -> Result<Vec<C>> {
+        Ok(vec![])
+    }
+}
+
+pub trait ProcessFactory<E> {
+    fn create(&self, req: &ExecProcessRequest, deadline: Deadline) -> Result<E>;
+}
+
+/// ContainerTemplate is a template struct to implement Container,
+/// most of the methods can be delegated to either init process or exec process.
+/// that's why we provides a ContainerTemplate struct,
+/// library users only need to implements Process for their own.
+pub struct ContainerTemplate<T, E, P> {
+    /// container id
+    pub id: String,
+    /// container bundle path
+    pub bundle: String,
+    /// init process of this container
+    pub init: T,
+    /// process factory that create processes when exec
+    pub process_factory: P,
+    /// exec processes of this container
+    pub processes: HashMap<String, E>,
+}
+
+impl<T, E, P> Container for ContainerTemplate<T, E, P>
+where
+    T: Process + Send + Sync,
+    E: Process + Send + Sync,
+    P: ProcessFactory<E>,
+{
+    fn start(&mut self, exec_id: Option<&str>, deadline: Deadline) -> Result<i32> {
+        let process = self.get_mut_process(exec_id)?;
+        process.start(deadline)?;
+        Ok(process.pid())
+    }
+
+    fn state(&self, exec_id: Option<&str>) -> Result<StateResponse> {
+        let process = self.get_process(exec_id)?;
+        let mut resp = process.state()?;
+        resp.bundle = self.bundle.to_string();
+        debug!("container state: {:?}", resp);
+        Ok(resp)
+    }
+
+    fn kill(
+        &mut self,
+        exec_id: Option<&str>,
+        signal: u32,
+        all: bool,
+        deadline: Deadline,
+    ) -> Result<()> {
+        let process = self.get_mut_process(exec_id)?;
+        process.kill(signal, all, deadline)
+    }
+
+    fn wait_channel(&mut self, exec_id: Option<&str>) -> Result<Receiver<()>> {
+        let process = self.get_mut_process(exec_id)?;
+        process.wait_channel()
+    }
+
+    fn get_exit_info(&self, exec_id: Option<&str>) -> Result<(i32, i32, Option<OffsetDateTime>)> {
+        let process = self.get_process(exec_id)?;
+        Ok((process.pid(), process.exit_code(), process.exited_at()))
+    }
+
+    fn delete(
+        &mut self,
+        exec_id_opt: Option<&str>,
+        deadline: Deadline,
+    ) -> Result<(i32, i32, Option<OffsetDateTime>)> {
+        let (pid, code, exited_at) = self.get_exit_info(exec_id_opt)?;
+        let process = self.get_mut_process(exec_id_opt);
+        match process {
+            Ok(p) => p.delete(deadline)?,
+            Err(Error::NotFoundError(_)) => return Ok((pid, code, exited_at)),
+            Err(e) => return Err(e),
+        }
+        if let Some(exec_id) = exec_id_opt {
+            self.processes.remove(exec_id);
+        }
+        Ok((pid, code, exited_at))
+    }
+
+    fn exec(&mut self, req: ExecProcessRequest, deadline: Deadline) -> Result<()> {
+        let exec_id = req.exec_id.to_string();
+        let exec_process = self.process_factory.create(&req, deadline)?;
+        self.processes.insert(exec_id, exec_process);
+        Ok(())
+    }
+
+    fn resize_pty(&mut self, exec_id: Option<&str>, height: u32, width: u32) -> Result<()> {
+        let process = self.get_mut_process(exec_id)?;
+        process.resize_pty(height, width)
+    }
+
+    fn pid(&self) -> i32 {
+        self.init.pid()
+    }
+
+    fn id(&self) -> String {
+        self.id.to_string()
+    }
+
+    #[cfg(target_os = "linux")]
+    fn update(&mut self, resources: &LinuxResources, deadline: Deadline) -> Result<()> {
+        self.init.update(resources, deadline)
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    fn update(&mut self, _resources: &LinuxResources, _deadline: Deadline) -> Result<()> {
+        Err(Error::Unimplemented("update".to_string()))
+    }
+
+    #[cfg(target_os = "linux")]
+    fn stats(&self, deadline: Deadline) -> Result<Metrics> {
+        self.init.stats(deadline)
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    fn stats(&self, _deadline: Deadline) -> Result<Metrics> {
+        Err(Error::Unimplemented("stats".to_string()))
+    }
+
+    /// Processes of the container, those of the execs are marked by their exec ids.
+    fn all_processes(&self, deadline: Deadline) -> Result<Vec<ProcessInfo>> {
+        let mut processes = self.init.ps(deadline)?;
+        for info in processes.iter_mut() {
+            let exec_id = self
+                .processes
+                .iter()
+                .find(|(_, p)| p.pid() as u32 == info.pid)
+                .map(|(exec_id, _)| exec_id);
+            if let Some(exec_id) = exec_id {
+                let details = ProcessDetails {
+                    exec_id: exec_id.to_string(),
+                    ..Default::default()
+                };
+                info.set_info(convert_to_any(Box::new(details))?);
+            }
+        }
+        Ok(processes)
+    }
+
+    fn process_pids(&self) -> Vec<(Option<String>, i32)> {
+        let mut pids = vec![(None, self.init.pid())];
+        for (exec_id, p) in self.processes.iter() {
+            pids.push((Some(exec_id.to_string()), p.pid()));
+        }
+        pids
+    }
+}
+
+impl<T, E, P> ContainerTemplate<T, E, P>
+where
+    T: Process + Send + Sync,
+    E: Process + Send + Sync,
+{
+    pub fn get_process(&self, exec_id: Option<&str>) -> Result<&(dyn Process + Send + Sync)> {
+        match exec_id {
+            Some(exec_id) => {
+                let p = self.processes.get(exec_id).ok_or_else(|| {
+                    Error::NotFoundError("can not find the exec by id".to_string())
+                })?;
+                Ok(p)
+            }
+            None => Ok(&self.init),
+        }
+    }
+
+    pub fn get_mut_process(
+        &mut self,
+        exec_id: Option<&str>,
+    ) -> Result<&mut (dyn Process + Send + Sync)> {
+        match exec_id {
+            Some(exec_id) => {
+                let p = self.processes.get_mut(exec_id).ok_or_else(|| {
+                    Error::NotFoundError("can not find the exec by id".to_string())
+                })?;
+                Ok(p)
+            }
+            None => Ok(&mut self.init),
+        }
+    }
+}
@@ -0,0 +1,87 @@
+/*
+   Copyright The containerd Authors.
+
+   Licensed under the Apache License, Version 2.0 (the "License");
+   you may not use this file except in compliance with the License.
+   You may obtain a copy of the License at
+
+       http://www.apache.org/licenses/LICENSE-2.0
+
+   Unless required by applicable law or agreed to in writing, software
+   distributed under the License is distributed on an "AS IS" BASIS,
+   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+   See the License for the specific language governing permissions and
+   limitations under the License.
+*/
+
+use std::time::Instant;
+
+use crate::api::*;
+use crate::layer::{Layers, TaskLayer};
+use crate::protos::shim::shim_ttrpc::Task;
+use crate::protos::ttrpc::{self, TtrpcContext};
+
+/// A [Task] with the layers stacked on it.
+///
+/// A request goes through the layers in the order they were added before reaching
+/// the task, the response goes back through them in reverse order.
+pub struct Layered<T> {
+    inner: T,
+    layers: Layers,
+}
+
+impl<T> Layered<T> {
+    pub fn new(inner: T) -> Self {
+        Self {
+            inner,
+            layers: Layers::default(),
+        }
+    }
+
+    /// Stack the layer, it sees the requests after the layers added before.
+    pub fn layer(mut self, layer: impl TaskLayer + 'static) -> Self {
+        self.layers.push(layer);
+        self
+    }
+
+    pub fn inner(&self) -> &T {
+        &self.inner
+    }
+}
+
+macro_rules! layered {
+    ($($name:ident($req:ty) -> $resp:ty),* $(,)?) => {
+        impl<T: Task> Task for Layered<T> {
+            $(
+                fn $name(&self, ctx: &TtrpcContext, req: $req) -> ttrpc::Result<$resp> {
+                    let start = Instant::now();
+                    let method = stringify!($name);
+                    let (entered, admitted) = self.layers.enter(method, &req);
+                    let resp = admitted.and_then(|_| self.inner.$name(ctx, req));
+                    self.layers.leave(entered, method, &resp, start.elapsed());
+                    resp
+                }
+            )*
+        }
+    };
+}
+
+layered! {
+    state(StateRequest) -> StateResponse,
+    create(CreateTaskRequest) -> CreateTaskResponse,
+    start(StartRequest) -> StartResponse,
+    delete(DeleteRequest) -> DeleteResponse,
+    pids(PidsRequest) -> PidsResponse,
+    pause(PauseRequest) -> Empty,
+    resume(ResumeRequest) -> Empty,
+    checkpoint(CheckpointTaskRequest) -> Empty,
+    kill(KillRequest) -> Empty,
+    exec(ExecProcessRequest) -> Empty,
+    resize_pty(ResizePtyRequest) -> Empty,
+    close_io(CloseIORequest) -> Empty,
+    update(UpdateTaskRequest) -> Empty,
+    wait(WaitRequest) -> WaitResponse,
+    stats(StatsRequest) -> StatsResponse,
+    connect(ConnectRequest) -> ConnectResponse,
+    shutdown(ShutdownRequest) -> Empty,
+}
@@ -48,17 +48,29 @@ use signal_hook::iterator::Signals;
 
 use crate::protos::protobuf::Message;
 use crate::protos::shim::shim_ttrpc::{create_task, Task};
+use crate::protos::shim_sync::{create_sandbox, create_task_v3, Sandbox};
 use crate::protos::ttrpc::{Client, Server};
 use util::{read_address, write_address};
 
 use crate::api::DeleteResponse;
-use crate::synchronous::publisher::RemotePublisher;
+use crate::publish::EXIT_FLUSH_TIMEOUT;
+use crate::synchronous::publisher::{BinaryPublisher, EventPublisher, RemotePublisher};
+use crate::synchronous::task_v3::TaskV3Adapter;
 use crate::Error;
 use crate::{args, logger, reap, Result, TTRPC_ADDRESS};
-use crate::{parse_sockaddr, socket_address, start_listener, Config, StartOpts, SOCKET_FD};
-
+use crate::{
+    bootstrap_output, parse_sockaddr, remove_legacy_sockets, socket_address, start_listener,
+    Config, StartOpts, SOCKET_FD, SOCKET_ROOT,
+};
+
+pub mod client;
+pub mod container;
+pub mod layer;
 pub mod monitor;
+pub mod processes;
 pub mod publisher;
+pub mod task;
+pub mod task_v3;
 pub mod util;
 
 pub mod console;
@@ -121,7 +133,13 @@ pub trait Shim {
     fn wait(&mut self);
 
     /// Create the task service object.
-    fn create_task_service(&self, publisher: RemotePublisher) -> Self::T;
+    fn create_task_service(&self, publisher: Box<dyn EventPublisher>) -> Self::T;
+
+    /// Create the sandbox service object, which is registered beside the task service
+    /// if the shim owns the lifecycle of the sandbox, e.g. a pod VM.
+    fn create_sandbox_service(&self) -> Option<Box<dyn Sandbox + Send + Sync>> {
+        None
+    }
 }
 
 /// Shim entry point that must be invoked from `main`.
@@ -143,7 +161,7 @@ where
     let os_args: Vec<_> = env::args_os().collect();
     let flags = args::parse(&os_args[1..])?;
 
-    let ttrpc_address = env::var(TTRPC_ADDRESS)?;
+    let ttrpc_address = env::var(TTRPC_ADDRESS).unwrap_or_default();
 
     // Create shim instance
     let mut config = opts.unwrap_or_default();
@@ -169,10 +187,11 @@ where
             };
 
             let address = shim.start_shim(args)?;
+            let output = bootstrap_output(&address, &config)?;
 
             std::io::stdout()
                 .lock()
-                .write_fmt(format_args!("{}", address))
+                .write_fmt(format_args!("{}", output))
                 .map_err(io_error!(e, "write stdout"))?;
 
             Ok(())
@@ -180,6 +199,9 @@ where
         "delete" => {
             std::thread::spawn(move || handle_signals(signals));
             let response = shim.delete_shim()?;
+            // shims started by a previous release listened on the legacy addresses
+            remove_legacy_sockets(Path::new(SOCKET_ROOT))
+                .unwrap_or_else(|e| warn!("failed to remove legacy sockets: {}", e));
             let stdout = std::io::stdout();
             let mut locked = stdout.lock();
             response.write_to_writer(&mut locked)?;
@@ -191,10 +213,17 @@ where
                 logger::init(flags.debug)?;
             }
 
-            let publisher = publisher::RemotePublisher::new(&ttrpc_address)?;
-            let task = shim.create_task_service(publisher);
-            let task_service = create_task(Arc::new(Box::new(task)));
-            let mut server = Server::new().register_service(task_service);
+            let (publisher, remote) = new_publisher(&ttrpc_address, &flags, &config)?;
+            let task: Box<dyn Task + Send + Sync> = Box::new(shim.create_task_service(publisher));
+            let task = Arc::new(task);
+            // Serve the task API v3 by the same task service for newer containerd releases.
+            let task_v3 = TaskV3Adapter::new(task.clone());
+            let mut server = Server::new()
+                .register_service(create_task(task))
+                .register_service(create_task_v3(Arc::new(Box::new(task_v3))));
+            if let Some(sandbox) = shim.create_sandbox_service() {
+                server = server.register_service(create_sandbox(Arc::new(sandbox)));
+            }
             server = server.add_listener(SOCKET_FD)?;
             server.start()?;
 
@@ -204,6 +233,12 @@ where
 
             info!("Shutting down shim instance");
             server.shutdown();
+            if let Some(remote) = remote {
+                remote
+                    .flush(EXIT_FLUSH_TIMEOUT)
+                    .unwrap_or_else(|e| warn!("failed to flush events: {}", e));
+            }
+            config.publisher.remove_spill_file();
 
             // NOTE: If the shim server is down(like oom killer), the address
             // socket might be leaking.
@@ -214,6 +249,35 @@ where
     }
 }
 
+/// Connect to the TTRPC address of containerd to publish events, or fall back to
+/// run the publish binary if containerd doesn't serve events over TTRPC.
+/// The remote publisher is also returned to flush its queue before the shim exits.
+fn new_publisher(
+    ttrpc_address: &str,
+    flags: &args::Flags,
+    config: &Config,
+) -> Result<(Box<dyn EventPublisher>, Option<RemotePublisher>)> {
+    if !ttrpc_address.is_empty() {
+        match RemotePublisher::with_config(ttrpc_address, config.publisher.clone()) {
+            Ok(p) => return Ok((Box::new(p.clone()), Some(p))),
+            Err(e) if flags.publish_binary.is_empty() => return Err(e),
+            Err(e) => warn!(
+                "failed to connect to {}, publish events by {}: {}",
+                ttrpc_address, flags.publish_binary, e
+            ),
+        }
+    }
+    if flags.publish_binary.is_empty() {
+        return Err(Error::InvalidArgument(format!(
+            "neither {} nor publish binary is given",
+            TTRPC_ADDRESS
+        )));
+    }
+    let publisher =
+        BinaryPublisher::new(&flags.publish_binary, &flags.address).with_reaper(!config.no_reaper);
+    Ok((Box::new(publisher), None))
+}
+
 fn setup_signals(config: &Config) -> Signals {
     let signals = Signals::new(&[SIGTERM, SIGINT, SIGPIPE]).expect("new signal failed");
     if !config.no_reaper {
@@ -327,6 +391,9 @@ pub fn spawn(opts: StartOpts, grouping: &str, vars: Vec<(&str, &str)>) -> Result
             "-address",
             &opts.address,
         ]);
+    if !opts.publish_binary.is_empty() {
+        command.args(&["-publish-binary", &opts.publish_binary]);
+    }
     if opts.debug {
         command.arg("-debug");
     }
@@ -0,0 +1,177 @@
+/*
+   Copyright The containerd Authors.
+
+   Licensed under the Apache License, Version 2.0 (the "License");
+   you may not use this file except in compliance with the License.
+   You may obtain a copy of the License at
+
+       http://www.apache.org/licenses/LICENSE-2.0
+
+   Unless required by applicable law or agreed to in writing, software
+   distributed under the License is distributed on an "AS IS" BASIS,
+   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+   See the License for the specific language governing permissions and
+   limitations under the License.
+*/
+
+use std::os::unix::io::AsRawFd;
+use std::sync::mpsc::{sync_channel, Receiver, SyncSender};
+use std::sync::Arc;
+
+use oci_spec::runtime::LinuxResources;
+use time::OffsetDateTime;
+
+use containerd_shim_protos::api::{ProcessInfo, StateResponse, Status};
+use containerd_shim_protos::cgroups::metrics::Metrics;
+use containerd_shim_protos::protobuf::well_known_types::Timestamp;
+
+use crate::io::Stdio;
+use crate::util::Deadline;
+use crate::{ioctl_set_winsz, Console};
+
+pub trait Process {
+    fn start(&mut self, deadline: Deadline) -> crate::Result<()>;
+    fn set_exited(&mut self, exit_code: i32);
+    fn pid(&self) -> i32;
+    fn state(&self) -> crate::Result<StateResponse>;
+    fn kill(&mut self, signal: u32, all: bool, deadline: Deadline) -> crate::Result<()>;
+    fn delete(&mut self, deadline: Deadline) -> crate::Result<()>;
+    fn wait_channel(&mut self) -> crate::Result<Receiver<()>>;
+    fn exit_code(&self) -> i32;
+    fn exited_at(&self) -> Option<OffsetDateTime>;
+    fn resize_pty(&mut self, height: u32, width: u32) -> crate::Result<()>;
+    fn update(&mut self, resources: &LinuxResources, deadline: Deadline) -> crate::Result<()>;
+    fn stats(&self, deadline: Deadline) -> crate::Result<Metrics>;
+    fn ps(&self, deadline: Deadline) -> crate::Result<Vec<ProcessInfo>>;
+}
+
+pub trait ProcessLifecycle<P: Process> {
+    fn start(&self, p: &mut P, deadline: Deadline) -> crate::Result<()>;
+    fn kill(&self, p: &mut P, signal: u32, all: bool, deadline: Deadline) -> crate::Result<()>;
+    fn delete(&self, p: &mut P, deadline: Deadline) -> crate::Result<()>;
+    fn update(
+        &self,
+        p: &mut P,
+        resources: &LinuxResources,
+        deadline: Deadline,
+    ) -> crate::Result<()>;
+    fn stats(&self, p: &P, deadline: Deadline) -> crate::Result<Metrics>;
+    fn ps(&self, p: &P, deadline: Deadline) -> crate::Result<Vec<ProcessInfo>>;
+}
+
+pub struct ProcessTemplate<S> {
+    pub state: Status,
+    pub id: String,
+    pub stdio: Stdio,
+    pub pid: i32,
+    pub exit_code: i32,
+    pub exited_at: Option<OffsetDateTime>,
+    pub wait_chan_tx: Vec<SyncSender<()>>,
+    pub console: Option<Console>,
+    pub lifecycle: Arc<S>,
+}
+
+impl<S> ProcessTemplate<S> {
+    pub fn new(id: &str, stdio: Stdio, lifecycle: S) -> Self {
+        Self {
+            state: Status::CREATED,
+            id: id.to_string(),
+            stdio,
+            pid: 0,
+            exit_code: 0,
+            exited_at: None,
+            wait_chan_tx: vec![],
+            console: None,
+            lifecycle: Arc::new(lifecycle),
+        }
+    }
+}
+
+impl<S> Process for ProcessTemplate<S>
+where
+    S: ProcessLifecycle<Self>,
+{
+    fn start(&mut self, deadline: Deadline) -> crate::Result<()> {
+        self.lifecycle.clone().start(self, deadline)
+    }
+
+    fn set_exited(&mut self, exit_code: i32) {
+        self.state = Status::STOPPED;
+        self.exit_code = exit_code;
+        self.exited_at = Some(OffsetDateTime::now_utc());
+        // set wait_chan_tx to empty, to trigger the drop of the initialized Receiver.
+        self.wait_chan_tx = vec![];
+    }
+
+    fn pid(&self) -> i32 {
+        self.pid
+    }
+
+    fn state(&self) -> crate::Result<StateResponse> {
+        let mut resp = StateResponse::new();
+        resp.id = self.id.to_string();
+        resp.status = self.state;
+        resp.pid = self.pid as u32;
+        resp.terminal = self.stdio.terminal;
+        resp.stdin = self.stdio.stdin.to_string();
+        resp.stdout = self.stdio.stdout.to_string();
+        resp.stderr = self.stdio.stderr.to_string();
+        resp.exit_status = self.exit_code as u32;
+        if let Some(exit_at) = self.exited_at {
+            let mut time_stamp = Timestamp::new();
+            time_stamp.set_seconds(exit_at.unix_timestamp());
+            time_stamp.set_nanos(exit_at.nanosecond() as i32);
+            resp.set_exited_at(time_stamp);
+        }
+        Ok(resp)
+    }
+
+    fn kill(&mut self, signal: u32, all: bool, deadline: Deadline) -> crate::Result<()> {
+        self.lifecycle.clone().kill(self, signal, all, deadline)
+    }
+
+    fn delete(&mut self, deadline: Deadline) -> crate::Result<()> {
+        self.lifecycle.clone().delete(self, deadline)
+    }
+
+    fn wait_channel(&mut self) -> crate::Result<Receiver<()>> {
+        let (tx, rx) = sync_channel::<()>(0);
+        if self.state != Status::STOPPED {
+            self.wait_chan_tx.push(tx);
+        }
+        Ok(rx)
+    }
+
+    fn exit_code(&self) -> i32 {
+        self.exit_code
+    }
+
+    fn exited_at(&self) -> Option<OffsetDateTime> {
+        self.exited_at
+    }
+
+    fn resize_pty(&mut self, height: u32, width: u32) -> crate::Result<()> {
+        if let Some(console) = self.console.as_ref() {
+            let w = libc::winsize {
+                ws_row: height as u16,
+                ws_col: width as u16,
+                ws_xpixel: 0,
+                ws_ypixel: 0,
+            };
+            unsafe { ioctl_set_winsz(console.file.as_raw_fd(), &w)? };
+        }
+        Ok(())
+    }
+
+    fn update(&mut self, resources: &LinuxResources, deadline: Deadline) -> crate::Result<()> {
+        self.lifecycle.clone().update(self, resources, deadline)
+    }
+
+    fn stats(&self, deadline: Deadline) -> crate::Result<Metrics> {
+        self.lifecycle.stats(self, deadline)
+    }
+
+    fn ps(&self, deadline: Deadline) -> crate::Result<Vec<ProcessInfo>> {
+        self.lifecycle.ps(self, deadline)
+    }
+}
@@ -16,6 +16,15 @@
 
 //! Implements a client to publish events from the shim back to containerd.
 
+use std::io::{Read, Write};
+use std::process::{Command, Stdio};
+use std::sync::mpsc::{sync_channel, Receiver, SyncSender};
+use std::sync::Arc;
+#[cfg(any(test, feature = "testing"))]
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use log::{debug, warn};
 use protobuf::Message;
 
 use containerd_shim_protos as client;
@@ -26,12 +35,40 @@ use client::ttrpc::{self, context::Context};
 use client::types::empty;
 use client::{Client, Events, EventsClient};
 
-use crate::error::Result;
+use crate::error::{Error, Result};
+use crate::monitor::{claim_exit, ExitEvent, Subject, Topic};
+#[cfg(any(test, feature = "testing"))]
+use crate::publish::PublishedEvent;
+use crate::publish::{
+    forward_context, is_rejected, publish_binary_args, Backoff, EventQueue, PublisherConfig,
+    PublisherMetrics, FLUSH_INTERVAL,
+};
+use crate::synchronous::monitor::monitor_subscribe;
 use crate::util::{connect, convert_to_any, timestamp};
 
+/// EventPublisher publishes events from the shim back to containerd.
+pub trait EventPublisher: Send + Sync {
+    /// Publish a new event.
+    ///
+    /// Event object can be anything that Protobuf able serialize (e.g. implement `Message` trait).
+    fn publish(
+        &self,
+        ctx: Context,
+        topic: &str,
+        namespace: &str,
+        event: Box<dyn Message>,
+    ) -> Result<()>;
+}
+
 /// Remote publisher connects to containerd's TTRPC endpoint to publish events from shim.
+///
+/// Events are queued and forwarded in order by a background thread,
+/// which reconnects to containerd if the connection is broken.
+/// A clone shares the queue, the shim keeps one to flush the queue before it exits.
+#[derive(Clone)]
 pub struct RemotePublisher {
-    client: EventsClient,
+    queue: Arc<EventQueue>,
+    notifier: SyncSender<()>,
 }
 
 impl RemotePublisher {
@@ -39,11 +76,27 @@ impl RemotePublisher {
     ///
     /// containerd uses `/run/containerd/containerd.sock.ttrpc` by default
     pub fn new(address: impl AsRef<str>) -> Result<RemotePublisher> {
-        let client = Self::connect(address)?;
+        Self::with_config(address, PublisherConfig::default())
+    }
 
-        Ok(RemotePublisher {
-            client: EventsClient::new(client),
-        })
+    /// Connect to containerd's TTRPC endpoint,
+    /// with the event queue and reconnection configured by `config`.
+    pub fn with_config(
+        address: impl AsRef<str>,
+        config: PublisherConfig,
+    ) -> Result<RemotePublisher> {
+        let client = Self::connect(&address)?;
+        let queue = Arc::new(EventQueue::new(&config)?);
+        let (notifier, rx) = sync_channel(1);
+        let worker = Worker {
+            address: address.as_ref().to_string(),
+            client: Some(EventsClient::new(client)),
+            queue: queue.clone(),
+            backoff: Backoff::new(&config),
+        };
+        std::thread::spawn(move || worker.run(rx));
+
+        Ok(RemotePublisher { queue, notifier })
     }
 
     fn connect(address: impl AsRef<str>) -> Result<Client> {
@@ -55,6 +108,8 @@ impl RemotePublisher {
     /// Publish a new event.
     ///
     /// Event object can be anything that Protobuf able serialize (e.g. implement `Message` trait).
+    /// The event is queued to be forwarded, an error is returned if it is dropped
+    /// because the queue is full.
     pub fn publish(
         &self,
         ctx: Context,
@@ -71,10 +126,44 @@ impl RemotePublisher {
         let mut req = events::ForwardRequest::new();
         req.set_envelope(envelope);
 
-        self.client.forward(ctx, &req)?;
+        self.enqueue(ctx, req)
+    }
+
+    /// Counters of the published, dropped and delayed events.
+    pub fn metrics(&self) -> PublisherMetrics {
+        self.queue.metrics()
+    }
 
+    /// Wait until all queued events are forwarded, or the timeout expires.
+    pub fn flush(&self, timeout: Duration) -> Result<()> {
+        let deadline = Instant::now() + timeout;
+        while !self.queue.is_empty() {
+            if Instant::now() >= deadline {
+                return Err(other!("{} events are not forwarded", self.queue.len()));
+            }
+            std::thread::sleep(FLUSH_INTERVAL);
+        }
         Ok(())
     }
+
+    fn enqueue(&self, ctx: Context, req: events::ForwardRequest) -> Result<()> {
+        self.queue.push(ctx, req)?;
+        // the worker is already notified if the channel is full
+        self.notifier.try_send(()).unwrap_or_default();
+        Ok(())
+    }
+}
+
+impl EventPublisher for RemotePublisher {
+    fn publish(
+        &self,
+        ctx: Context,
+        topic: &str,
+        namespace: &str,
+        event: Box<dyn Message>,
+    ) -> Result<()> {
+        RemotePublisher::publish(self, ctx, topic, namespace, event)
+    }
 }
 
 impl Events for RemotePublisher {
@@ -83,12 +172,216 @@ impl Events for RemotePublisher {
         _ctx: &ttrpc::TtrpcContext,
         req: events::ForwardRequest,
     ) -> ttrpc::Result<empty::Empty> {
-        self.client.forward(Context::default(), &req)
+        self.enqueue(Context::default(), req)?;
+        Ok(empty::Empty::new())
+    }
+}
+
+struct Worker {
+    address: String,
+    client: Option<EventsClient>,
+    queue: Arc<EventQueue>,
+    backoff: Backoff,
+}
+
+impl Worker {
+    fn run(mut self, rx: Receiver<()>) {
+        // set when forwarding failed, until the queue is drained
+        let mut stalled = false;
+        loop {
+            let (ctx, req) = match self.queue.front() {
+                Some(e) => e,
+                None => {
+                    stalled = false;
+                    // exit when the publisher is dropped
+                    if rx.recv().is_err() && self.queue.is_empty() {
+                        return;
+                    }
+                    continue;
+                }
+            };
+
+            if self.client.is_none() {
+                match RemotePublisher::connect(&self.address) {
+                    Ok(c) => {
+                        debug!("reconnected to {}", self.address);
+                        self.queue.counters.reconnected();
+                        self.client = Some(EventsClient::new(c));
+                    }
+                    Err(e) => {
+                        let delay = self.backoff.next_delay();
+                        warn!(
+                            "failed to reconnect to {}, retry in {:?}: {}",
+                            self.address, delay, e
+                        );
+                        std::thread::sleep(delay);
+                        continue;
+                    }
+                }
+            }
+            let client = self.client.as_ref().unwrap();
+
+            let topic = req.get_envelope().get_topic().to_string();
+            match client.forward(forward_context(ctx), &req) {
+                Ok(_) => {
+                    self.queue.pop_front();
+                    self.queue.counters.published(stalled);
+                    self.backoff.reset();
+                }
+                Err(e) if is_rejected(&e) => {
+                    warn!("event {} is rejected, drop it: {}", topic, e);
+                    self.queue.pop_front();
+                    self.queue.counters.dropped();
+                }
+                Err(e) => {
+                    let delay = self.backoff.next_delay();
+                    warn!(
+                        "failed to forward event {}, retry in {:?}: {}",
+                        topic, delay, e
+                    );
+                    stalled = true;
+                    self.client = None;
+                    std::thread::sleep(delay);
+                }
+            }
+        }
+    }
+}
+
+/// Binary publisher runs the publish binary given by containerd, which is containerd itself,
+/// once for each event, for the containerd that doesn't serve events over TTRPC to shims.
+pub struct BinaryPublisher {
+    binary: String,
+    address: String,
+    reaper: bool,
+}
+
+impl BinaryPublisher {
+    /// `binary` is the publish binary and `address` is the GRPC address of containerd.
+    pub fn new(binary: impl AsRef<str>, address: impl AsRef<str>) -> Self {
+        Self {
+            binary: binary.as_ref().to_string(),
+            address: address.as_ref().to_string(),
+            reaper: true,
+        }
+    }
+
+    /// Whether the exit of the publish binary is collected by the reaper of the shim,
+    /// it should be unset if the reaper is disabled by `Config::no_reaper`,
+    /// then the publisher waits for the binary itself.
+    pub fn with_reaper(mut self, reaper: bool) -> Self {
+        self.reaper = reaper;
+        self
+    }
+}
+
+impl EventPublisher for BinaryPublisher {
+    fn publish(
+        &self,
+        _ctx: Context,
+        topic: &str,
+        namespace: &str,
+        event: Box<dyn Message>,
+    ) -> Result<()> {
+        let data = convert_to_any(event)?.write_to_bytes()?;
+        // the exit of the child is collected by the reaper of the shim, if it is enabled
+        let subscription = match self.reaper {
+            true => Some(monitor_subscribe(Topic::Pid)?),
+            false => None,
+        };
+        let mut child = Command::new(&self.binary)
+            .args(&publish_binary_args(&self.address, topic, namespace))
+            .stdin(Stdio::piped())
+            .stdout(Stdio::null())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(io_error!(e, "spawn {}", self.binary))?;
+        let pid = child.id() as i32;
+        if subscription.is_some() {
+            claim_exit(pid);
+        }
+
+        if let Some(mut stdin) = child.stdin.take() {
+            stdin
+                .write_all(&data)
+                .map_err(io_error!(e, "write event to {}", self.binary))?;
+        }
+        let mut stderr = String::new();
+        if let Some(mut r) = child.stderr.take() {
+            r.read_to_string(&mut stderr).unwrap_or_default();
+        }
+
+        let exit_code = match subscription {
+            Some(s) => {
+                s.rx.iter()
+                    .find_map(|e| match e {
+                        ExitEvent {
+                            subject: Subject::Pid(epid),
+                            exit_code,
+                        } if epid == pid => Some(exit_code),
+                        _ => None,
+                    })
+                    .ok_or_else(|| other!("failed to wait for exit of {}", self.binary))?
+            }
+            None => child
+                .wait()
+                .map_err(io_error!(e, "wait for {}", self.binary))?
+                .code()
+                .unwrap_or(-1),
+        };
+        if exit_code != 0 {
+            return Err(other!(
+                "failed to publish event {}, exit code {}: {}",
+                topic,
+                exit_code,
+                stderr.trim()
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// In-memory publisher records the published events, it is meant for tests.
+#[cfg(any(test, feature = "testing"))]
+#[derive(Default)]
+pub struct MemoryPublisher {
+    events: Mutex<Vec<PublishedEvent>>,
+}
+
+#[cfg(any(test, feature = "testing"))]
+impl MemoryPublisher {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Events published so far, in order.
+    pub fn events(&self) -> Vec<PublishedEvent> {
+        self.events.lock().unwrap().clone()
+    }
+}
+
+#[cfg(any(test, feature = "testing"))]
+impl EventPublisher for MemoryPublisher {
+    fn publish(
+        &self,
+        _ctx: Context,
+        topic: &str,
+        namespace: &str,
+        event: Box<dyn Message>,
+    ) -> Result<()> {
+        let event = convert_to_any(event)?;
+        self.events.lock().unwrap().push(PublishedEvent {
+            topic: topic.to_string(),
+            namespace: namespace.to_string(),
+            event,
+        });
+        Ok(())
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use std::os::unix::fs::PermissionsExt;
     use std::os::unix::io::AsRawFd;
     use std::os::unix::net::UnixListener;
     use std::sync::{Arc, Barrier};
@@ -146,8 +439,60 @@ mod tests {
         client
             .publish(Context::default(), "/tasks/oom", "ns1", Box::new(msg))
             .unwrap();
+        client.flush(Duration::from_secs(5)).unwrap();
+        assert_eq!(client.metrics().published, 1);
         barrier.wait();
 
         thread.join().unwrap();
     }
+
+    #[test]
+    fn test_binary_publisher_without_reaper() {
+        let tmpdir = tempfile::tempdir().unwrap();
+        let binary = tmpdir.path().join("publish");
+        std::fs::write(
+            &binary,
+            "#!/bin/sh\ncat > /dev/null\n[ \"$5\" = /tasks/oom ]\n",
+        )
+        .unwrap();
+        std::fs::set_permissions(&binary, std::fs::Permissions::from_mode(0o755)).unwrap();
+
+        // nothing collects the exit of the binary for the publisher
+        let publisher = BinaryPublisher::new(binary.to_str().unwrap(), "/run/containerd.sock")
+            .with_reaper(false);
+        let mut msg = TaskOOM::new();
+        msg.set_container_id("test".to_string());
+        publisher
+            .publish(
+                Context::default(),
+                "/tasks/oom",
+                "ns1",
+                Box::new(msg.clone()),
+            )
+            .unwrap();
+        publisher
+            .publish(Context::default(), "/tasks/exit", "ns1", Box::new(msg))
+            .unwrap_err();
+    }
+
+    #[test]
+    fn test_memory_publisher() {
+        let publisher = MemoryPublisher::new();
+        let mut msg = TaskOOM::new();
+        msg.set_container_id("test".to_string());
+        EventPublisher::publish(
+            &publisher,
+            Context::default(),
+            "/tasks/oom",
+            "ns1",
+            Box::new(msg.clone()),
+        )
+        .unwrap();
+
+        let events = publisher.events();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].topic, "/tasks/oom");
+        assert_eq!(events[0].namespace, "ns1");
+        assert_eq!(events[0].event, convert_to_any(Box::new(msg)).unwrap());
+    }
 }
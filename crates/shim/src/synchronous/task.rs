@@ -0,0 +1,440 @@
+/*
+   Copyright The containerd Authors.
+
+   Licensed under the Apache License, Version 2.0 (the "License");
+   you may not use this file except in compliance with the License.
+   You may obtain a copy of the License at
+
+       http://www.apache.org/licenses/LICENSE-2.0
+
+   Unless required by applicable law or agreed to in writing, software
+   distributed under the License is distributed on an "AS IS" BASIS,
+   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+   See the License for the specific language governing permissions and
+   limitations under the License.
+*/
+
+use std::collections::HashMap;
+use std::sync::mpsc::Sender;
+use std::sync::{Arc, Mutex};
+
+use log::{debug, info, warn};
+use oci_spec::runtime::LinuxResources;
+
+use containerd_shim_protos::api::{
+    CloseIORequest, ConnectRequest, ConnectResponse, DeleteResponse, PidsRequest, PidsResponse,
+    StatsRequest, StatsResponse, UpdateTaskRequest,
+};
+use containerd_shim_protos::events::task::{
+    TaskCreate, TaskDelete, TaskExecAdded, TaskExecStarted, TaskIO, TaskStart,
+};
+use containerd_shim_protos::protobuf::{Message, SingularPtrField};
+use containerd_shim_protos::shim::shim_ttrpc::Task;
+use containerd_shim_protos::ttrpc::TtrpcContext;
+
+use crate::api::{
+    CreateTaskRequest, CreateTaskResponse, DeleteRequest, Empty, ExecProcessRequest, KillRequest,
+    ResizePtyRequest, ShutdownRequest, StartRequest, StartResponse, StateRequest, StateResponse,
+    Status, WaitRequest, WaitResponse,
+};
+use crate::bookkeeping::{Reservations, Starting};
+use crate::error::{Error, Result};
+use crate::event::Event;
+use crate::monitor::ProcessIndex;
+use crate::synchronous::container::{Container, ContainerFactory};
+use crate::synchronous::monitor::monitor_notify_by_pid;
+use crate::synchronous::ExitSignal;
+use crate::util::{convert_to_any, convert_to_timestamp, AsOption, Deadline};
+use crate::TtrpcResult;
+
+type EventSender = Sender<(String, Box<dyn Message>)>;
+
+/// TaskService is a Task template struct, it is considered a helper struct,
+/// which has already implemented `Task` trait, so that users can make it the type `T`
+/// parameter of `Service`, and implements their own `ContainerFactory` and `Container`.
+///
+/// Each container has a lock of its own, the lock of the map is only held to look up,
+/// add or remove a container, so a long operation on one container doesn't block the others.
+///
+/// Every request is bounded by its [Deadline], the wait for the lock of the container and
+/// for an exit as well as what the container does are given up once it passes.
+pub struct TaskService<F, C> {
+    pub factory: F,
+    pub containers: Arc<Mutex<HashMap<String, Arc<Mutex<C>>>>>,
+    /// Processes of the containers by pid, to dispatch their exits.
+    pub processes: Arc<ProcessIndex>,
+    pub namespace: String,
+    pub exit: Arc<ExitSignal>,
+    /// Ids of the containers being created.
+    creating: Reservations,
+    pub tx: Arc<Mutex<EventSender>>,
+}
+
+impl<F, C> TaskService<F, C>
+where
+    F: Default,
+{
+    pub fn new(ns: &str, exit: Arc<ExitSignal>, tx: EventSender) -> Self {
+        Self {
+            factory: Default::default(),
+            containers: Arc::new(Mutex::new(Default::default())),
+            processes: Default::default(),
+            namespace: ns.to_string(),
+            exit,
+            creating: Default::default(),
+            tx: Arc::new(Mutex::new(tx)),
+        }
+    }
+}
+
+impl<F, C> TaskService<F, C> {
+    /// Look up the container, to be locked by the caller with [Deadline::lock]
+    /// once the map is unlocked.
+    pub fn get_container(&self, id: &str) -> TtrpcResult<Arc<Mutex<C>>> {
+        let container = self.containers.lock().unwrap().get(id).cloned();
+        let container = container
+            .ok_or_else(|| Error::NotFoundError(format!("can not find container by id {}", id)))?;
+        Ok(container)
+    }
+
+    pub fn send_event(&self, event: impl Event) {
+        let topic = event.topic();
+        self.tx
+            .lock()
+            .unwrap()
+            .send((topic.to_string(), Box::new(event)))
+            .unwrap_or_else(|e| warn!("send {} to publisher: {}", topic, e));
+    }
+
+    /// Replay the exits of processes which exited before their pids were indexed.
+    fn replay_exits(&self, exits: Vec<(i32, i32)>) {
+        for (pid, code) in exits {
+            debug!("replay the early exit of process {}", pid);
+            monitor_notify_by_pid(pid, code)
+                .unwrap_or_else(|e| warn!("failed to replay exit of process {}: {}", pid, e));
+        }
+    }
+}
+
+impl<F, C> TaskService<F, C>
+where
+    F: ContainerFactory<C>,
+    C: Container,
+{
+    /// Rebuild the containers saved by a previous instance of the shim,
+    /// it should be called before the task service begins to serve requests.
+    pub fn restore(&self) -> Result<()> {
+        // hold the lock while restoring, so that exits of the restored processes
+        // are not handled before they are added, and stash the exits that arrive
+        // before their pids are indexed.
+        let mut containers = self.containers.lock().unwrap();
+        let mut starting = Starting::new(&self.processes);
+        let restored = self.factory.restore(&*self.namespace)?;
+        for c in restored {
+            let id = c.id();
+            info!("restored container {}", id);
+            for (exec_id, pid) in c.process_pids() {
+                if pid > 0 {
+                    starting.index(pid, &id, exec_id.as_deref());
+                }
+            }
+            containers.insert(id, Arc::new(Mutex::new(c)));
+        }
+        let early_exits = starting.finish();
+        drop(containers);
+        self.replay_exits(early_exits);
+        Ok(())
+    }
+
+    fn save(&self, container: &C) {
+        self.factory
+            .save(&*self.namespace, container)
+            .unwrap_or_else(|e| warn!("failed to save state of container: {}", e));
+    }
+}
+
+impl<F, C> Task for TaskService<F, C>
+where
+    F: ContainerFactory<C> + Sync + Send,
+    C: Container + Send + 'static,
+{
+    fn state(&self, ctx: &TtrpcContext, req: StateRequest) -> TtrpcResult<StateResponse> {
+        let container = self.get_container(req.get_id())?;
+        let container = Deadline::from(ctx).lock("lock container", &container)?;
+        let exec_id = req.get_exec_id().as_option();
+        let resp = container.state(exec_id)?;
+        Ok(resp)
+    }
+
+    fn create(
+        &self,
+        ctx: &TtrpcContext,
+        req: CreateTaskRequest,
+    ) -> TtrpcResult<CreateTaskResponse> {
+        info!("Create request for {:?}", &req);
+        let ns = self.namespace.as_str();
+        let id = req.id.as_str();
+        // reserve the id, the reservation is released once the container is added,
+        // or if the create fails
+        let _reservation = {
+            let containers = self.containers.lock().unwrap();
+            self.creating.reserve(id, containers.contains_key(id))?
+        };
+
+        // The container is created without the lock of the map,
+        // which may take long to run the runtime, mount the rootfs and set up the IO.
+        let mut starting = Starting::new(&self.processes);
+        let container = self.factory.create(ns, &req, ctx.into())?;
+        let mut resp = CreateTaskResponse::new();
+        let pid = container.pid() as u32;
+        resp.pid = pid;
+        self.save(&container);
+
+        let early_exits = {
+            let mut containers = self.containers.lock().unwrap();
+            containers.insert(id.to_string(), Arc::new(Mutex::new(container)));
+            starting.index(pid as i32, id, None);
+            starting.finish()
+        };
+
+        self.send_event(TaskCreate {
+            container_id: req.id.to_string(),
+            bundle: req.bundle.to_string(),
+            rootfs: req.rootfs,
+            io: SingularPtrField::some(TaskIO {
+                stdin: req.stdin.to_string(),
+                stdout: req.stdout.to_string(),
+                stderr: req.stderr.to_string(),
+                terminal: req.terminal,
+                unknown_fields: Default::default(),
+                cached_size: Default::default(),
+            }),
+            checkpoint: req.checkpoint.to_string(),
+            pid,
+            ..Default::default()
+        });
+        self.replay_exits(early_exits);
+        info!("Create request for {} returns pid {}", id, resp.pid);
+        Ok(resp)
+    }
+
+    fn start(&self, ctx: &TtrpcContext, req: StartRequest) -> TtrpcResult<StartResponse> {
+        info!("Start request for {:?}", &req);
+        let deadline = Deadline::from(ctx);
+        let container = self.get_container(req.get_id())?;
+        let mut container = deadline.lock("lock container", &container)?;
+        let exec_id = req.exec_id.as_str().as_option();
+        let mut starting = Starting::new(&self.processes);
+        let pid = container.start(exec_id, deadline)?;
+        starting.index(pid, req.get_id(), exec_id);
+        let early_exits = starting.finish();
+        self.save(&container);
+        // the exit handler needs the container to replay the exit
+        drop(container);
+
+        let mut resp = StartResponse::new();
+        resp.pid = pid as u32;
+
+        if req.exec_id.is_empty() {
+            self.send_event(TaskStart {
+                container_id: req.id.to_string(),
+                pid: pid as u32,
+                ..Default::default()
+            });
+        } else {
+            self.send_event(TaskExecStarted {
+                container_id: req.id.to_string(),
+                exec_id: req.exec_id.to_string(),
+                pid: pid as u32,
+                ..Default::default()
+            });
+        };
+
+        self.replay_exits(early_exits);
+        info!("Start request for {:?} returns pid {}", req, resp.get_pid());
+        Ok(resp)
+    }
+
+    fn delete(&self, ctx: &TtrpcContext, req: DeleteRequest) -> TtrpcResult<DeleteResponse> {
+        info!("Delete request for {:?}", &req);
+        let deadline = Deadline::from(ctx);
+        let container = self.get_container(req.get_id())?;
+        let mut container = deadline.lock("lock container", &container)?;
+        let id = container.id();
+        let exec_id_opt = req.get_exec_id().as_option();
+        let (pid, exit_status, exited_at) = container.delete(exec_id_opt, deadline)?;
+        self.processes.remove(&id, exec_id_opt);
+        if req.get_exec_id().is_empty() {
+            self.factory.cleanup(&*self.namespace, &container)?;
+            self.containers.lock().unwrap().remove(req.get_id());
+        } else {
+            self.save(&container);
+        }
+        drop(container);
+
+        let ts = convert_to_timestamp(exited_at);
+        self.send_event(TaskDelete {
+            container_id: id,
+            pid: pid as u32,
+            exit_status: exit_status as u32,
+            exited_at: SingularPtrField::some(ts.clone()),
+            ..Default::default()
+        });
+
+        let mut resp = DeleteResponse::new();
+        resp.set_exited_at(ts);
+        resp.set_pid(pid as u32);
+        resp.set_exit_status(exit_status as u32);
+        info!(
+            "Delete request for {} {} returns {:?}",
+            req.get_id(),
+            req.get_exec_id(),
+            resp
+        );
+        Ok(resp)
+    }
+
+    fn pids(&self, ctx: &TtrpcContext, req: PidsRequest) -> TtrpcResult<PidsResponse> {
+        debug!("Pids request for {:?}", req);
+        let deadline = Deadline::from(ctx);
+        let container = self.get_container(req.get_id())?;
+        let procs = deadline
+            .lock("lock container", &container)?
+            .all_processes(deadline)?;
+        debug!("Pids request for {:?} returns successfully", req);
+        Ok(PidsResponse {
+            processes: procs.into(),
+            ..Default::default()
+        })
+    }
+
+    fn kill(&self, ctx: &TtrpcContext, req: KillRequest) -> TtrpcResult<Empty> {
+        info!("Kill request for {:?}", req);
+        let deadline = Deadline::from(ctx);
+        let container = self.get_container(req.get_id())?;
+        deadline.lock("lock container", &container)?.kill(
+            req.get_exec_id().as_option(),
+            req.signal,
+            req.all,
+            deadline,
+        )?;
+        info!("Kill request for {:?} returns successfully", req);
+        Ok(Empty::new())
+    }
+
+    fn exec(&self, ctx: &TtrpcContext, req: ExecProcessRequest) -> TtrpcResult<Empty> {
+        info!("Exec request for {:?}", req);
+        let deadline = Deadline::from(ctx);
+        let exec_id = req.get_exec_id().to_string();
+        let container = self.get_container(req.get_id())?;
+        let mut container = deadline.lock("lock container", &container)?;
+        container.exec(req, deadline)?;
+        self.save(&container);
+
+        self.send_event(TaskExecAdded {
+            container_id: container.id(),
+            exec_id,
+            ..Default::default()
+        });
+
+        Ok(Empty::new())
+    }
+
+    fn resize_pty(&self, ctx: &TtrpcContext, req: ResizePtyRequest) -> TtrpcResult<Empty> {
+        debug!(
+            "Resize pty request for container {}, exec_id: {}",
+            &req.id, &req.exec_id
+        );
+        let container = self.get_container(req.get_id())?;
+        Deadline::from(ctx)
+            .lock("lock container", &container)?
+            .resize_pty(req.get_exec_id().as_option(), req.height, req.width)?;
+        Ok(Empty::new())
+    }
+
+    fn close_io(&self, _ctx: &TtrpcContext, _req: CloseIORequest) -> TtrpcResult<Empty> {
+        // TODO call close_io of container
+        Ok(Empty::new())
+    }
+
+    fn update(&self, ctx: &TtrpcContext, req: UpdateTaskRequest) -> TtrpcResult<Empty> {
+        debug!("Update request for {:?}", req);
+        let resources: LinuxResources = serde_json::from_slice(req.get_resources().get_value())
+            .map_err(|e| Error::InvalidArgument(format!("failed to parse resource spec: {}", e)))?;
+        let deadline = Deadline::from(ctx);
+        let container = self.get_container(req.get_id())?;
+        deadline
+            .lock("lock container", &container)?
+            .update(&resources, deadline)?;
+        Ok(Empty::new())
+    }
+
+    fn wait(&self, ctx: &TtrpcContext, req: WaitRequest) -> TtrpcResult<WaitResponse> {
+        info!("Wait request for {:?}", req);
+        let deadline = Deadline::from(ctx);
+        let exec_id = req.exec_id.as_str().as_option();
+        let wait_rx = {
+            let container = self.get_container(req.get_id())?;
+            let mut container = deadline.lock("lock container", &container)?;
+            let state = container.state(exec_id)?;
+            if state.status != Status::RUNNING && state.status != Status::CREATED {
+                let mut resp = WaitResponse::new();
+                resp.exit_status = state.exit_status;
+                resp.exited_at = state.exited_at;
+                info!("Wait request for {:?} returns {:?}", req, &resp);
+                return Ok(resp);
+            }
+            container.wait_channel(exec_id)?
+        };
+
+        // the channel is closed once the process exits
+        deadline.recv("wait", &wait_rx)?;
+        // get lock again.
+        let container = self.get_container(req.get_id())?;
+        let (_, code, exited_at) = deadline
+            .lock("lock container", &container)?
+            .get_exit_info(exec_id)?;
+        let mut resp = WaitResponse::new();
+        resp.exit_status = code as u32;
+        let ts = convert_to_timestamp(exited_at);
+        resp.exited_at = SingularPtrField::some(ts);
+        info!("Wait request for {:?} returns {:?}", req, &resp);
+        Ok(resp)
+    }
+
+    fn stats(&self, ctx: &TtrpcContext, req: StatsRequest) -> TtrpcResult<StatsResponse> {
+        debug!("Stats request for {:?}", req);
+        let deadline = Deadline::from(ctx);
+        let container = self.get_container(req.get_id())?;
+        let stats = deadline
+            .lock("lock container", &container)?
+            .stats(deadline)?;
+
+        let mut resp = StatsResponse::new();
+        resp.set_stats(convert_to_any(Box::new(stats))?);
+        Ok(resp)
+    }
+
+    fn connect(&self, ctx: &TtrpcContext, req: ConnectRequest) -> TtrpcResult<ConnectResponse> {
+        info!("Connect request for {:?}", req);
+        let container = self.get_container(req.get_id())?;
+        let pid = Deadline::from(ctx)
+            .lock("lock container", &container)?
+            .pid();
+
+        Ok(ConnectResponse {
+            shim_pid: std::process::id() as u32,
+            task_pid: pid as u32,
+            ..Default::default()
+        })
+    }
+
+    fn shutdown(&self, _ctx: &TtrpcContext, _req: ShutdownRequest) -> TtrpcResult<Empty> {
+        debug!("Shutdown request");
+        if !self.containers.lock().unwrap().is_empty() {
+            return Ok(Empty::new());
+        }
+        self.exit.signal();
+        Ok(Empty::default())
+    }
+}
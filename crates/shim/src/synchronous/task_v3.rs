@@ -0,0 +1,71 @@
+/*
+   Copyright The containerd Authors.
+
+   Licensed under the Apache License, Version 2.0 (the "License");
+   you may not use this file except in compliance with the License.
+   You may obtain a copy of the License at
+
+       http://www.apache.org/licenses/LICENSE-2.0
+
+   Unless required by applicable law or agreed to in writing, software
+   distributed under the License is distributed on an "AS IS" BASIS,
+   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+   See the License for the specific language governing permissions and
+   limitations under the License.
+*/
+
+use std::sync::Arc;
+
+use crate::protos::shim::shim_ttrpc::Task;
+use crate::protos::shim_sync::TaskV3;
+use crate::protos::task_v3::shim::*;
+use crate::protos::ttrpc::{self, TtrpcContext};
+use crate::protos::types::empty::Empty;
+use crate::util::convert_message;
+
+/// Serves the task API v3 by a [Task] of the API v2.
+///
+/// The messages of both versions have the same encoding, so the requests and responses are
+/// converted through it.
+pub struct TaskV3Adapter {
+    task: Arc<Box<dyn Task + Send + Sync>>,
+}
+
+impl TaskV3Adapter {
+    pub fn new(task: Arc<Box<dyn Task + Send + Sync>>) -> Self {
+        Self { task }
+    }
+}
+
+macro_rules! adapt {
+    ($($name:ident($req:ty) -> $resp:ty),* $(,)?) => {
+        impl TaskV3 for TaskV3Adapter {
+            $(
+                fn $name(&self, ctx: &TtrpcContext, req: $req) -> ttrpc::Result<$resp> {
+                    let resp = self.task.$name(ctx, convert_message(&req)?)?;
+                    Ok(convert_message(&resp)?)
+                }
+            )*
+        }
+    };
+}
+
+adapt! {
+    state(StateRequest) -> StateResponse,
+    create(CreateTaskRequest) -> CreateTaskResponse,
+    start(StartRequest) -> StartResponse,
+    delete(DeleteRequest) -> DeleteResponse,
+    pids(PidsRequest) -> PidsResponse,
+    pause(PauseRequest) -> Empty,
+    resume(ResumeRequest) -> Empty,
+    checkpoint(CheckpointTaskRequest) -> Empty,
+    kill(KillRequest) -> Empty,
+    exec(ExecProcessRequest) -> Empty,
+    resize_pty(ResizePtyRequest) -> Empty,
+    close_io(CloseIORequest) -> Empty,
+    update(UpdateTaskRequest) -> Empty,
+    wait(WaitRequest) -> WaitResponse,
+    stats(StatsRequest) -> StatsResponse,
+    connect(ConnectRequest) -> ConnectResponse,
+    shutdown(ShutdownRequest) -> Empty,
+}
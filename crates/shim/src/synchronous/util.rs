@@ -17,6 +17,10 @@
 use std::fs::{rename, File, OpenOptions};
 use std::io::{Read, Write};
 use std::path::Path;
+use std::sync::mpsc::{Receiver, RecvTimeoutError};
+use std::sync::{Mutex, MutexGuard, TryLockError};
+use std::thread;
+use std::time::Duration;
 
 use libc::mode_t;
 use log::warn;
@@ -24,8 +28,9 @@ use nix::sys::stat::Mode;
 use oci_spec::runtime::Spec;
 
 use containerd_shim_protos::shim::oci::Options;
+use containerd_shim_protos::ttrpc::TtrpcContext;
 
-use crate::util::{JsonOptions, OPTIONS_FILE_NAME, RUNTIME_FILE_NAME};
+use crate::util::{Deadline, JsonOptions, OPTIONS_FILE_NAME, RUNTIME_FILE_NAME};
 use crate::Error;
 
 pub fn read_file_to_str<P: AsRef<Path>>(filename: P) -> crate::Result<String> {
@@ -140,3 +145,77 @@ impl Drop for HelperRemoveFile {
             .unwrap_or_else(|e| warn!("remove dir {} error: {}", &self.path, e));
     }
 }
+
+impl From<&TtrpcContext> for Deadline {
+    fn from(ctx: &TtrpcContext) -> Self {
+        Self::after_nanos(ctx.timeout_nano)
+    }
+}
+
+/// Interval to retry a lock held by another request until the deadline.
+const LOCK_RETRY_INTERVAL: Duration = Duration::from_millis(10);
+
+impl Deadline {
+    /// Lock the mutex until the deadline, it panics if the mutex is poisoned as `lock().unwrap()`.
+    pub fn lock<'a, T>(&self, what: &str, m: &'a Mutex<T>) -> crate::Result<MutexGuard<'a, T>> {
+        if self.instant().is_none() {
+            return Ok(m.lock().unwrap());
+        }
+        loop {
+            match m.try_lock() {
+                Ok(guard) => return Ok(guard),
+                Err(TryLockError::Poisoned(e)) => panic!("{}: {}", what, e),
+                Err(TryLockError::WouldBlock) => {
+                    self.check(what)?;
+                    thread::sleep(LOCK_RETRY_INTERVAL);
+                }
+            }
+        }
+    }
+
+    /// Receive from the channel until the deadline, `None` is returned if it is closed.
+    pub fn recv<T>(&self, what: &str, rx: &Receiver<T>) -> crate::Result<Option<T>> {
+        match self.remaining() {
+            Some(timeout) => match rx.recv_timeout(timeout) {
+                Ok(v) => Ok(Some(v)),
+                Err(RecvTimeoutError::Disconnected) => Ok(None),
+                Err(RecvTimeoutError::Timeout) => Err(Error::DeadlineExceeded(what.to_string())),
+            },
+            None => Ok(rx.recv().ok()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::mpsc::channel;
+
+    use super::*;
+
+    #[test]
+    fn test_deadline_recv() {
+        let (tx, rx) = channel();
+        tx.send(1).unwrap();
+        let deadline = Deadline::after_nanos(10_000_000);
+        assert_eq!(deadline.recv("test", &rx).unwrap(), Some(1));
+        assert!(matches!(
+            deadline.recv("test", &rx),
+            Err(Error::DeadlineExceeded(_))
+        ));
+        drop(tx);
+        assert_eq!(Deadline::default().recv("test", &rx).unwrap(), None);
+    }
+
+    #[test]
+    fn test_deadline_lock() {
+        let m = Mutex::new(1);
+        let deadline = Deadline::after_nanos(20_000_000);
+        let guard = deadline.lock("test", &m).unwrap();
+        assert!(matches!(
+            deadline.lock("test", &m),
+            Err(Error::DeadlineExceeded(_))
+        ));
+        drop(guard);
+        assert_eq!(*Deadline::default().lock("test", &m).unwrap(), 1);
+    }
+}
@@ -0,0 +1,382 @@
+/*
+   Copyright The containerd Authors.
+
+   Licensed under the Apache License, Version 2.0 (the "License");
+   you may not use this file except in compliance with the License.
+   You may obtain a copy of the License at
+
+       http://www.apache.org/licenses/LICENSE-2.0
+
+   Unless required by applicable law or agreed to in writing, software
+   distributed under the License is distributed on an "AS IS" BASIS,
+   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+   See the License for the specific language governing permissions and
+   limitations under the License.
+*/
+
+//! Conformance cases of the task service, asserting the semantics containerd expects from
+//! shims, as implemented by the Go runc shim.
+//!
+//! The cases talk to a shim over ttrpc, each in a task of its own, so a shim launched by
+//! [FakeContainerd] is able to run all of them:
+//!
+//! ```text
+//! let conformance = Conformance::new(&client, &containerd, |id| prepare_bundle(id));
+//! for (name, result) in conformance.run() {
+//!     assert!(result.is_ok(), "{}: {:?}", name, result);
+//! }
+//! ```
+
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+use crate::error::{Error, Result};
+use crate::event::{decode, TypedEvent};
+use crate::protos::api::*;
+use crate::protos::protobuf::well_known_types::Timestamp;
+use crate::protos::protobuf::SingularPtrField;
+use crate::protos::shim::events::Envelope;
+use crate::protos::shim_sync::TaskClient;
+use crate::protos::topics::*;
+use crate::protos::ttrpc::{self, context::Context, Code};
+use crate::testing::FakeContainerd;
+
+/// Time to wait for the events of a task to be forwarded.
+const EVENT_TIMEOUT: Duration = Duration::from_secs(5);
+
+const SIGKILL: u32 = 9;
+
+/// Exit status of a process killed by SIGKILL.
+const KILLED: u32 = 128 + SIGKILL;
+
+macro_rules! ensure {
+    ($cond:expr, $($args:tt)+) => {
+        if !$cond {
+            return Err(other!($($args)+));
+        }
+    };
+}
+
+type Case<'a> = fn(&Conformance<'a>) -> Result<()>;
+
+/// Runs the conformance cases against a shim.
+pub struct Conformance<'a> {
+    client: &'a TaskClient,
+    containerd: &'a FakeContainerd,
+    bundle: Box<dyn Fn(&str) -> Result<PathBuf> + 'a>,
+}
+
+impl<'a> Conformance<'a> {
+    /// The `bundle` prepares the bundle of a task by its id, the process of which must run
+    /// until it is killed.
+    pub fn new(
+        client: &'a TaskClient,
+        containerd: &'a FakeContainerd,
+        bundle: impl Fn(&str) -> Result<PathBuf> + 'a,
+    ) -> Self {
+        Self {
+            client,
+            containerd,
+            bundle: Box::new(bundle),
+        }
+    }
+
+    /// Names of the cases and their results, in the order they are run.
+    pub fn run(&self) -> Vec<(&'static str, Result<()>)> {
+        let cases: [(&'static str, Case<'a>); 6] = [
+            ("kill_exited", Self::kill_exited),
+            ("wait_after_delete", Self::wait_after_delete),
+            ("delete_unknown_exec", Self::delete_unknown_exec),
+            ("exit_timestamp", Self::exit_timestamp),
+            ("event_order", Self::event_order),
+            ("shutdown_with_live_task", Self::shutdown_with_live_task),
+        ];
+        cases
+            .iter()
+            .map(|(name, case)| (*name, case(self)))
+            .collect()
+    }
+
+    /// Killing a process that has exited fails with NOT_FOUND.
+    pub fn kill_exited(&self) -> Result<()> {
+        let id = self.start_task("kill-exited")?;
+        self.kill(&id)?;
+        self.wait(&id)?;
+        let res = self.client.kill(ctx(), &kill_request(&id));
+        expect_code(res, Code::NOT_FOUND, "kill exited task")?;
+        self.delete(&id, "")?;
+        Ok(())
+    }
+
+    /// Waiting for a deleted task fails with NOT_FOUND.
+    pub fn wait_after_delete(&self) -> Result<()> {
+        let id = self.start_task("wait-after-delete")?;
+        self.kill(&id)?;
+        self.wait(&id)?;
+        self.delete(&id, "")?;
+        let res = self.client.wait(ctx(), &wait_request(&id));
+        expect_code(res, Code::NOT_FOUND, "wait deleted task")?;
+        Ok(())
+    }
+
+    /// Deleting an exec process that was never added fails with NOT_FOUND.
+    pub fn delete_unknown_exec(&self) -> Result<()> {
+        let id = self.start_task("delete-unknown-exec")?;
+        let res = self.client.delete(ctx(), &delete_request(&id, "unknown"));
+        let checked = expect_code(res, Code::NOT_FOUND, "delete unknown exec");
+        self.cleanup(&id)?;
+        checked
+    }
+
+    /// The exit timestamp is reported by wait, delete and the exit event alike,
+    /// and is not before the task is started.
+    pub fn exit_timestamp(&self) -> Result<()> {
+        let started = crate::util::timestamp()?;
+        let id = self.start_task("exit-timestamp")?;
+        self.kill(&id)?;
+        let waited = self.wait(&id)?;
+        ensure!(
+            waited.exit_status == KILLED,
+            "exit status {}, expect {}",
+            waited.exit_status,
+            KILLED
+        );
+        let exited_at = non_zero(&waited.exited_at, "exited_at of wait")?;
+        ensure!(
+            (exited_at.seconds, exited_at.nanos) >= (started.seconds, started.nanos),
+            "exited at {:?}, before started at {:?}",
+            exited_at,
+            started
+        );
+
+        let deleted = self.delete(&id, "")?;
+        ensure!(
+            deleted.exit_status == KILLED,
+            "exit status of delete {}, expect {}",
+            deleted.exit_status,
+            KILLED
+        );
+        ensure!(
+            deleted.get_exited_at() == exited_at,
+            "exited_at of delete {:?}, of wait {:?}",
+            deleted.get_exited_at(),
+            exited_at
+        );
+
+        let events = self.events(&id)?;
+        let exit = events.iter().find_map(|e| match e {
+            TypedEvent::TaskExit(exit) => Some(exit),
+            _ => None,
+        });
+        let exit = exit.ok_or_else(|| other!("no exit event"))?;
+        ensure!(
+            exit.get_exited_at() == exited_at && exit.exit_status == KILLED,
+            "exit event {:?}, expect exit status {} at {:?}",
+            exit,
+            KILLED,
+            exited_at
+        );
+        Ok(())
+    }
+
+    /// The events of a task are published in the order of its lifecycle.
+    pub fn event_order(&self) -> Result<()> {
+        let id = self.start_task("event-order")?;
+        self.cleanup(&id)?;
+        let topics: Vec<String> = self.events(&id)?.iter().filter_map(|e| e.topic()).collect();
+        let expected = [
+            TASK_CREATE_EVENT_TOPIC,
+            TASK_START_EVENT_TOPIC,
+            TASK_EXIT_EVENT_TOPIC,
+            TASK_DELETE_EVENT_TOPIC,
+        ];
+        ensure!(
+            topics == expected,
+            "events {:?}, expect {:?}",
+            topics,
+            expected
+        );
+        Ok(())
+    }
+
+    /// A shutdown which is not `now` is ignored while the shim has a task.
+    pub fn shutdown_with_live_task(&self) -> Result<()> {
+        let id = self.start_task("shutdown-with-live-task")?;
+        let req = ShutdownRequest {
+            id: id.clone(),
+            ..Default::default()
+        };
+        self.client.shutdown(ctx(), &req)?;
+        let state = self.client.state(ctx(), &state_request(&id));
+        let checked = match state {
+            Ok(state) if state.status == Status::RUNNING => Ok(()),
+            Ok(state) => Err(other!("task is {:?} after shutdown", state.status)),
+            Err(e) => Err(other!("shim is gone after shutdown: {}", e)),
+        };
+        self.cleanup(&id)?;
+        checked
+    }
+
+    fn start_task(&self, id: &str) -> Result<String> {
+        let bundle = (self.bundle)(id)?;
+        let req = CreateTaskRequest {
+            id: id.to_string(),
+            bundle: bundle.display().to_string(),
+            ..Default::default()
+        };
+        self.client.create(ctx(), &req)?;
+        let req = StartRequest {
+            id: id.to_string(),
+            ..Default::default()
+        };
+        self.client.start(ctx(), &req)?;
+        Ok(id.to_string())
+    }
+
+    fn kill(&self, id: &str) -> Result<()> {
+        self.client.kill(ctx(), &kill_request(id))?;
+        Ok(())
+    }
+
+    fn wait(&self, id: &str) -> Result<WaitResponse> {
+        Ok(self.client.wait(ctx(), &wait_request(id))?)
+    }
+
+    fn delete(&self, id: &str, exec_id: &str) -> Result<DeleteResponse> {
+        Ok(self.client.delete(ctx(), &delete_request(id, exec_id))?)
+    }
+
+    /// Kill, wait and delete the task.
+    fn cleanup(&self, id: &str) -> Result<()> {
+        self.kill(id)?;
+        self.wait(id)?;
+        self.delete(id, "")?;
+        Ok(())
+    }
+
+    /// Events of the task, once its delete event is forwarded.
+    fn events(&self, id: &str) -> Result<Vec<TypedEvent>> {
+        let deadline = Instant::now() + EVENT_TIMEOUT;
+        loop {
+            let events = task_events(&self.containerd.events(), id)?;
+            if events
+                .iter()
+                .any(|e| matches!(e, TypedEvent::TaskDelete(_)))
+            {
+                return Ok(events);
+            }
+            if Instant::now() >= deadline {
+                return Err(other!("no delete event of {}", id));
+            }
+            std::thread::sleep(Duration::from_millis(50));
+        }
+    }
+}
+
+fn ctx() -> Context {
+    Context::default()
+}
+
+fn kill_request(id: &str) -> KillRequest {
+    KillRequest {
+        id: id.to_string(),
+        signal: SIGKILL,
+        ..Default::default()
+    }
+}
+
+fn wait_request(id: &str) -> WaitRequest {
+    WaitRequest {
+        id: id.to_string(),
+        ..Default::default()
+    }
+}
+
+fn state_request(id: &str) -> StateRequest {
+    StateRequest {
+        id: id.to_string(),
+        ..Default::default()
+    }
+}
+
+fn delete_request(id: &str, exec_id: &str) -> DeleteRequest {
+    DeleteRequest {
+        id: id.to_string(),
+        exec_id: exec_id.to_string(),
+        ..Default::default()
+    }
+}
+
+fn expect_code<T>(res: ttrpc::Result<T>, code: Code, action: &str) -> Result<()> {
+    match res {
+        Err(ttrpc::Error::RpcStatus(s)) if s.get_code() == code => Ok(()),
+        Err(e) => Err(other!("{}: expect {:?}, got {}", action, code, e)),
+        Ok(_) => Err(other!("{}: expect {:?}, got success", action, code)),
+    }
+}
+
+fn non_zero<'t>(ts: &'t SingularPtrField<Timestamp>, name: &str) -> Result<&'t Timestamp> {
+    match ts.as_ref() {
+        Some(ts) if ts.seconds != 0 || ts.nanos != 0 => Ok(ts),
+        _ => Err(other!("{} is not set", name)),
+    }
+}
+
+/// The task events of the container, in the order they are forwarded.
+fn task_events(envelopes: &[Envelope], id: &str) -> Result<Vec<TypedEvent>> {
+    let mut events = Vec::new();
+    for envelope in envelopes {
+        let event = decode(envelope)?;
+        let container_id = match &event {
+            TypedEvent::TaskCreate(e) => &e.container_id,
+            TypedEvent::TaskStart(e) => &e.container_id,
+            TypedEvent::TaskExit(e) => &e.container_id,
+            TypedEvent::TaskDelete(e) => &e.container_id,
+            _ => continue,
+        };
+        if container_id == id {
+            events.push(event);
+        }
+    }
+    Ok(events)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::event::Event;
+    use crate::protos::events::task::{TaskCreate, TaskExit};
+    use crate::util::convert_to_any;
+
+    use super::*;
+
+    fn envelope<E: Event + 'static>(event: E) -> Envelope {
+        let mut envelope = Envelope::new();
+        envelope.set_topic(event.topic());
+        envelope.set_event(convert_to_any(Box::new(event)).unwrap());
+        envelope
+    }
+
+    #[test]
+    fn test_task_events() {
+        let mut create = TaskCreate::new();
+        create.set_container_id("a".to_string());
+        let mut exit = TaskExit::new();
+        exit.set_container_id("b".to_string());
+        let envelopes = vec![envelope(create.clone()), envelope(exit)];
+
+        let events = task_events(&envelopes, "a").unwrap();
+        assert_eq!(events, vec![TypedEvent::TaskCreate(create)]);
+    }
+
+    #[test]
+    fn test_expect_code() {
+        let not_found: ttrpc::Result<()> = Err(ttrpc::Error::RpcStatus(ttrpc::get_status(
+            Code::NOT_FOUND,
+            "not found",
+        )));
+        assert!(expect_code(not_found, Code::NOT_FOUND, "test").is_ok());
+        let other: ttrpc::Result<()> = Err(ttrpc::Error::Others("other".to_string()));
+        assert!(expect_code(other, Code::NOT_FOUND, "test").is_err());
+        assert!(expect_code(Ok(()), Code::NOT_FOUND, "test").is_err());
+    }
+}
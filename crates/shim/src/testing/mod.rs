@@ -0,0 +1,356 @@
+/*
+   Copyright The containerd Authors.
+
+   Licensed under the Apache License, Version 2.0 (the "License");
+   you may not use this file except in compliance with the License.
+   You may obtain a copy of the License at
+
+       http://www.apache.org/licenses/LICENSE-2.0
+
+   Unless required by applicable law or agreed to in writing, software
+   distributed under the License is distributed on an "AS IS" BASIS,
+   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+   See the License for the specific language governing permissions and
+   limitations under the License.
+*/
+
+//! The parts of containerd a shim talks to, to test the whole lifecycle of a shim without
+//! containerd: the `start` action, the task service, the `delete` action and the events.
+//!
+//! ```text
+//! let containerd = FakeContainerd::new(tmpdir.path())?;
+//! let shim = containerd.shim("/usr/local/bin/containerd-shim-runc-v2", "default", "test", bundle);
+//! let client = shim.start()?;
+//! // create, start, wait and delete the task by the client ...
+//! let resp = shim.delete()?;
+//! let event = containerd.wait_event(TASK_EXIT_EVENT_TOPIC, Duration::from_secs(5));
+//! ```
+//!
+//! The [conformance] cases check the task service of a shim launched this way.
+
+use std::io::{Read, Write};
+use std::os::unix::io::AsRawFd;
+use std::os::unix::net::UnixListener;
+use std::path::{Path, PathBuf};
+use std::process::{Child, Command, Stdio};
+use std::sync::{Arc, Condvar, Mutex};
+use std::time::{Duration, Instant};
+
+use command_fds::{CommandFdExt, FdMapping};
+
+use crate::client::{ttrpc_address, CONNECT_INTERVAL, DEFAULT_CONNECT_TIMEOUT};
+use crate::error::{Error, Result};
+use crate::protos::api::{DeleteResponse, Empty};
+use crate::protos::protobuf::well_known_types::Any;
+use crate::protos::protobuf::Message;
+use crate::protos::shim::events::{Envelope, ForwardRequest};
+use crate::protos::shim_sync::{create_events, Client, Events, TaskClient};
+use crate::protos::ttrpc::{self, Server, TtrpcContext};
+use crate::{SOCKET_FD, TTRPC_ADDRESS};
+
+pub mod conformance;
+
+/// Envelopes forwarded by shims.
+#[derive(Default)]
+struct Captured {
+    envelopes: Mutex<Vec<Envelope>>,
+    cond: Condvar,
+}
+
+/// Events service capturing the envelopes forwarded by shims.
+struct EventsService(Arc<Captured>);
+
+impl Events for EventsService {
+    fn forward(&self, _ctx: &TtrpcContext, req: ForwardRequest) -> ttrpc::Result<Empty> {
+        let mut envelopes = self.0.envelopes.lock().unwrap();
+        envelopes.push(req.get_envelope().clone());
+        self.0.cond.notify_all();
+        Ok(Empty::new())
+    }
+}
+
+/// An in-process stand-in of containerd, serving the ttrpc `Events` service.
+pub struct FakeContainerd {
+    address: String,
+    ttrpc_address: String,
+    server: Option<Server>,
+    events: Arc<Captured>,
+}
+
+impl FakeContainerd {
+    /// Serve the events service in the directory, where the sockets of shims are created
+    /// too if they are located by the address of containerd.
+    pub fn new(dir: impl AsRef<Path>) -> Result<Self> {
+        let address = dir.as_ref().join("containerd.sock");
+        let ttrpc_address = dir.as_ref().join("containerd.sock.ttrpc");
+        let events = Arc::new(Captured::default());
+        let service: Box<dyn Events + Send + Sync> = Box::new(EventsService(events.clone()));
+        let mut server = Server::new()
+            .bind(&format!("unix://{}", ttrpc_address.display()))?
+            .register_service(create_events(Arc::new(service)));
+        server.start()?;
+        Ok(Self {
+            address: address.display().to_string(),
+            ttrpc_address: ttrpc_address.display().to_string(),
+            server: Some(server),
+            events,
+        })
+    }
+
+    /// Address of the GRPC socket passed to shims, nothing listens on it.
+    pub fn address(&self) -> &str {
+        &self.address
+    }
+
+    /// Address of the events service, passed to shims by `TTRPC_ADDRESS`.
+    pub fn ttrpc_address(&self) -> &str {
+        &self.ttrpc_address
+    }
+
+    /// Envelopes of the events forwarded so far, in order.
+    pub fn events(&self) -> Vec<Envelope> {
+        self.events.envelopes.lock().unwrap().clone()
+    }
+
+    /// Wait for the first event of the topic, `None` if it is not forwarded within the timeout.
+    pub fn wait_event(&self, topic: &str, timeout: Duration) -> Option<Envelope> {
+        let deadline = Instant::now() + timeout;
+        let mut envelopes = self.events.envelopes.lock().unwrap();
+        loop {
+            if let Some(e) = envelopes.iter().find(|e| e.get_topic() == topic) {
+                return Some(e.clone());
+            }
+            let now = Instant::now();
+            if now >= deadline {
+                return None;
+            }
+            envelopes = self
+                .events
+                .cond
+                .wait_timeout(envelopes, deadline - now)
+                .unwrap()
+                .0;
+        }
+    }
+
+    /// A shim binary to be launched by this containerd for the task in the bundle.
+    pub fn shim(
+        &self,
+        binary: impl AsRef<Path>,
+        namespace: &str,
+        id: &str,
+        bundle: impl AsRef<Path>,
+    ) -> ShimLauncher {
+        ShimLauncher {
+            binary: binary.as_ref().to_path_buf(),
+            namespace: namespace.to_string(),
+            id: id.to_string(),
+            bundle: bundle.as_ref().to_path_buf(),
+            address: self.address.clone(),
+            ttrpc_address: self.ttrpc_address.clone(),
+            options: None,
+            debug: false,
+        }
+    }
+}
+
+impl Drop for FakeContainerd {
+    fn drop(&mut self) {
+        if let Some(server) = self.server.take() {
+            server.shutdown();
+        }
+    }
+}
+
+/// Launches a shim binary the way containerd does.
+pub struct ShimLauncher {
+    binary: PathBuf,
+    namespace: String,
+    id: String,
+    bundle: PathBuf,
+    address: String,
+    ttrpc_address: String,
+    options: Option<Any>,
+    debug: bool,
+}
+
+impl ShimLauncher {
+    /// Options written to the stdin of `start`, like the `Options` of runc shims.
+    pub fn options(mut self, options: Any) -> Self {
+        self.options = Some(options);
+        self
+    }
+
+    pub fn debug(mut self, debug: bool) -> Self {
+        self.debug = debug;
+        self
+    }
+
+    /// Command running the action of the shim, in the bundle and with containerd's flags.
+    pub fn command(&self, action: Option<&str>) -> Command {
+        let mut command = Command::new(&self.binary);
+        command
+            .current_dir(&self.bundle)
+            .env(TTRPC_ADDRESS, &self.ttrpc_address)
+            .args(&["-namespace", &self.namespace, "-id", &self.id])
+            .args(&["-address", &self.address]);
+        if self.debug {
+            command.arg("-debug");
+        }
+        if let Some(action) = action {
+            if action == "delete" {
+                command.arg("-bundle").arg(&self.bundle);
+            }
+            command.arg(action);
+        }
+        command
+    }
+
+    /// Run the `start` action, which spawns the shim server, and connect to it.
+    pub fn start(&self) -> Result<TaskClient> {
+        let mut child = self
+            .command(Some("start"))
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .spawn()
+            .map_err(io_error!(e, "spawn shim start"))?;
+        let options = match &self.options {
+            Some(options) => options.write_to_bytes()?,
+            None => Vec::new(),
+        };
+        let mut stdin = child.stdin.take().unwrap();
+        stdin
+            .write_all(&options)
+            .map_err(io_error!(e, "write options"))?;
+        drop(stdin);
+        let output = wait_output(child, "start")?;
+        let address = bootstrap_address(&String::from_utf8_lossy(&output))?;
+        connect(&address)
+    }
+
+    /// Spawn the shim server directly, serving on the socket passed as fd 3,
+    /// without the `start` action.
+    pub fn serve(&self, socket: impl AsRef<Path>) -> Result<(Child, TaskClient)> {
+        let listener =
+            UnixListener::bind(socket.as_ref()).map_err(io_error!(e, "bind shim socket"))?;
+        let mut command = self.command(None);
+        command.stdin(Stdio::null()).fd_mappings(vec![FdMapping {
+            parent_fd: listener.as_raw_fd(),
+            child_fd: SOCKET_FD,
+        }])?;
+        let child = command.spawn().map_err(io_error!(e, "spawn shim"))?;
+        let client = connect(&socket.as_ref().display().to_string())?;
+        Ok((child, client))
+    }
+
+    /// Run the `delete` action, which cleans up the task after the shim is gone.
+    pub fn delete(&self) -> Result<DeleteResponse> {
+        let child = self
+            .command(Some("delete"))
+            .stdin(Stdio::null())
+            .stdout(Stdio::piped())
+            .spawn()
+            .map_err(io_error!(e, "spawn shim delete"))?;
+        let output = wait_output(child, "delete")?;
+        Ok(DeleteResponse::parse_from_bytes(&output)?)
+    }
+}
+
+/// Wait for the action to exit successfully and return its stdout.
+fn wait_output(mut child: Child, action: &str) -> Result<Vec<u8>> {
+    let mut output = Vec::new();
+    if let Some(mut stdout) = child.stdout.take() {
+        stdout
+            .read_to_end(&mut output)
+            .map_err(io_error!(e, "read stdout of shim {}", action))?;
+    }
+    let status = child.wait().map_err(io_error!(e, "wait shim {}", action))?;
+    if !status.success() {
+        return Err(other!("shim {} failed: {}", action, status));
+    }
+    Ok(output)
+}
+
+/// Address of the shim in the output of `start`, which is either the address itself or the
+/// bootstrap params in JSON.
+fn bootstrap_address(output: &str) -> Result<String> {
+    let output = output.trim();
+    if !output.starts_with('{') {
+        return Ok(output.to_string());
+    }
+    let params: serde_json::Value = serde_json::from_str(output)?;
+    match params["address"].as_str() {
+        Some(address) => Ok(address.to_string()),
+        None => Err(other!("no address in bootstrap params: {}", output)),
+    }
+}
+
+fn connect(address: &str) -> Result<TaskClient> {
+    let address = ttrpc_address(address);
+    let deadline = Instant::now() + DEFAULT_CONNECT_TIMEOUT;
+    loop {
+        match Client::connect(&address) {
+            Ok(client) => return Ok(TaskClient::new(client)),
+            Err(e) if Instant::now() >= deadline => {
+                return Err(other!("connect {}: {}", address, e))
+            }
+            Err(_) => std::thread::sleep(CONNECT_INTERVAL),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::protos::shim_sync::EventsClient;
+    use crate::protos::ttrpc::context::Context;
+
+    use super::*;
+
+    #[test]
+    fn test_bootstrap_address() {
+        let address = "unix:///run/containerd/s/1234";
+        assert_eq!(bootstrap_address(address).unwrap(), address);
+        assert_eq!(
+            bootstrap_address(&format!("{}\n", address)).unwrap(),
+            address
+        );
+        let params =
+            r#"{"version":3,"address":"unix:///run/containerd/s/1234","protocol":"ttrpc"}"#;
+        assert_eq!(bootstrap_address(params).unwrap(), address);
+        assert!(bootstrap_address(r#"{"version":3}"#).is_err());
+    }
+
+    #[test]
+    fn test_command() {
+        let tmpdir = tempfile::tempdir().unwrap();
+        let containerd = FakeContainerd::new(tmpdir.path()).unwrap();
+        let shim = containerd
+            .shim("/bin/shim", "default", "test", "/bundle")
+            .debug(true);
+        let command = format!("{:?}", shim.command(Some("delete")));
+        assert!(command.contains(r#""/bin/shim" "-namespace" "default" "-id" "test""#));
+        assert!(command.contains(r#""-debug" "-bundle" "/bundle" "delete""#));
+    }
+
+    #[test]
+    fn test_forward_events() {
+        let tmpdir = tempfile::tempdir().unwrap();
+        let containerd = FakeContainerd::new(tmpdir.path()).unwrap();
+        let client = Client::connect(&format!("unix://{}", containerd.ttrpc_address())).unwrap();
+        let client = EventsClient::new(client);
+
+        let mut envelope = Envelope::new();
+        envelope.set_topic("/tasks/exit".to_string());
+        envelope.set_namespace("default".to_string());
+        let mut req = ForwardRequest::new();
+        req.set_envelope(envelope.clone());
+        client.forward(Context::default(), &req).unwrap();
+
+        let received = containerd.wait_event("/tasks/exit", Duration::from_secs(5));
+        assert_eq!(received, Some(envelope));
+        assert_eq!(containerd.events().len(), 1);
+        assert!(containerd
+            .wait_event("/tasks/delete", Duration::from_millis(10))
+            .is_none());
+    }
+}
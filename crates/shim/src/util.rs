@@ -16,7 +16,7 @@
 
 use std::env;
 use std::os::unix::io::RawFd;
-use std::time::{SystemTime, UNIX_EPOCH};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 
 use serde::{Deserialize, Serialize};
 use time::OffsetDateTime;
@@ -24,7 +24,7 @@ use time::OffsetDateTime;
 use crate::api::Options;
 #[cfg(feature = "async")]
 pub use crate::asynchronous::util::*;
-use crate::error::Result;
+use crate::error::{Error, Result};
 use crate::protos::protobuf::well_known_types::{Any, Timestamp};
 use crate::protos::protobuf::Message;
 #[cfg(not(feature = "async"))]
@@ -161,6 +161,13 @@ pub fn convert_to_any(obj: Box<dyn Message>) -> Result<Any> {
     Ok(any)
 }
 
+/// Convert a message to another type of the same protobuf encoding, like the messages of
+/// the task API v2 and v3.
+pub fn convert_message<F: Message, T: Message>(from: &F) -> Result<T> {
+    let data = from.write_to_bytes()?;
+    Ok(T::parse_from_bytes(&data)?)
+}
+
 /// Returns a temp dir. If the environment variable "XDG_RUNTIME_DIR" is set, return its value.
 /// Otherwise if `std::env::temp_dir()` failed, return current dir or return the temp dir depended on OS.
 pub(crate) fn xdg_runtime_dir() -> String {
@@ -200,13 +207,90 @@ impl AsOption for str {
     }
 }
 
+/// Deadline of a request, set by the client with the timeout of the ttrpc context.
+///
+/// Every Task RPC is bounded by the deadline of its request, which is passed down to the
+/// container and process traits, so that the runtime commands they run are cancelled once
+/// it passes and `DeadlineExceeded` is returned. The work left half done by a create or an
+/// exec start is rolled back, the other operations leave the container for the client to
+/// retry or delete.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Deadline(Option<Instant>);
+
+impl Deadline {
+    /// Deadline after the timeout of a ttrpc context, there is none if it is not positive.
+    pub fn after_nanos(nanos: i64) -> Self {
+        if nanos <= 0 {
+            return Self::default();
+        }
+        Self(Some(Instant::now() + Duration::from_nanos(nanos as u64)))
+    }
+
+    pub fn instant(&self) -> Option<Instant> {
+        self.0
+    }
+
+    pub fn is_exceeded(&self) -> bool {
+        self.0.map(|d| d <= Instant::now()).unwrap_or(false)
+    }
+
+    /// Time left until the deadline, `None` if there is no deadline.
+    pub fn remaining(&self) -> Option<Duration> {
+        self.0.map(|d| d.saturating_duration_since(Instant::now()))
+    }
+
+    /// Fail with `DeadlineExceeded` if the deadline has passed, before work that can not be
+    /// cancelled once begun.
+    pub fn check(&self, what: &str) -> Result<()> {
+        if self.is_exceeded() {
+            return Err(Error::DeadlineExceeded(what.to_string()));
+        }
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_deadline() {
+        let deadline = Deadline::default();
+        assert!(!deadline.is_exceeded());
+        assert_eq!(deadline.remaining(), None);
+        assert!(deadline.check("test").is_ok());
+        assert_eq!(Deadline::after_nanos(-1), Deadline::default());
+
+        let deadline = Deadline::after_nanos(1_000_000_000);
+        assert!(!deadline.is_exceeded());
+        assert!(deadline.remaining().unwrap() > Duration::from_millis(500));
+
+        let deadline = Deadline::after_nanos(1);
+        std::thread::sleep(Duration::from_millis(1));
+        assert!(deadline.is_exceeded());
+        assert_eq!(deadline.remaining(), Some(Duration::ZERO));
+        assert!(matches!(
+            deadline.check("test"),
+            Err(Error::DeadlineExceeded(_))
+        ));
+    }
+
     #[test]
     fn test_timestamp() {
         let ts = timestamp().unwrap();
         assert!(ts.seconds > 0);
     }
+
+    #[test]
+    fn test_convert_message() {
+        let mut state = crate::protos::shim::shim::StateResponse::new();
+        state.set_id("test".to_string());
+        state.set_pid(1234);
+        state.set_exit_status(137);
+        let converted: crate::protos::task_v3::shim::StateResponse =
+            convert_message(&state).unwrap();
+        assert_eq!(converted.get_id(), "test");
+        assert_eq!(converted.get_pid(), 1234);
+        assert_eq!(converted.get_exit_status(), 137);
+    }
 }